@@ -248,6 +248,70 @@ pub struct TextContent {
     pub annotations: Vec<serde_json::Value>,
 }
 
+/// An incremental update to a message, received while streaming a run.
+///
+/// Delivered as `thread.message.delta` events by
+/// [`run::create_stream`](crate::run::create_stream).
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessageDelta {
+    /// The ID of the message being updated.
+    pub id: String,
+
+    /// Object type, always "thread.message.delta".
+    pub object: String,
+
+    /// The changed parts of the message.
+    pub delta: MessageDeltaBody,
+}
+
+impl MessageDelta {
+    /// Concatenate the text fragments carried by this delta.
+    pub fn text(&self) -> String {
+        self.delta
+            .content
+            .iter()
+            .filter_map(|c| c.text.as_ref().and_then(|t| t.value.as_deref()))
+            .collect()
+    }
+}
+
+/// The changed parts of a [`MessageDelta`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessageDeltaBody {
+    /// The role of the message author, sent with the first delta.
+    #[serde(default)]
+    pub role: Option<MessageRole>,
+
+    /// Content fragments, addressed by index.
+    #[serde(default)]
+    pub content: Vec<MessageDeltaContent>,
+}
+
+/// A content fragment within a [`MessageDelta`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessageDeltaContent {
+    /// Index of the content part this fragment belongs to.
+    pub index: u32,
+
+    /// The type of content (e.g., "text").
+    #[serde(rename = "type")]
+    pub content_type: String,
+
+    /// Text fragment (if type is "text").
+    pub text: Option<TextDelta>,
+}
+
+/// A text fragment within a [`MessageDeltaContent`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct TextDelta {
+    /// The text appended by this fragment.
+    pub value: Option<String>,
+
+    /// Annotations added by this fragment.
+    #[serde(default)]
+    pub annotations: Vec<serde_json::Value>,
+}
+
 /// Response from listing messages.
 #[derive(Debug, Clone, Deserialize)]
 pub struct MessageList {
@@ -56,9 +56,13 @@ use std::time::Duration;
 use azure_ai_foundry_core::client::FoundryClient;
 use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
 use azure_ai_foundry_core::models::Usage;
+use azure_ai_foundry_core::sse::{self, SseEvent};
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 
+use crate::message::{Message, MessageDelta};
 use crate::models::API_VERSION;
+use crate::run_step::RunStep;
 use crate::thread::Thread;
 
 // ---------------------------------------------------------------------------
@@ -677,6 +681,158 @@ pub async fn create_thread_and_run(
     Ok(run)
 }
 
+/// An event received while streaming a run.
+///
+/// Events are classified by their SSE `event:` name. Lifecycle events carry
+/// the updated object; events without a dedicated variant (thread creation,
+/// run step deltas, ...) are passed through as [`RunStreamEvent::Other`].
+#[derive(Debug, Clone)]
+pub enum RunStreamEvent {
+    /// A `thread.run.*` event carrying the updated run.
+    Run {
+        /// The event name (e.g. `thread.run.completed`).
+        event: String,
+        /// The run state at the time of the event.
+        run: Box<Run>,
+    },
+    /// A `thread.run.step.*` event carrying the updated run step.
+    RunStep {
+        /// The event name (e.g. `thread.run.step.created`).
+        event: String,
+        /// The run step state at the time of the event.
+        step: Box<RunStep>,
+    },
+    /// A `thread.message.*` event carrying the updated message.
+    Message {
+        /// The event name (e.g. `thread.message.completed`).
+        event: String,
+        /// The message state at the time of the event.
+        message: Box<Message>,
+    },
+    /// A `thread.message.delta` event with an incremental message update.
+    MessageDelta(MessageDelta),
+    /// The stream has finished.
+    Done,
+    /// Any other event.
+    Other {
+        /// The event name.
+        event: String,
+        /// The raw event payload.
+        data: serde_json::Value,
+    },
+}
+
+/// Create a run on a thread and stream its events as they happen.
+///
+/// Sends the request with `stream: true`. The SSE stream is decoded by
+/// [`azure_ai_foundry_core::sse`]; an in-stream `error` event is yielded as
+/// [`FoundryError::Api`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_agents::run::{self, RunCreateRequest, RunStreamEvent};
+/// # use futures::StreamExt;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let request = RunCreateRequest::builder()
+///     .assistant_id("asst_abc123")
+///     .try_build()?;
+///
+/// let stream = run::create_stream(client, "thread_xyz", &request).await?;
+/// let mut stream = std::pin::pin!(stream);
+/// while let Some(event) = stream.next().await {
+///     match event? {
+///         RunStreamEvent::MessageDelta(delta) => print!("{}", delta.text()),
+///         RunStreamEvent::Run { run, .. } => println!("\n[run {}]", run.status),
+///         _ => {}
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::runs::create_stream` with fields `thread_id` and `assistant_id`.
+#[tracing::instrument(
+    name = "foundry::runs::create_stream",
+    skip(client, request),
+    fields(thread_id = %thread_id, assistant_id = %request.assistant_id)
+)]
+pub async fn create_stream(
+    client: &FoundryClient,
+    thread_id: &str,
+    request: &RunCreateRequest,
+) -> FoundryResult<impl Stream<Item = FoundryResult<RunStreamEvent>>> {
+    tracing::debug!("creating streaming run");
+    FoundryClient::validate_resource_id(thread_id)?;
+    let path = format!("/threads/{}/runs?{}", thread_id, API_VERSION);
+    let stream_request = StreamingRunRequest {
+        request,
+        stream: true,
+    };
+    let response = client.post_stream(&path, &stream_request).await?;
+
+    tracing::debug!("stream initiated");
+
+    Ok(sse::decode(response.bytes_stream())
+        .map(|event| event.and_then(|event| parse_run_event(&event))))
+}
+
+/// Internal request type for streaming runs.
+///
+/// Borrows the [`RunCreateRequest`] and adds `stream: true`.
+#[derive(Serialize)]
+struct StreamingRunRequest<'a> {
+    #[serde(flatten)]
+    request: &'a RunCreateRequest,
+    stream: bool,
+}
+
+/// Classify a decoded SSE event by its name and parse its payload.
+fn parse_run_event(event: &SseEvent) -> FoundryResult<RunStreamEvent> {
+    let name = event.event.as_str();
+    if name == "done" || event.is_done() {
+        return Ok(RunStreamEvent::Done);
+    }
+
+    let parsed = if name == "thread.message.delta" {
+        serde_json::from_str(&event.data).map(RunStreamEvent::MessageDelta)
+    } else if name == "thread.run.step.delta" {
+        return Ok(other_event(event));
+    } else if name.starts_with("thread.run.step.") {
+        serde_json::from_str(&event.data).map(|step| RunStreamEvent::RunStep {
+            event: name.to_owned(),
+            step: Box::new(step),
+        })
+    } else if name.starts_with("thread.run.") {
+        serde_json::from_str(&event.data).map(|run| RunStreamEvent::Run {
+            event: name.to_owned(),
+            run: Box::new(run),
+        })
+    } else if name.starts_with("thread.message.") {
+        serde_json::from_str(&event.data).map(|message| RunStreamEvent::Message {
+            event: name.to_owned(),
+            message: Box::new(message),
+        })
+    } else {
+        return Ok(other_event(event));
+    };
+
+    parsed.map_err(|e| FoundryError::stream_with_source("failed to parse run event", e))
+}
+
+/// Pass an event through as [`RunStreamEvent::Other`], keeping non-JSON payloads as strings.
+fn other_event(event: &SseEvent) -> RunStreamEvent {
+    let data = serde_json::from_str(&event.data)
+        .unwrap_or_else(|_| serde_json::Value::String(event.data.clone()));
+    RunStreamEvent::Other {
+        event: event.event.clone(),
+        data,
+    }
+}
+
 /// Poll a run until it reaches a terminal state.
 ///
 /// Returns the final run state when it completes, fails, or is cancelled.
@@ -1700,4 +1856,131 @@ mod tests {
             );
         }
     }
+
+    // --- Run streaming ---
+
+    fn run_event_body() -> String {
+        let run = serde_json::json!({
+            "id": "run_s1",
+            "object": "thread.run",
+            "created_at": TEST_TIMESTAMP,
+            "thread_id": "thread_s1",
+            "assistant_id": "asst_abc",
+            "status": "completed"
+        });
+        let delta = serde_json::json!({
+            "id": "msg_1",
+            "object": "thread.message.delta",
+            "delta": {"content": [{"index": 0, "type": "text", "text": {"value": "Hel"}}]}
+        });
+        let delta2 = serde_json::json!({
+            "id": "msg_1",
+            "object": "thread.message.delta",
+            "delta": {"content": [{"index": 0, "type": "text", "text": {"value": "lo"}}]}
+        });
+        format!(
+            "event: thread.message.delta\ndata: {}\n\n\
+             event: thread.message.delta\ndata: {}\n\n\
+             event: thread.run.completed\ndata: {}\n\n\
+             event: done\ndata: [DONE]\n\n",
+            delta, delta2, run
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_stream_yields_typed_events() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/threads/thread_s1/runs"))
+            .and(body_json(serde_json::json!({
+                "assistant_id": "asst_abc",
+                "stream": true
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(run_event_body())
+                    .insert_header("content-type", "text/event-stream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = RunCreateRequest::builder().assistant_id("asst_abc").build();
+
+        let stream = create_stream(&client, "thread_s1", &request)
+            .await
+            .expect("should start stream");
+        let events: Vec<_> = stream.collect().await;
+
+        assert_eq!(events.len(), 4);
+        let text: String = events
+            .iter()
+            .filter_map(|e| match e {
+                Ok(RunStreamEvent::MessageDelta(delta)) => Some(delta.text()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(text, "Hello");
+        match &events[2] {
+            Ok(RunStreamEvent::Run { event, run }) => {
+                assert_eq!(event, "thread.run.completed");
+                assert_eq!(run.status, RunStatus::Completed);
+            }
+            other => panic!("Expected run event, got {:?}", other),
+        }
+        assert!(matches!(events[3], Ok(RunStreamEvent::Done)));
+    }
+
+    #[tokio::test]
+    async fn test_create_stream_error_event_is_api_error() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/threads/thread_s1/runs"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(
+                        "event: error\ndata: {\"error\":{\"code\":\"server_error\",\"message\":\"boom\"}}\n\n",
+                    )
+                    .insert_header("content-type", "text/event-stream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = RunCreateRequest::builder().assistant_id("asst_abc").build();
+
+        let stream = create_stream(&client, "thread_s1", &request)
+            .await
+            .expect("should start stream");
+        let events: Vec<_> = stream.collect().await;
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            Err(FoundryError::Api { code, .. }) if code == "server_error"
+        ));
+    }
+
+    #[test]
+    fn test_parse_run_event_passes_unknown_events_through() {
+        let event = SseEvent {
+            event: "thread.run.step.delta".into(),
+            data: r#"{"id":"step_1"}"#.into(),
+            id: None,
+            retry: None,
+        };
+        match parse_run_event(&event).expect("should parse") {
+            RunStreamEvent::Other { event, data } => {
+                assert_eq!(event, "thread.run.step.delta");
+                assert_eq!(data["id"], "step_1");
+            }
+            other => panic!("Expected Other, got {:?}", other),
+        }
+    }
 }
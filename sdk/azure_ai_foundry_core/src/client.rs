@@ -236,6 +236,8 @@ pub struct FoundryClient {
     api_version: String,
     retry_policy: RetryPolicy,
     streaming_timeout: Duration,
+    stream_idle_timeout: Option<Duration>,
+    max_stream_resumes: u32,
 }
 
 /// Builder for constructing a [`FoundryClient`].
@@ -250,6 +252,8 @@ pub struct FoundryClientBuilder {
     connect_timeout: Option<Duration>,
     read_timeout: Option<Duration>,
     streaming_timeout: Option<Duration>,
+    stream_idle_timeout: Option<Duration>,
+    max_stream_resumes: Option<u32>,
     retry_policy: Option<RetryPolicy>,
 }
 
@@ -274,6 +278,13 @@ impl FoundryClient {
         &self.retry_policy
     }
 
+    /// Get the credential used to authenticate requests.
+    ///
+    /// Useful for transports the client does not wrap itself, such as WebSockets.
+    pub fn credential(&self) -> &FoundryCredential {
+        &self.credential
+    }
+
     /// Get the streaming timeout duration.
     ///
     /// This is the maximum time allowed for streaming responses.
@@ -281,6 +292,19 @@ impl FoundryClient {
         self.streaming_timeout
     }
 
+    /// Get the per-chunk idle timeout for streaming responses, if any.
+    ///
+    /// A stream that receives no bytes for this long is ended with
+    /// [`FoundryError::StreamInterrupted`].
+    pub fn stream_idle_timeout(&self) -> Option<Duration> {
+        self.stream_idle_timeout
+    }
+
+    /// Get the maximum number of times an interrupted stream is resumed.
+    pub fn max_stream_resumes(&self) -> u32 {
+        self.max_stream_resumes
+    }
+
     /// Build a full URL for an API path.
     ///
     /// # Arguments
@@ -310,15 +334,27 @@ impl FoundryClient {
         F: Fn(String) -> Fut,
         Fut: std::future::Future<Output = Result<reqwest::Response, reqwest::Error>>,
     {
-        for attempt in 0..=self.retry_policy.max_retries {
-            let span = tracing::Span::current();
-            span.record("attempt", attempt);
-
+        self.retry(|| async {
             // Resolve credential on each attempt to handle token expiration during retries.
             // The internal cache ensures this is O(1) when the token is still valid.
             let auth = self.credential.resolve().await?;
+            Ok(build_and_send(auth).await?)
+        })
+        .await
+    }
 
-            let response = build_and_send(auth).await?;
+    /// Run `send` until it succeeds, fails with a non-retriable status, or
+    /// the retry policy is exhausted.
+    async fn retry<F, Fut>(&self, send: F) -> FoundryResult<reqwest::Response>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = FoundryResult<reqwest::Response>>,
+    {
+        for attempt in 0..=self.retry_policy.max_retries {
+            let span = tracing::Span::current();
+            span.record("attempt", attempt);
+
+            let response = send().await?;
 
             let status = response.status().as_u16();
             span.record("status_code", status);
@@ -418,17 +454,52 @@ impl FoundryClient {
         &self,
         path: &str,
         body: &T,
+    ) -> FoundryResult<reqwest::Response> {
+        self.send_post(path, body, &[]).await
+    }
+
+    /// Send a POST request with a JSON body and extra HTTP headers.
+    ///
+    /// Behaves like [`Self::post`]; `headers` are added to every attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if authentication fails, serialization fails,
+    /// the request fails after all retries, or the server returns a non-retriable error.
+    #[tracing::instrument(
+        name = "foundry::client::post",
+        skip(self, body, headers),
+        fields(path = %path, attempt, status_code)
+    )]
+    pub async fn post_with_headers<T: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &T,
+        headers: &[(&str, &str)],
+    ) -> FoundryResult<reqwest::Response> {
+        self.send_post(path, body, headers).await
+    }
+
+    /// Shared implementation of [`Self::post`] and [`Self::post_with_headers`].
+    async fn send_post<T: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &T,
+        headers: &[(&str, &str)],
     ) -> FoundryResult<reqwest::Response> {
         let url = self.url(path)?;
         tracing::debug!("sending POST request");
 
         self.execute_with_retry(|auth| {
-            self.http
+            let mut request = self
+                .http
                 .post(url.clone())
                 .header("Authorization", auth)
-                .header("api-version", &self.api_version)
-                .json(body)
-                .send()
+                .header("api-version", &self.api_version);
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            request.json(body).send()
         })
         .await
     }
@@ -554,19 +625,55 @@ impl FoundryClient {
         &self,
         path: &str,
         body: &T,
+    ) -> FoundryResult<reqwest::Response> {
+        self.send_stream(path, body, &[]).await
+    }
+
+    /// Send a POST request for streaming responses with extra HTTP headers.
+    ///
+    /// Behaves like [`Self::post_stream`]; `headers` are added to every
+    /// attempt. Used to resume a stream with `Last-Event-ID`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if authentication fails, serialization fails,
+    /// the request fails, or the HTTP status code indicates an error.
+    #[tracing::instrument(
+        name = "foundry::client::post_stream",
+        skip(self, body, headers),
+        fields(path = %path, attempt, status_code, streaming_timeout_secs = self.streaming_timeout.as_secs())
+    )]
+    pub async fn post_stream_with_headers<T: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &T,
+        headers: &[(&str, &str)],
+    ) -> FoundryResult<reqwest::Response> {
+        self.send_stream(path, body, headers).await
+    }
+
+    /// Shared implementation of [`Self::post_stream`] and [`Self::post_stream_with_headers`].
+    async fn send_stream<T: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &T,
+        headers: &[(&str, &str)],
     ) -> FoundryResult<reqwest::Response> {
         let url = self.url(path)?;
         let streaming_timeout = self.streaming_timeout;
         tracing::debug!("sending POST request for streaming");
 
         self.execute_with_retry(|auth| {
-            self.http
+            let mut request = self
+                .http
                 .post(url.clone())
                 .header("Authorization", auth)
                 .header("api-version", &self.api_version)
-                .timeout(streaming_timeout)
-                .json(body)
-                .send()
+                .timeout(streaming_timeout);
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            request.json(body).send()
         })
         .await
     }
@@ -662,6 +769,39 @@ impl FoundryClient {
         Ok(response.bytes().await?)
     }
 
+    /// Download a URL outside the API, such as a pre-signed storage link
+    /// returned by the service.
+    ///
+    /// No credentials are sent. The client's HTTP settings (timeouts, proxy)
+    /// and retry policy apply.
+    ///
+    /// # Tracing
+    ///
+    /// This method emits a span named `foundry::client::download` with the
+    /// fields `attempt` and `status_code`. The URL is not recorded, as it
+    /// may carry a signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `url` is invalid, the request fails after all
+    /// retries, or the server returns a non-retriable error response.
+    #[tracing::instrument(
+        name = "foundry::client::download",
+        skip(self, url),
+        fields(attempt, status_code)
+    )]
+    pub async fn download(&self, url: &str) -> FoundryResult<bytes::Bytes> {
+        let url = Url::parse(url)
+            .map_err(|e| FoundryError::validation(format!("invalid download URL: {}", e)))?;
+        tracing::debug!("sending GET request for download");
+
+        let response = self
+            .retry(|| async { Ok(self.http.get(url.clone()).send().await?) })
+            .await?;
+
+        Ok(response.bytes().await?)
+    }
+
     /// Maximum length for error messages to prevent sensitive data leaks.
     const MAX_ERROR_MESSAGE_LEN: usize = 1000;
 
@@ -1007,6 +1147,32 @@ impl FoundryClientBuilder {
         self
     }
 
+    /// Set the per-chunk idle timeout for streaming responses.
+    ///
+    /// Unlike [`streaming_timeout`](Self::streaming_timeout), which bounds the
+    /// whole request, this ends a stream that stalls mid-way: if no bytes
+    /// (including keep-alive comments) arrive for `timeout`, the stream yields
+    /// [`FoundryError::StreamInterrupted`] carrying the partial content.
+    ///
+    /// Disabled by default.
+    pub fn stream_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.stream_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Set how many times an interrupted stream is transparently resumed.
+    ///
+    /// Resumption re-sends the request with a `Last-Event-ID` header and is
+    /// only attempted when the server sent SSE event IDs. Otherwise, or once
+    /// the attempts are used up, the stream yields
+    /// [`FoundryError::StreamInterrupted`].
+    ///
+    /// Defaults to `0` (no resumption).
+    pub fn max_stream_resumes(mut self, resumes: u32) -> Self {
+        self.max_stream_resumes = Some(resumes);
+        self
+    }
+
     /// Set the retry policy for transient errors.
     ///
     /// Configures automatic retries for retriable HTTP errors (429, 500, 502, 503, 504)
@@ -1082,6 +1248,8 @@ impl FoundryClientBuilder {
                 .unwrap_or_else(|| DEFAULT_API_VERSION.to_string()),
             retry_policy: self.retry_policy.unwrap_or_default(),
             streaming_timeout: self.streaming_timeout.unwrap_or(DEFAULT_STREAMING_TIMEOUT),
+            stream_idle_timeout: self.stream_idle_timeout,
+            max_stream_resumes: self.max_stream_resumes.unwrap_or(0),
         })
     }
 }
@@ -1591,6 +1759,94 @@ mod tests {
         assert_eq!(client.streaming_timeout(), Duration::from_secs(180));
     }
 
+    #[test]
+    fn test_builder_accepts_stream_idle_timeout_and_resumes() {
+        let client = FoundryClient::builder()
+            .endpoint("https://test.services.ai.azure.com")
+            .credential(FoundryCredential::api_key("test"))
+            .stream_idle_timeout(Duration::from_secs(30))
+            .max_stream_resumes(2)
+            .build()
+            .expect("should build");
+
+        assert_eq!(client.stream_idle_timeout(), Some(Duration::from_secs(30)));
+        assert_eq!(client.max_stream_resumes(), 2);
+    }
+
+    #[test]
+    fn test_stream_idle_timeout_and_resumes_disabled_by_default() {
+        let client = FoundryClient::builder()
+            .endpoint("https://test.services.ai.azure.com")
+            .credential(FoundryCredential::api_key("test"))
+            .build()
+            .expect("should build");
+
+        assert!(client.stream_idle_timeout().is_none());
+        assert_eq!(client.max_stream_resumes(), 0);
+    }
+
+    #[tokio::test]
+    async fn post_stream_with_headers_sends_headers() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/stream-resume"))
+            .and(header("Last-Event-ID", "42"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("data: test\n\n")
+                    .insert_header("content-type", "text/event-stream"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = FoundryClient::builder()
+            .endpoint(server.uri())
+            .credential(FoundryCredential::api_key("test"))
+            .build()
+            .expect("should build");
+
+        let response = client
+            .post_stream_with_headers(
+                "/stream-resume",
+                &serde_json::json!({}),
+                &[("Last-Event-ID", "42")],
+            )
+            .await
+            .expect("should succeed");
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn post_with_headers_sends_headers() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/with-headers"))
+            .and(header("extra-parameters", "pass-through"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = FoundryClient::builder()
+            .endpoint(server.uri())
+            .credential(FoundryCredential::api_key("test"))
+            .build()
+            .expect("should build");
+
+        let response = client
+            .post_with_headers(
+                "/with-headers",
+                &serde_json::json!({}),
+                &[("extra-parameters", "pass-through")],
+            )
+            .await
+            .expect("should succeed");
+        assert_eq!(response.status(), 200);
+    }
+
     #[test]
     fn test_default_streaming_timeout_is_5_minutes() {
         use std::time::Duration;
@@ -2875,6 +3131,66 @@ mod tests {
         assert_eq!(bytes.as_ref(), b"retried data");
     }
 
+    #[tokio::test]
+    async fn test_download_retries_without_credentials() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/blob/image.png"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/blob/image.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"png".to_vec()))
+            .mount(&server)
+            .await;
+
+        let client = FoundryClient::builder()
+            .endpoint("https://example.services.ai.azure.com")
+            .credential(FoundryCredential::api_key("test-api-key"))
+            .retry_policy(RetryPolicy::new(3, Duration::from_millis(10)).unwrap())
+            .build()
+            .expect("should build");
+
+        let bytes = client
+            .download(&format!("{}/blob/image.png?sig=abc", server.uri()))
+            .await
+            .expect("should succeed after retry");
+        assert_eq!(bytes.as_ref(), b"png");
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests
+            .iter()
+            .all(|r| !r.headers.contains_key("authorization")));
+    }
+
+    #[tokio::test]
+    async fn test_download_times_out_with_read_timeout() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/blob/slow.png"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+            .mount(&server)
+            .await;
+
+        let client = FoundryClient::builder()
+            .endpoint("https://example.services.ai.azure.com")
+            .credential(FoundryCredential::api_key("test-api-key"))
+            .read_timeout(Duration::from_millis(100))
+            .retry_policy(RetryPolicy::new(0, Duration::ZERO).unwrap())
+            .build()
+            .expect("should build");
+
+        let result = client
+            .download(&format!("{}/blob/slow.png", server.uri()))
+            .await;
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_get_bytes_returns_error_on_404() {
         let server = MockServer::start().await;
@@ -28,6 +28,10 @@ pub enum FoundryError {
     #[error("Request error: {0}")]
     Request(#[from] reqwest::Error),
 
+    /// A local file operation failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     /// The endpoint URL is invalid.
     #[error("Invalid endpoint URL: {message}")]
     InvalidEndpoint {
@@ -52,6 +56,22 @@ pub enum FoundryError {
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
 
+    /// A stream stopped before completing (idle timeout or dropped connection).
+    ///
+    /// Unlike [`Stream`](Self::Stream), this is recoverable: it carries the
+    /// content received so far and, when the server sent event IDs, the last
+    /// one seen so the stream can be resumed.
+    #[error("Stream interrupted: {message}")]
+    StreamInterrupted {
+        message: String,
+        /// Content received before the interruption, indexed by choice.
+        partial_content: Vec<String>,
+        /// The last SSE event ID received, usable as `Last-Event-ID`.
+        last_event_id: Option<String>,
+        #[source]
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
     /// An error from the Azure SDK.
     #[error("Azure SDK error: {message}")]
     AzureSdk {
@@ -163,6 +183,19 @@ impl FoundryError {
         }
     }
 
+    /// Creates a stream interruption error with no partial content yet.
+    ///
+    /// Callers that track the streamed content fill in `partial_content`
+    /// before surfacing the error.
+    pub fn stream_interrupted(message: impl Into<String>, last_event_id: Option<String>) -> Self {
+        Self::StreamInterrupted {
+            message: message.into(),
+            partial_content: Vec::new(),
+            last_event_id,
+            source: None,
+        }
+    }
+
     /// Creates a validation error without a field name.
     pub fn validation(message: impl Into<String>) -> Self {
         Self::Validation {
@@ -182,11 +215,13 @@ impl FoundryError {
     /// Returns `true` if this error is likely transient and the request may
     /// succeed on retry.
     ///
-    /// Retryable errors are HTTP 429 (rate limit), 500, 502, 503, and 504.
-    /// All other error types (validation, auth, client errors) are not retryable.
+    /// Retryable errors are HTTP 429 (rate limit), 500, 502, 503, and 504,
+    /// and interrupted streams. All other error types (validation, auth,
+    /// client errors) are not retryable.
     pub fn is_retryable(&self) -> bool {
         match self {
             Self::Http { status, .. } => crate::client::is_retriable_status(*status),
+            Self::StreamInterrupted { .. } => true,
             _ => false,
         }
     }
@@ -204,6 +239,23 @@ mod tests {
         assert!(err.is_retryable(), "429 should be retryable");
     }
 
+    #[test]
+    fn foundry_error_from_io_error() {
+        use std::io;
+
+        let err = FoundryError::from(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        assert!(matches!(err, FoundryError::Io(_)));
+        assert!(!err.is_retryable());
+        assert_eq!(err.to_string(), "I/O error: missing");
+    }
+
+    #[test]
+    fn foundry_error_is_retryable_for_stream_interrupted() {
+        let err = FoundryError::stream_interrupted("idle timeout", Some("7".into()));
+        assert!(err.is_retryable());
+        assert_eq!(err.to_string(), "Stream interrupted: idle timeout");
+    }
+
     #[test]
     fn foundry_error_is_retryable_for_server_errors() {
         for status in [500, 502, 503, 504] {
@@ -4,6 +4,7 @@ pub mod auth;
 pub mod client;
 pub mod error;
 pub mod models;
+pub mod sse;
 
 pub use error::FoundryError;
 
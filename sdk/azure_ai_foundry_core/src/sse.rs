@@ -0,0 +1,698 @@
+//! Server-Sent Events (SSE) decoding.
+//!
+//! Streaming endpoints across Azure AI Foundry (chat completions, the Responses
+//! API and agent runs) deliver their output as `text/event-stream`. This module
+//! implements the [WHATWG event stream interpretation] once so every crate in
+//! the SDK parses those streams the same way:
+//!
+//! - Lines may be terminated by `\n`, `\r\n` or a lone `\r`.
+//! - `data:` fields spanning several lines are joined with `\n`.
+//! - `event:`, `id:` and `retry:` fields are honoured; the last event ID
+//!   persists across events so a caller can resume with `Last-Event-ID`.
+//! - Comment lines (starting with `:`) and unknown fields are ignored.
+//! - A leading byte order mark is skipped and invalid UTF-8 is decoded with
+//!   replacement characters instead of dropping the line.
+//! - An event left without its terminating blank line when the stream ends is
+//!   discarded.
+//!
+//! [`decode`] additionally turns Azure in-stream `error` events into
+//! [`FoundryError::Api`] so callers do not mistake them for payloads.
+//!
+//! [WHATWG event stream interpretation]: https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation
+//!
+//! ## Example
+//!
+//! ```rust
+//! use azure_ai_foundry_core::sse::SseDecoder;
+//!
+//! let mut decoder = SseDecoder::new();
+//! let events = decoder
+//!     .feed(b"event: greeting\ndata: hello\ndata: world\n\n")
+//!     .unwrap();
+//!
+//! assert_eq!(events.len(), 1);
+//! assert_eq!(events[0].event, "greeting");
+//! assert_eq!(events[0].data, "hello\nworld");
+//! ```
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::Deserialize;
+
+use crate::error::{FoundryError, FoundryResult};
+
+/// Maximum number of buffered bytes for a single SSE line or event (1MB).
+///
+/// This limit prevents denial-of-service attacks where a malicious server
+/// could send an extremely long line without newlines, or an endless event
+/// without a blank line, causing unbounded memory consumption.
+pub const SSE_BUFFER_LIMIT: usize = 1_048_576;
+
+/// The event type used when an event has no `event:` field.
+pub const DEFAULT_EVENT_TYPE: &str = "message";
+
+/// UTF-8 byte order mark, skipped at the start of a stream.
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// A single dispatched Server-Sent Event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    /// The event type (`"message"` when the stream did not set one).
+    pub event: String,
+
+    /// The event payload, with multi-line `data:` fields joined by `\n`.
+    pub data: String,
+
+    /// The last event ID seen on the stream when this event was dispatched.
+    pub id: Option<String>,
+
+    /// The reconnection delay most recently requested by the server.
+    pub retry: Option<Duration>,
+}
+
+impl SseEvent {
+    /// Returns `true` for the OpenAI-style `[DONE]` end-of-stream sentinel.
+    pub fn is_done(&self) -> bool {
+        self.data.trim() == "[DONE]"
+    }
+
+    /// Returns the error carried by an Azure in-stream error event, if any.
+    ///
+    /// Recognises an `event: error` record as well as JSON payloads shaped as
+    /// `{"error": {...}}` or `{"type": "error", ...}`.
+    pub fn error(&self) -> Option<FoundryError> {
+        let is_error_event = self.event == "error";
+        if !is_error_event && !self.data.contains("\"error\"") {
+            return None;
+        }
+
+        let value = serde_json::from_str::<serde_json::Value>(&self.data).ok();
+        let body = match &value {
+            Some(serde_json::Value::Object(map)) => match map.get("error") {
+                Some(error @ serde_json::Value::Object(_)) => Some(error),
+                _ if map.get("type").and_then(|t| t.as_str()) == Some("error") => value.as_ref(),
+                _ if is_error_event => value.as_ref(),
+                _ => return None,
+            },
+            _ if is_error_event => None,
+            _ => return None,
+        };
+
+        let (code, message) = match body.and_then(|b| ErrorBody::deserialize(b).ok()) {
+            Some(body) => (body.code, body.message),
+            None => (None, None),
+        };
+
+        Some(FoundryError::Api {
+            code: code.unwrap_or_else(|| "unknown".into()),
+            message: message.unwrap_or_else(|| self.data.clone()),
+        })
+    }
+
+    /// Convert an in-stream error event into `Err`, passing other events through.
+    pub fn into_result(self) -> FoundryResult<Self> {
+        match self.error() {
+            Some(err) => Err(err),
+            None => Ok(self),
+        }
+    }
+}
+
+/// The fields of an in-stream error payload.
+#[derive(Deserialize)]
+struct ErrorBody {
+    #[serde(default, deserialize_with = "deserialize_code")]
+    code: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// Accept error codes sent either as strings or as numbers.
+fn deserialize_code<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::String(s)) => Some(s),
+            Some(serde_json::Value::Number(n)) => Some(n.to_string()),
+            _ => None,
+        },
+    )
+}
+
+/// Incremental, push-based SSE decoder.
+///
+/// Feed it raw bytes as they arrive with [`feed`](Self::feed); it returns the
+/// events completed by those bytes and keeps any partial line or event for the
+/// next call.
+#[derive(Debug, Default)]
+pub struct SseDecoder {
+    /// Bytes of the current, not yet terminated line.
+    buffer: Vec<u8>,
+    /// Data buffer of the event being assembled.
+    data: String,
+    /// Event type buffer of the event being assembled.
+    event_type: String,
+    /// Last event ID buffer.
+    last_event_id: Option<String>,
+    /// Most recent `retry:` value.
+    retry: Option<Duration>,
+    /// Whether the previous line ended with `\r` (so a leading `\n` belongs to it).
+    skip_lf: bool,
+    /// Whether the byte order mark check has been done.
+    started: bool,
+}
+
+impl SseDecoder {
+    /// Create a new decoder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The last event ID seen on the stream, for use as `Last-Event-ID`.
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// The reconnection delay most recently requested by the server.
+    pub fn retry(&self) -> Option<Duration> {
+        self.retry
+    }
+
+    /// Decode a chunk of bytes, returning the events it completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FoundryError::Stream`] when a single line or event grows past
+    /// [`SSE_BUFFER_LIMIT`]. The partial input is discarded.
+    pub fn feed(&mut self, bytes: &[u8]) -> FoundryResult<Vec<SseEvent>> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut events = Vec::new();
+        let mut start = 0;
+
+        loop {
+            if self.skip_lf && start < self.buffer.len() {
+                if self.buffer[start] == b'\n' {
+                    start += 1;
+                }
+                self.skip_lf = false;
+            }
+
+            let Some(offset) = memchr::memchr2(b'\n', b'\r', &self.buffer[start..]) else {
+                break;
+            };
+            let end = start + offset;
+            self.skip_lf = self.buffer[end] == b'\r';
+
+            let mut line = &self.buffer[start..end];
+            if !self.started {
+                line = line.strip_prefix(BOM).unwrap_or(line);
+                self.started = true;
+            }
+            let line = String::from_utf8_lossy(line).into_owned();
+            start = end + 1;
+
+            if let Some(event) = self.process_line(&line) {
+                events.push(event);
+            }
+        }
+
+        self.buffer.drain(..start);
+
+        if self.buffer.len() > SSE_BUFFER_LIMIT || self.data.len() > SSE_BUFFER_LIMIT {
+            self.buffer.clear();
+            self.data.clear();
+            self.event_type.clear();
+            return Err(FoundryError::stream(format!(
+                "SSE buffer limit exceeded ({}MB). Possible malformed stream.",
+                SSE_BUFFER_LIMIT / 1_048_576
+            )));
+        }
+
+        Ok(events)
+    }
+
+    /// Apply a single line to the decoder state, dispatching on blank lines.
+    fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+
+        match field {
+            "event" => self.event_type = value.to_owned(),
+            "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            }
+            "id" if !value.contains('\0') => {
+                self.last_event_id = (!value.is_empty()).then(|| value.to_owned());
+            }
+            "retry" if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+                if let Ok(ms) = value.parse::<u64>() {
+                    self.retry = Some(Duration::from_millis(ms));
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Dispatch the event being assembled, if it carries any data.
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event_type = std::mem::take(&mut self.event_type);
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let mut data = std::mem::take(&mut self.data);
+        data.pop(); // trailing '\n' appended by the last `data:` line
+
+        Some(SseEvent {
+            event: if event_type.is_empty() {
+                DEFAULT_EVENT_TYPE.to_owned()
+            } else {
+                event_type
+            },
+            data,
+            id: self.last_event_id.clone(),
+            retry: self.retry,
+        })
+    }
+}
+
+/// Decode a byte stream into a stream of [`SseEvent`]s.
+///
+/// In-stream error events are yielded as `Err` (see [`SseEvent::error`]).
+/// A transport error after the stream started is yielded as
+/// [`FoundryError::StreamInterrupted`]. The stream ends after the first
+/// transport or buffer-limit error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::sse;
+/// # use futures::StreamExt;
+/// # async fn example(response: reqwest::Response) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let mut events = std::pin::pin!(sse::decode(response.bytes_stream()));
+/// while let Some(event) = events.next().await {
+///     let event = event?;
+///     println!("{}: {}", event.event, event.data);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode<S, B, E>(bytes: S) -> impl Stream<Item = FoundryResult<SseEvent>>
+where
+    S: Stream<Item = Result<B, E>>,
+    B: AsRef<[u8]>,
+    E: Into<FoundryError>,
+{
+    decode_with_idle_timeout(bytes, None)
+}
+
+/// Decode a byte stream like [`decode`], ending it if it stalls.
+///
+/// When `idle_timeout` is set and no bytes arrive for that long, the stream
+/// yields [`FoundryError::StreamInterrupted`] (with the last event ID seen)
+/// and ends. Keep-alive comments count as activity.
+pub fn decode_with_idle_timeout<S, B, E>(
+    bytes: S,
+    idle_timeout: Option<Duration>,
+) -> impl Stream<Item = FoundryResult<SseEvent>>
+where
+    S: Stream<Item = Result<B, E>>,
+    B: AsRef<[u8]>,
+    E: Into<FoundryError>,
+{
+    let state = DecodeState {
+        bytes: Box::pin(with_idle_timeout(bytes, idle_timeout)),
+        decoder: SseDecoder::new(),
+        pending: VecDeque::new(),
+        finished: false,
+    };
+
+    stream::unfold(state, move |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((event.into_result(), state));
+            }
+            if state.finished {
+                return None;
+            }
+
+            match state.bytes.next().await {
+                Some(Ok(chunk)) => match state.decoder.feed(chunk.as_ref()) {
+                    Ok(events) => state.pending.extend(events),
+                    Err(e) => {
+                        state.finished = true;
+                        return Some((Err(e), state));
+                    }
+                },
+                Some(Err(mut e)) => {
+                    state.finished = true;
+                    if let FoundryError::StreamInterrupted { last_event_id, .. } = &mut e {
+                        *last_event_id = state.decoder.last_event_id().map(str::to_owned);
+                    }
+                    return Some((Err(e), state));
+                }
+                None => return None,
+            }
+        }
+    })
+}
+
+/// Guard a raw byte stream the way [`decode_with_idle_timeout`] does, for
+/// streaming responses that are not SSE (such as raw audio).
+///
+/// A transport error, or no bytes for `idle_timeout` when it is set, yields
+/// [`FoundryError::StreamInterrupted`] and ends the stream.
+pub fn with_idle_timeout<S, B, E>(
+    bytes: S,
+    idle_timeout: Option<Duration>,
+) -> impl Stream<Item = FoundryResult<B>>
+where
+    S: Stream<Item = Result<B, E>>,
+    E: Into<FoundryError>,
+{
+    let bytes = Box::pin(bytes.map_err(Into::into));
+    stream::unfold((bytes, false), move |(mut bytes, finished)| async move {
+        if finished {
+            return None;
+        }
+
+        let next = match idle_timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, bytes.try_next()).await {
+                Ok(next) => next,
+                Err(_) => {
+                    let err = FoundryError::stream_interrupted(
+                        format!("no data received for {:?}", timeout),
+                        None,
+                    );
+                    return Some((Err(err), (bytes, true)));
+                }
+            },
+            None => bytes.try_next().await,
+        };
+
+        match next {
+            Ok(Some(chunk)) => Some((Ok(chunk), (bytes, false))),
+            Ok(None) => None,
+            Err(e) => {
+                let err = FoundryError::StreamInterrupted {
+                    message: format!("connection lost: {}", e),
+                    partial_content: Vec::new(),
+                    last_event_id: None,
+                    source: Some(Box::new(e)),
+                };
+                Some((Err(err), (bytes, true)))
+            }
+        }
+    })
+}
+
+/// State threaded through [`decode_with_idle_timeout`].
+struct DecodeState<S> {
+    bytes: Pin<Box<S>>,
+    decoder: SseDecoder,
+    pending: VecDeque<SseEvent>,
+    finished: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    fn decode_all(input: &[u8]) -> Vec<SseEvent> {
+        SseDecoder::new().feed(input).expect("should decode")
+    }
+
+    #[test]
+    fn test_single_data_event() {
+        let events = decode_all(b"data: hello\n\n");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event, "message");
+        assert_eq!(events[0].data, "hello");
+        assert!(events[0].id.is_none());
+    }
+
+    #[test]
+    fn test_multi_line_data_is_joined() {
+        let events = decode_all(b"data: first\ndata: second\ndata:\n\n");
+        assert_eq!(events[0].data, "first\nsecond\n");
+    }
+
+    #[test]
+    fn test_event_id_and_retry_fields() {
+        let events = decode_all(b"event: update\nid: 42\nretry: 1500\ndata: x\n\ndata: y\n\n");
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event, "update");
+        assert_eq!(events[0].id.as_deref(), Some("42"));
+        assert_eq!(events[0].retry, Some(Duration::from_millis(1500)));
+        // Event type resets, last event ID persists.
+        assert_eq!(events[1].event, "message");
+        assert_eq!(events[1].id.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn test_invalid_retry_and_id_with_null_are_ignored() {
+        let mut decoder = SseDecoder::new();
+        decoder
+            .feed(b"retry: 10s\nid: a\0b\ndata: x\n\n")
+            .expect("should decode");
+        assert!(decoder.retry().is_none());
+        assert!(decoder.last_event_id().is_none());
+    }
+
+    #[test]
+    fn test_crlf_and_cr_line_endings() {
+        let events = decode_all(b"data: a\r\n\r\ndata: b\r\rdata: c\n\n");
+        let data: Vec<_> = events.iter().map(|e| e.data.as_str()).collect();
+        assert_eq!(data, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_crlf_split_across_chunks() {
+        let mut decoder = SseDecoder::new();
+        let mut events = decoder.feed(b"data: a\r").unwrap();
+        events.extend(decoder.feed(b"\n\r\n").unwrap());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "a");
+    }
+
+    #[test]
+    fn test_field_without_colon_and_single_space_stripping() {
+        let events = decode_all(b"data\ndata:  two spaces\n\n");
+        assert_eq!(events[0].data, "\n two spaces");
+    }
+
+    #[test]
+    fn test_comments_and_unknown_fields_are_ignored() {
+        let events = decode_all(b": keep-alive\nfoo: bar\n\n\ndata: x\n\n");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "x");
+    }
+
+    #[test]
+    fn test_leading_bom_is_skipped() {
+        let events = decode_all(b"\xEF\xBB\xBFdata: x\n\n");
+        assert_eq!(events[0].data, "x");
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_replaced_not_dropped() {
+        let events = decode_all(b"data: a\xFFb\n\n");
+        assert_eq!(events[0].data, "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn test_event_split_across_chunks() {
+        let mut decoder = SseDecoder::new();
+        assert!(decoder.feed(b"event: ping\nda").unwrap().is_empty());
+        assert!(decoder.feed(b"ta: {\"a\":").unwrap().is_empty());
+        let events = decoder.feed(b"1}\n\n").unwrap();
+        assert_eq!(events[0].event, "ping");
+        assert_eq!(events[0].data, "{\"a\":1}");
+    }
+
+    #[test]
+    fn test_buffer_limit_on_unterminated_line() {
+        let mut decoder = SseDecoder::new();
+        let err = decoder
+            .feed(&vec![b'a'; SSE_BUFFER_LIMIT + 1])
+            .expect_err("should exceed limit");
+        assert!(err.to_string().contains("buffer limit"));
+    }
+
+    #[test]
+    fn test_buffer_limit_on_undispatched_event() {
+        let mut decoder = SseDecoder::new();
+        let line = format!("data: {}\n", "a".repeat(1024));
+        let mut result = Ok(Vec::new());
+        for _ in 0..1100 {
+            result = decoder.feed(line.as_bytes());
+            if result.is_err() {
+                break;
+            }
+        }
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_is_done() {
+        let events = decode_all(b"data: [DONE]\n\n");
+        assert!(events[0].is_done());
+    }
+
+    #[test]
+    fn test_error_event_type_maps_to_api_error() {
+        let events = decode_all(
+            b"event: error\ndata: {\"error\":{\"code\":\"server_error\",\"message\":\"boom\"}}\n\n",
+        );
+        match events[0].error() {
+            Some(FoundryError::Api { code, message }) => {
+                assert_eq!(code, "server_error");
+                assert_eq!(message, "boom");
+            }
+            other => panic!("Expected Api error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_typed_error_payload_maps_to_api_error() {
+        let events =
+            decode_all(b"data: {\"type\":\"error\",\"code\":429,\"message\":\"slow down\"}\n\n");
+        match events[0].error() {
+            Some(FoundryError::Api { code, message }) => {
+                assert_eq!(code, "429");
+                assert_eq!(message, "slow down");
+            }
+            other => panic!("Expected Api error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_plain_error_event_uses_data_as_message() {
+        let events = decode_all(b"event: error\ndata: upstream closed\n\n");
+        match events[0].error() {
+            Some(FoundryError::Api { code, message }) => {
+                assert_eq!(code, "unknown");
+                assert_eq!(message, "upstream closed");
+            }
+            other => panic!("Expected Api error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_payload_mentioning_error_is_not_an_error() {
+        let events =
+            decode_all(b"data: {\"content\":\"the \\\"error\\\" word\",\"error\":null}\n\n");
+        assert!(events[0].error().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_decode_stream_yields_events_and_errors() {
+        let chunks: Vec<Result<&[u8], FoundryError>> = vec![
+            Ok(b"data: one\n\nevent: err"),
+            Ok(b"or\ndata: {\"message\":\"bad\"}\n\ndata: two\n\n"),
+        ];
+        let items: Vec<_> = decode(futures::stream::iter(chunks)).collect().await;
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].as_ref().unwrap().data, "one");
+        assert!(matches!(&items[1], Err(FoundryError::Api { message, .. }) if message == "bad"));
+        assert_eq!(items[2].as_ref().unwrap().data, "two");
+    }
+
+    #[tokio::test]
+    async fn test_decode_stream_discards_unterminated_event() {
+        let chunks: Vec<Result<&[u8], FoundryError>> = vec![Ok(b"data: one\n\ndata: partial")];
+        let items: Vec<_> = decode(futures::stream::iter(chunks)).collect().await;
+        assert_eq!(items.len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_idle_timeout_interrupts_stalled_stream() {
+        let chunks: Vec<Result<&[u8], FoundryError>> = vec![Ok(b"id: 3\ndata: one\n\n")];
+        let bytes = futures::stream::iter(chunks).chain(futures::stream::pending());
+        let items: Vec<_> = decode_with_idle_timeout(bytes, Some(Duration::from_secs(5)))
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].as_ref().unwrap().data, "one");
+        match &items[1] {
+            Err(FoundryError::StreamInterrupted { last_event_id, .. }) => {
+                assert_eq!(last_event_id.as_deref(), Some("3"));
+            }
+            other => panic!("Expected StreamInterrupted, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transport_error_is_stream_interrupted() {
+        let chunks: Vec<Result<&[u8], FoundryError>> = vec![
+            Ok(b"data: one\n\n"),
+            Err(FoundryError::stream("reset by peer")),
+        ];
+        let items: Vec<_> = decode(futures::stream::iter(chunks)).collect().await;
+
+        assert_eq!(items.len(), 2);
+        match &items[1] {
+            Err(err @ FoundryError::StreamInterrupted { last_event_id, .. }) => {
+                assert!(last_event_id.is_none());
+                assert!(err.to_string().contains("reset by peer"));
+            }
+            other => panic!("Expected StreamInterrupted, got {:?}", other),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_with_idle_timeout_guards_raw_bytes() {
+        let chunks: Vec<Result<&[u8], FoundryError>> = vec![Ok(b"RIFF")];
+        let bytes = futures::stream::iter(chunks).chain(futures::stream::pending());
+        let items: Vec<_> = with_idle_timeout(bytes, Some(Duration::from_secs(5)))
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].as_ref().unwrap(), b"RIFF");
+        assert!(matches!(
+            items[1],
+            Err(FoundryError::StreamInterrupted { .. })
+        ));
+
+        let chunks: Vec<Result<&[u8], FoundryError>> =
+            vec![Err(FoundryError::stream("reset by peer")), Ok(b"later")];
+        let items: Vec<_> = with_idle_timeout(futures::stream::iter(chunks), None)
+            .collect()
+            .await;
+        assert_eq!(items.len(), 1);
+        assert!(items[0]
+            .as_ref()
+            .is_err_and(|e| e.to_string().contains("reset by peer")));
+    }
+
+    #[tokio::test]
+    async fn test_decode_stream_stops_after_buffer_limit() {
+        let oversized = vec![b'a'; SSE_BUFFER_LIMIT + 1];
+        let chunks: Vec<Result<Vec<u8>, FoundryError>> =
+            vec![Ok(oversized), Ok(b"data: later\n\n".to_vec())];
+        let items: Vec<_> = decode(futures::stream::iter(chunks)).collect().await;
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err());
+    }
+}
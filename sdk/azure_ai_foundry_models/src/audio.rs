@@ -22,6 +22,25 @@
 //! # }
 //! ```
 //!
+//! # Captions Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_models::audio::*;
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let request = TranscriptionRequest::builder()
+//!     .model("whisper-1")
+//!     .filename("talk.wav")
+//!     .data(std::fs::read("talk.wav").unwrap())
+//!     .timestamp_granularity(TimestampGranularity::Segment)
+//!     .build();
+//!
+//! let response = transcribe_verbose(client, &request).await?;
+//! std::fs::write("talk.srt", response.to_srt()).unwrap();
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Text-to-Speech Example
 //!
 //! ```rust,no_run
@@ -42,7 +61,11 @@
 
 use azure_ai_foundry_core::client::FoundryClient;
 use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use azure_ai_foundry_core::sse::SseEvent;
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::time::Duration;
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -69,6 +92,8 @@ pub enum AudioResponseFormat {
     Vtt,
     /// Verbose JSON with timestamps, segments, and metadata.
     VerboseJson,
+    /// JSON with speaker-labelled segments (diarization models only).
+    DiarizedJson,
 }
 
 impl AudioResponseFormat {
@@ -82,6 +107,7 @@ impl AudioResponseFormat {
             Self::Srt => "srt",
             Self::Vtt => "vtt",
             Self::VerboseJson => "verbose_json",
+            Self::DiarizedJson => "diarized_json",
         }
     }
 }
@@ -92,6 +118,103 @@ impl std::fmt::Display for AudioResponseFormat {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Transcription options
+// ---------------------------------------------------------------------------
+
+/// Timestamp detail to include in a verbose transcription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampGranularity {
+    /// Per-word timestamps ([`VerboseTranscriptionResponse::words`]).
+    Word,
+    /// Per-segment timestamps ([`VerboseTranscriptionResponse::segments`]).
+    Segment,
+}
+
+impl TimestampGranularity {
+    /// Return the string representation used by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Word => "word",
+            Self::Segment => "segment",
+        }
+    }
+}
+
+/// Additional information to include in a transcription response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptionInclude {
+    /// Token log probabilities ([`TranscriptionResponse::logprobs`]).
+    Logprobs,
+}
+
+impl TranscriptionInclude {
+    /// Return the string representation used by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Logprobs => "logprobs",
+        }
+    }
+}
+
+/// How the service splits audio into chunks before transcribing it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChunkingStrategy {
+    /// Let the service choose chunk boundaries (`"auto"`).
+    Auto,
+    /// Split on silences detected by server-side voice activity detection.
+    ServerVad(ServerVadConfig),
+}
+
+impl ChunkingStrategy {
+    /// Value sent in the `chunking_strategy` form field.
+    fn to_form_value(&self) -> String {
+        match self {
+            Self::Auto => "auto".into(),
+            Self::ServerVad(config) => {
+                serde_json::to_string(config).expect("ServerVadConfig serialization is infallible")
+            }
+        }
+    }
+}
+
+/// Server-side voice activity detection settings for [`ChunkingStrategy::ServerVad`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ServerVadConfig {
+    /// Always `"server_vad"`.
+    #[serde(rename = "type")]
+    pub strategy_type: ServerVadType,
+    /// Audio to include before detected speech, in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix_padding_ms: Option<u32>,
+    /// Silence duration that ends a chunk, in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub silence_duration_ms: Option<u32>,
+    /// Voice activity threshold (0.0 to 1.0).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f32>,
+}
+
+/// Type tag of [`ServerVadConfig`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerVadType {
+    /// Server-side voice activity detection.
+    #[default]
+    ServerVad,
+}
+
+/// A known speaker for diarization, matched against a short reference clip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownSpeaker {
+    /// Label used for this speaker in diarized segments.
+    pub name: String,
+    /// Reference audio as a data URL (e.g. `data:audio/wav;base64,...`).
+    pub reference: String,
+}
+
 // ---------------------------------------------------------------------------
 // Speech format
 // ---------------------------------------------------------------------------
@@ -115,6 +238,125 @@ pub enum SpeechFormat {
     Pcm16,
 }
 
+/// How text-to-speech audio is delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechStreamFormat {
+    /// Raw audio bytes in the response body.
+    Audio,
+    /// Server-sent events carrying base64 audio deltas.
+    Sse,
+}
+
+/// A text-to-speech voice.
+///
+/// Built-in voices have their own variants; any other name (for example a
+/// voice added to a newer model) can be passed as [`Voice::Custom`]. Strings
+/// convert with `From`, so `"alloy"` becomes [`Voice::Alloy`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Voice {
+    /// The `alloy` voice.
+    Alloy,
+    /// The `ash` voice.
+    Ash,
+    /// The `ballad` voice.
+    Ballad,
+    /// The `coral` voice.
+    Coral,
+    /// The `echo` voice.
+    Echo,
+    /// The `fable` voice.
+    Fable,
+    /// The `nova` voice.
+    Nova,
+    /// The `onyx` voice.
+    Onyx,
+    /// The `sage` voice.
+    Sage,
+    /// The `shimmer` voice.
+    Shimmer,
+    /// The `verse` voice.
+    Verse,
+    /// Any other voice name.
+    Custom(String),
+}
+
+impl Voice {
+    /// Return the voice name as sent to the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Alloy => "alloy",
+            Self::Ash => "ash",
+            Self::Ballad => "ballad",
+            Self::Coral => "coral",
+            Self::Echo => "echo",
+            Self::Fable => "fable",
+            Self::Nova => "nova",
+            Self::Onyx => "onyx",
+            Self::Sage => "sage",
+            Self::Shimmer => "shimmer",
+            Self::Verse => "verse",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+impl std::fmt::Display for Voice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for Voice {
+    fn from(name: &str) -> Self {
+        match name {
+            "alloy" => Self::Alloy,
+            "ash" => Self::Ash,
+            "ballad" => Self::Ballad,
+            "coral" => Self::Coral,
+            "echo" => Self::Echo,
+            "fable" => Self::Fable,
+            "nova" => Self::Nova,
+            "onyx" => Self::Onyx,
+            "sage" => Self::Sage,
+            "shimmer" => Self::Shimmer,
+            "verse" => Self::Verse,
+            other => Self::Custom(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for Voice {
+    fn from(name: String) -> Self {
+        match Self::from(name.as_str()) {
+            Self::Custom(_) => Self::Custom(name),
+            voice => voice,
+        }
+    }
+}
+
+impl From<Voice> for String {
+    fn from(voice: Voice) -> Self {
+        match voice {
+            Voice::Custom(name) => name,
+            voice => voice.as_str().to_string(),
+        }
+    }
+}
+
+impl PartialEq<str> for Voice {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Voice {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Transcription request
 // ---------------------------------------------------------------------------
@@ -138,6 +380,14 @@ pub struct TranscriptionRequest {
     pub response_format: Option<AudioResponseFormat>,
     /// The sampling temperature, between 0.0 and 1.0.
     pub temperature: Option<f32>,
+    /// Timestamp detail for verbose responses (sent as `timestamp_granularities[]`).
+    pub timestamp_granularities: Vec<TimestampGranularity>,
+    /// Additional response data (sent as `include[]`).
+    pub include: Vec<TranscriptionInclude>,
+    /// How the audio is split into chunks before transcription.
+    pub chunking_strategy: Option<ChunkingStrategy>,
+    /// Known speakers for diarization models.
+    pub known_speakers: Vec<KnownSpeaker>,
 }
 
 impl TranscriptionRequest {
@@ -151,6 +401,10 @@ impl TranscriptionRequest {
             prompt: None,
             response_format: None,
             temperature: None,
+            timestamp_granularities: Vec::new(),
+            include: Vec::new(),
+            chunking_strategy: None,
+            known_speakers: Vec::new(),
         }
     }
 }
@@ -164,6 +418,10 @@ pub struct TranscriptionRequestBuilder {
     prompt: Option<String>,
     response_format: Option<AudioResponseFormat>,
     temperature: Option<f32>,
+    timestamp_granularities: Vec<TimestampGranularity>,
+    include: Vec<TranscriptionInclude>,
+    chunking_strategy: Option<ChunkingStrategy>,
+    known_speakers: Vec<KnownSpeaker>,
 }
 
 impl std::fmt::Debug for TranscriptionRequestBuilder {
@@ -179,6 +437,17 @@ impl std::fmt::Debug for TranscriptionRequestBuilder {
             .field("prompt", &self.prompt)
             .field("response_format", &self.response_format)
             .field("temperature", &self.temperature)
+            .field("timestamp_granularities", &self.timestamp_granularities)
+            .field("include", &self.include)
+            .field("chunking_strategy", &self.chunking_strategy)
+            .field(
+                "known_speakers",
+                &self
+                    .known_speakers
+                    .iter()
+                    .map(|s| s.name.as_str())
+                    .collect::<Vec<_>>(),
+            )
             .finish()
     }
 }
@@ -226,6 +495,45 @@ impl TranscriptionRequestBuilder {
         self
     }
 
+    /// Request a timestamp granularity (may be called for both word and segment).
+    ///
+    /// Requires the `verbose_json` response format, which is selected when no
+    /// other format is set (and always by [`transcribe_verbose`]).
+    pub fn timestamp_granularity(mut self, granularity: TimestampGranularity) -> Self {
+        if !self.timestamp_granularities.contains(&granularity) {
+            self.timestamp_granularities.push(granularity);
+        }
+        self
+    }
+
+    /// Include token log probabilities in the response.
+    ///
+    /// Only supported with the `json` response format and `gpt-4o-transcribe`
+    /// family models.
+    pub fn include_logprobs(mut self) -> Self {
+        if !self.include.contains(&TranscriptionInclude::Logprobs) {
+            self.include.push(TranscriptionInclude::Logprobs);
+        }
+        self
+    }
+
+    /// Set how the audio is split into chunks before transcription.
+    pub fn chunking_strategy(mut self, strategy: ChunkingStrategy) -> Self {
+        self.chunking_strategy = Some(strategy);
+        self
+    }
+
+    /// Add a known speaker for diarization.
+    ///
+    /// `reference` is a short audio clip of the speaker as a data URL.
+    pub fn known_speaker(mut self, name: impl Into<String>, reference: impl Into<String>) -> Self {
+        self.known_speakers.push(KnownSpeaker {
+            name: name.into(),
+            reference: reference.into(),
+        });
+        self
+    }
+
     /// Build the request, returning an error if required fields are missing
     /// or parameter values are invalid.
     pub fn try_build(self) -> FoundryResult<TranscriptionRequest> {
@@ -258,14 +566,59 @@ impl TranscriptionRequestBuilder {
             }
         }
 
+        let mut response_format = self.response_format;
+        if !self.timestamp_granularities.is_empty() {
+            match response_format {
+                None => response_format = Some(AudioResponseFormat::VerboseJson),
+                Some(AudioResponseFormat::VerboseJson) => {}
+                Some(_) => {
+                    return Err(FoundryError::Builder(
+                        "timestamp_granularities require the verbose_json response format".into(),
+                    ));
+                }
+            }
+        }
+
+        if self.include.contains(&TranscriptionInclude::Logprobs)
+            && !matches!(response_format, None | Some(AudioResponseFormat::Json))
+        {
+            return Err(FoundryError::Builder(
+                "logprobs require the json response format".into(),
+            ));
+        }
+
+        if let Some(ChunkingStrategy::ServerVad(config)) = &self.chunking_strategy {
+            if let Some(threshold) = config.threshold {
+                if !(0.0..=1.0).contains(&threshold) {
+                    return Err(FoundryError::Builder(
+                        "server_vad threshold must be between 0.0 and 1.0".into(),
+                    ));
+                }
+            }
+        }
+
+        if self
+            .known_speakers
+            .iter()
+            .any(|s| s.name.trim().is_empty() || !s.reference.starts_with("data:"))
+        {
+            return Err(FoundryError::Builder(
+                "known speakers need a name and a data URL reference".into(),
+            ));
+        }
+
         Ok(TranscriptionRequest {
             model,
             filename,
             data,
             language: self.language,
             prompt: self.prompt,
-            response_format: self.response_format,
+            response_format,
             temperature: self.temperature,
+            timestamp_granularities: self.timestamp_granularities,
+            include: self.include,
+            chunking_strategy: self.chunking_strategy,
+            known_speakers: self.known_speakers,
         })
     }
 
@@ -448,7 +801,11 @@ pub struct SpeechRequest {
     /// The text to generate audio for.
     pub input: String,
     /// The voice to use for generation.
-    pub voice: String,
+    pub voice: Voice,
+
+    /// Instructions controlling tone, accent or pacing (gpt-4o-mini-tts and later).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
 
     /// The format of the audio output.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -457,6 +814,10 @@ pub struct SpeechRequest {
     /// The speed of the generated audio (0.25 to 4.0).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f32>,
+
+    /// Whether audio is returned as raw bytes or as SSE audio deltas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_format: Option<SpeechStreamFormat>,
 }
 
 impl SpeechRequest {
@@ -466,8 +827,10 @@ impl SpeechRequest {
             model: None,
             input: None,
             voice: None,
+            instructions: None,
             response_format: None,
             speed: None,
+            stream_format: None,
         }
     }
 }
@@ -477,9 +840,11 @@ impl SpeechRequest {
 pub struct SpeechRequestBuilder {
     model: Option<String>,
     input: Option<String>,
-    voice: Option<String>,
+    voice: Option<Voice>,
+    instructions: Option<String>,
     response_format: Option<SpeechFormat>,
     speed: Option<f32>,
+    stream_format: Option<SpeechStreamFormat>,
 }
 
 impl SpeechRequestBuilder {
@@ -496,11 +861,21 @@ impl SpeechRequestBuilder {
     }
 
     /// Set the voice to use for generation.
-    pub fn voice(mut self, voice: impl Into<String>) -> Self {
+    ///
+    /// Accepts a [`Voice`] or a voice name.
+    pub fn voice(mut self, voice: impl Into<Voice>) -> Self {
         self.voice = Some(voice.into());
         self
     }
 
+    /// Set instructions controlling how the speech is delivered.
+    ///
+    /// Supported by gpt-4o-mini-tts; `tts-1` and `tts-1-hd` ignore or reject it.
+    pub fn instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = Some(instructions.into());
+        self
+    }
+
     /// Set the audio output format.
     pub fn response_format(mut self, format: SpeechFormat) -> Self {
         self.response_format = Some(format);
@@ -513,6 +888,12 @@ impl SpeechRequestBuilder {
         self
     }
 
+    /// Set how the audio is delivered. See [`speak_stream`].
+    pub fn stream_format(mut self, format: SpeechStreamFormat) -> Self {
+        self.stream_format = Some(format);
+        self
+    }
+
     /// Build the request, returning an error if required fields are missing
     /// or parameter values are invalid.
     pub fn try_build(self) -> FoundryResult<SpeechRequest> {
@@ -539,10 +920,16 @@ impl SpeechRequestBuilder {
         let voice = self
             .voice
             .ok_or_else(|| FoundryError::Builder("voice is required".into()))?;
-        if voice.trim().is_empty() {
+        if voice.as_str().trim().is_empty() {
             return Err(FoundryError::Builder("voice cannot be empty".into()));
         }
 
+        if let Some(ref instructions) = self.instructions {
+            if instructions.trim().is_empty() {
+                return Err(FoundryError::Builder("instructions cannot be empty".into()));
+            }
+        }
+
         if let Some(speed) = self.speed {
             if !(0.25..=4.0).contains(&speed) {
                 return Err(FoundryError::Builder(
@@ -555,8 +942,10 @@ impl SpeechRequestBuilder {
             model,
             input,
             voice,
+            instructions: self.instructions,
             response_format: self.response_format,
             speed: self.speed,
+            stream_format: self.stream_format,
         })
     }
 
@@ -580,6 +969,22 @@ impl SpeechRequestBuilder {
 pub struct TranscriptionResponse {
     /// The transcribed or translated text.
     pub text: String,
+    /// Token log probabilities, when requested with
+    /// [`include_logprobs`](TranscriptionRequestBuilder::include_logprobs).
+    #[serde(default)]
+    pub logprobs: Option<Vec<TranscriptionLogprob>>,
+}
+
+/// Log probability of a single transcribed token.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TranscriptionLogprob {
+    /// The token text.
+    pub token: String,
+    /// Log probability of the token.
+    pub logprob: f64,
+    /// UTF-8 bytes of the token.
+    #[serde(default)]
+    pub bytes: Vec<u8>,
 }
 
 /// Response from an audio translation request.
@@ -602,6 +1007,83 @@ pub struct VerboseTranscriptionResponse {
     pub text: String,
     /// The segments of the transcription.
     pub segments: Option<Vec<TranscriptionSegment>>,
+    /// Word-level timestamps, when requested with [`TimestampGranularity::Word`].
+    #[serde(default)]
+    pub words: Option<Vec<TranscriptionWord>>,
+}
+
+/// A word with its timing in a verbose transcription response.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TranscriptionWord {
+    /// The transcribed word.
+    pub word: String,
+    /// Start time of the word in seconds.
+    pub start: f64,
+    /// End time of the word in seconds.
+    pub end: f64,
+}
+
+/// Response from a diarized transcription request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiarizedTranscriptionResponse {
+    /// The task that was performed (e.g., "transcribe").
+    #[serde(default)]
+    pub task: Option<String>,
+    /// The duration of the audio in seconds.
+    #[serde(default)]
+    pub duration: Option<f64>,
+    /// The full transcribed text.
+    pub text: String,
+    /// Speaker-labelled segments in chronological order.
+    #[serde(default)]
+    pub segments: Vec<DiarizedSegment>,
+}
+
+/// A segment of speech attributed to one speaker.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DiarizedSegment {
+    /// Segment identifier.
+    pub id: String,
+    /// Speaker label (a known speaker name, or `A`, `B`, ... otherwise).
+    pub speaker: String,
+    /// Start time of the segment in seconds.
+    pub start: f64,
+    /// End time of the segment in seconds.
+    pub end: f64,
+    /// The transcribed text of the segment.
+    pub text: String,
+}
+
+impl DiarizedTranscriptionResponse {
+    /// The distinct speaker labels, in order of first appearance.
+    pub fn speakers(&self) -> Vec<&str> {
+        let mut speakers: Vec<&str> = Vec::new();
+        for segment in &self.segments {
+            if !speakers.contains(&segment.speaker.as_str()) {
+                speakers.push(&segment.speaker);
+            }
+        }
+        speakers
+    }
+
+    /// Render the segments as SubRip (SRT) captions prefixed with the speaker.
+    pub fn to_srt(&self) -> String {
+        render_srt(self.cues())
+    }
+
+    /// Render the segments as WebVTT captions with voice (`<v>`) tags.
+    pub fn to_vtt(&self) -> String {
+        render_vtt(self.cues())
+    }
+
+    fn cues(&self) -> impl Iterator<Item = Cue<'_>> {
+        self.segments.iter().map(|s| Cue {
+            start: s.start,
+            end: s.end,
+            text: s.text.trim().into(),
+            speaker: Some(&s.speaker),
+        })
+    }
 }
 
 /// A segment of a verbose transcription response.
@@ -629,6 +1111,143 @@ pub struct TranscriptionSegment {
     pub no_speech_prob: f64,
 }
 
+/// Maximum number of words per caption when cues are built from word timestamps.
+const MAX_WORDS_PER_CUE: usize = 12;
+
+impl VerboseTranscriptionResponse {
+    /// Render the transcription as SubRip (SRT) captions.
+    ///
+    /// Uses [`segments`](Self::segments) when present. Otherwise cues are
+    /// built from [`words`](Self::words), breaking after sentence punctuation
+    /// or every 12 words. Without either, the whole text becomes one cue.
+    pub fn to_srt(&self) -> String {
+        render_srt(self.cues())
+    }
+
+    /// Render the transcription as WebVTT captions.
+    ///
+    /// Cues are chosen the same way as [`to_srt`](Self::to_srt).
+    pub fn to_vtt(&self) -> String {
+        render_vtt(self.cues())
+    }
+
+    fn cues(&self) -> Vec<Cue<'_>> {
+        if let Some(segments) = self.segments.as_ref().filter(|s| !s.is_empty()) {
+            return segments
+                .iter()
+                .map(|s| Cue {
+                    start: s.start,
+                    end: s.end,
+                    text: s.text.trim().into(),
+                    speaker: None,
+                })
+                .collect();
+        }
+
+        if let Some(words) = self.words.as_ref().filter(|w| !w.is_empty()) {
+            let mut cues = Vec::new();
+            for group in split_words(words) {
+                let text = group
+                    .iter()
+                    .map(|w| w.word.trim())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                cues.push(Cue {
+                    start: group[0].start,
+                    end: group[group.len() - 1].end,
+                    text: text.into(),
+                    speaker: None,
+                });
+            }
+            return cues;
+        }
+
+        if self.text.trim().is_empty() {
+            return Vec::new();
+        }
+        vec![Cue {
+            start: 0.0,
+            end: self.duration,
+            text: self.text.trim().into(),
+            speaker: None,
+        }]
+    }
+}
+
+/// Split words into caption-sized groups.
+fn split_words(words: &[TranscriptionWord]) -> Vec<&[TranscriptionWord]> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for (i, word) in words.iter().enumerate() {
+        let len = i + 1 - start;
+        let ends_sentence = word.word.trim_end().ends_with(['.', '?', '!']);
+        if ends_sentence || len == MAX_WORDS_PER_CUE {
+            groups.push(&words[start..=i]);
+            start = i + 1;
+        }
+    }
+    if start < words.len() {
+        groups.push(&words[start..]);
+    }
+    groups
+}
+
+// ---------------------------------------------------------------------------
+// Helper: caption rendering
+// ---------------------------------------------------------------------------
+
+/// A single caption cue.
+struct Cue<'a> {
+    start: f64,
+    end: f64,
+    text: std::borrow::Cow<'a, str>,
+    speaker: Option<&'a str>,
+}
+
+/// Format seconds as `HH:MM:SS<sep>mmm`.
+fn format_timestamp(seconds: f64, separator: char) -> String {
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    let (hours, rest) = (total_ms / 3_600_000, total_ms % 3_600_000);
+    let (minutes, rest) = (rest / 60_000, rest % 60_000);
+    let (secs, ms) = (rest / 1000, rest % 1000);
+    format!("{hours:02}:{minutes:02}:{secs:02}{separator}{ms:03}")
+}
+
+fn render_srt<'a>(cues: impl IntoIterator<Item = Cue<'a>>) -> String {
+    let mut out = String::new();
+    for (i, cue) in cues.into_iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n",
+            i + 1,
+            format_timestamp(cue.start, ','),
+            format_timestamp(cue.end, ',')
+        ));
+        if let Some(speaker) = cue.speaker {
+            out.push_str(&format!("{}: ", speaker));
+        }
+        out.push_str(&cue.text);
+        out.push_str("\n\n");
+    }
+    out
+}
+
+fn render_vtt<'a>(cues: impl IntoIterator<Item = Cue<'a>>) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues {
+        out.push_str(&format!(
+            "{} --> {}\n",
+            format_timestamp(cue.start, '.'),
+            format_timestamp(cue.end, '.')
+        ));
+        if let Some(speaker) = cue.speaker {
+            out.push_str(&format!("<v {}>", speaker));
+        }
+        out.push_str(&cue.text);
+        out.push_str("\n\n");
+    }
+    out
+}
+
 // ---------------------------------------------------------------------------
 // Helper: build multipart form for audio
 // ---------------------------------------------------------------------------
@@ -672,6 +1291,59 @@ fn build_audio_form(
     form
 }
 
+/// Add the transcription-only fields of `request` to a multipart form.
+///
+/// List parameters are repeated with the `[]` suffix the API expects.
+fn add_transcription_fields(
+    mut form: reqwest::multipart::Form,
+    request: &TranscriptionRequest,
+) -> reqwest::multipart::Form {
+    for granularity in &request.timestamp_granularities {
+        form = form.text("timestamp_granularities[]", granularity.as_str());
+    }
+    for include in &request.include {
+        form = form.text("include[]", include.as_str());
+    }
+    if let Some(strategy) = &request.chunking_strategy {
+        form = form.text("chunking_strategy", strategy.to_form_value());
+    }
+    for speaker in &request.known_speakers {
+        form = form
+            .text("known_speaker_names[]", speaker.name.clone())
+            .text("known_speaker_references[]", speaker.reference.clone());
+    }
+    form
+}
+
+/// Send a transcription request, optionally overriding its response format.
+async fn send_transcription(
+    client: &FoundryClient,
+    request: &TranscriptionRequest,
+    response_format: Option<AudioResponseFormat>,
+) -> FoundryResult<reqwest::Response> {
+    // Clone the request once — Bytes::clone is O(1), String::clone is O(n)
+    // but unavoidable since the closure must be callable on every retry.
+    let mut req = request.clone();
+    if response_format.is_some() {
+        req.response_format = response_format;
+    }
+
+    client
+        .post_multipart("/openai/v1/audio/transcriptions", move || {
+            let form = build_audio_form(
+                req.data.clone(),
+                req.filename.clone(),
+                req.model.clone(),
+                req.language.clone(),
+                req.prompt.clone(),
+                req.response_format,
+                req.temperature,
+            );
+            add_transcription_fields(form, &req)
+        })
+        .await
+}
+
 // ---------------------------------------------------------------------------
 // API functions
 // ---------------------------------------------------------------------------
@@ -710,32 +1382,20 @@ pub async fn transcribe(
 ) -> FoundryResult<TranscriptionResponse> {
     tracing::debug!("sending transcription request");
 
-    // Clone the request once — Bytes::clone is O(1), String::clone is O(n)
-    // but unavoidable since the closure must be callable on every retry.
-    let req = request.clone();
-
-    let response = client
-        .post_multipart("/openai/v1/audio/transcriptions", move || {
-            build_audio_form(
-                req.data.clone(),
-                req.filename.clone(),
-                req.model.clone(),
-                req.language.clone(),
-                req.prompt.clone(),
-                req.response_format,
-                req.temperature,
-            )
-        })
-        .await?;
+    let response = send_transcription(client, request, None).await?;
 
     let body = response.json::<TranscriptionResponse>().await?;
     Ok(body)
 }
 
-/// Translate audio to English text.
+/// Transcribe audio with timestamps, returning the verbose response.
 ///
-/// Translation always outputs English regardless of the input language.
-/// For same-language transcription, use [`transcribe`] instead.
+/// Always requests the `verbose_json` response format, overriding
+/// `request.response_format`. Add
+/// [`timestamp_granularity`](TranscriptionRequestBuilder::timestamp_granularity)
+/// to get word-level timestamps. The result can be rendered as captions with
+/// [`VerboseTranscriptionResponse::to_srt`] or
+/// [`VerboseTranscriptionResponse::to_vtt`].
 ///
 /// # Example
 ///
@@ -743,9 +1403,108 @@ pub async fn transcribe(
 /// # use azure_ai_foundry_core::client::FoundryClient;
 /// # use azure_ai_foundry_models::audio::*;
 /// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
-/// let request = TranslationRequest::builder()
+/// let request = TranscriptionRequest::builder()
 ///     .model("whisper-1")
-///     .filename("audio_fr.wav")
+///     .filename("talk.wav")
+///     .data(std::fs::read("talk.wav").unwrap())
+///     .timestamp_granularity(TimestampGranularity::Word)
+///     .timestamp_granularity(TimestampGranularity::Segment)
+///     .build();
+///
+/// let response = transcribe_verbose(client, &request).await?;
+/// std::fs::write("talk.vtt", response.to_vtt()).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::audio::transcribe_verbose` with field `model`.
+#[tracing::instrument(
+    name = "foundry::audio::transcribe_verbose",
+    skip(client, request),
+    fields(model = %request.model)
+)]
+pub async fn transcribe_verbose(
+    client: &FoundryClient,
+    request: &TranscriptionRequest,
+) -> FoundryResult<VerboseTranscriptionResponse> {
+    tracing::debug!("sending verbose transcription request");
+
+    let response =
+        send_transcription(client, request, Some(AudioResponseFormat::VerboseJson)).await?;
+
+    let body = response.json::<VerboseTranscriptionResponse>().await?;
+    Ok(body)
+}
+
+/// Transcribe audio with speaker diarization.
+///
+/// Always requests the `diarized_json` response format, overriding
+/// `request.response_format`, and defaults the chunking strategy to
+/// [`ChunkingStrategy::Auto`] (required by the service for audio longer than
+/// 30 seconds). Use a diarization model such as `gpt-4o-transcribe-diarize`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::audio::*;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let request = TranscriptionRequest::builder()
+///     .model("gpt-4o-transcribe-diarize")
+///     .filename("meeting.wav")
+///     .data(std::fs::read("meeting.wav").unwrap())
+///     .build();
+///
+/// let response = transcribe_diarized(client, &request).await?;
+/// for segment in &response.segments {
+///     println!("[{}] {}", segment.speaker, segment.text);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::audio::transcribe_diarized` with field `model`.
+#[tracing::instrument(
+    name = "foundry::audio::transcribe_diarized",
+    skip(client, request),
+    fields(model = %request.model)
+)]
+pub async fn transcribe_diarized(
+    client: &FoundryClient,
+    request: &TranscriptionRequest,
+) -> FoundryResult<DiarizedTranscriptionResponse> {
+    tracing::debug!("sending diarized transcription request");
+
+    let response = if request.chunking_strategy.is_none() {
+        let mut request = request.clone();
+        request.chunking_strategy = Some(ChunkingStrategy::Auto);
+        send_transcription(client, &request, Some(AudioResponseFormat::DiarizedJson)).await?
+    } else {
+        send_transcription(client, request, Some(AudioResponseFormat::DiarizedJson)).await?
+    };
+
+    let body = response.json::<DiarizedTranscriptionResponse>().await?;
+    Ok(body)
+}
+
+/// Translate audio to English text.
+///
+/// Translation always outputs English regardless of the input language.
+/// For same-language transcription, use [`transcribe`] instead.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::audio::*;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let request = TranslationRequest::builder()
+///     .model("whisper-1")
+///     .filename("audio_fr.wav")
 ///     .data(vec![0u8; 100])
 ///     .build();
 ///
@@ -831,11 +1590,665 @@ pub async fn translate(
 pub async fn speak(client: &FoundryClient, request: &SpeechRequest) -> FoundryResult<bytes::Bytes> {
     tracing::debug!("sending speech request");
 
-    let response = client.post("/openai/v1/audio/speech", request).await?;
+    let response = client.post(SPEECH_PATH, request).await?;
     let body = response.bytes().await?;
     Ok(body)
 }
 
+/// API path for text-to-speech.
+const SPEECH_PATH: &str = "/openai/v1/audio/speech";
+
+/// Generate speech audio from text, yielding audio chunks as they arrive.
+///
+/// Unlike [`speak`], the body is not buffered, so playback or forwarding can
+/// start with the first chunk. With [`SpeechStreamFormat::Sse`] the service
+/// sends base64 audio in `speech.audio.delta` events; these are decoded, so
+/// the stream yields raw audio in either mode.
+///
+/// The request uses the client's streaming timeout, and both modes honour
+/// its stream idle timeout. A stall or dropped connection mid-stream yields
+/// [`FoundryError::StreamInterrupted`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::audio::*;
+/// # use futures::StreamExt;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let request = SpeechRequest::builder()
+///     .model("gpt-4o-mini-tts")
+///     .input("Hello, world!")
+///     .voice(Voice::Coral)
+///     .instructions("Speak in a cheerful tone.")
+///     .response_format(SpeechFormat::Pcm16)
+///     .build();
+///
+/// let mut stream = std::pin::pin!(speak_stream(client, &request).await?);
+/// while let Some(chunk) = stream.next().await {
+///     let audio = chunk?;
+///     println!("received {} bytes", audio.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::audio::speak_stream` with fields `model` and `voice`.
+#[tracing::instrument(
+    name = "foundry::audio::speak_stream",
+    skip(client, request),
+    fields(model = %request.model, voice = %request.voice)
+)]
+pub async fn speak_stream(
+    client: &FoundryClient,
+    request: &SpeechRequest,
+) -> FoundryResult<impl Stream<Item = FoundryResult<bytes::Bytes>>> {
+    tracing::debug!("sending streaming speech request");
+
+    let response = client.post_stream(SPEECH_PATH, request).await?;
+
+    let chunks: Pin<Box<dyn Stream<Item = FoundryResult<bytes::Bytes>> + Send>> =
+        if request.stream_format == Some(SpeechStreamFormat::Sse) {
+            Box::pin(crate::chat::parse_sse_stream(
+                response,
+                parse_speech_event,
+                client.stream_idle_timeout(),
+            ))
+        } else {
+            Box::pin(azure_ai_foundry_core::sse::with_idle_timeout(
+                response.bytes_stream(),
+                client.stream_idle_timeout(),
+            ))
+        };
+
+    Ok(chunks)
+}
+
+/// A server-sent event from a streaming speech response.
+#[derive(Debug, Deserialize)]
+struct SpeechStreamEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    #[serde(default)]
+    audio: Option<String>,
+}
+
+/// Decode the audio carried by a speech SSE event, skipping all other events.
+fn parse_speech_event(event: &SseEvent) -> Option<FoundryResult<bytes::Bytes>> {
+    use base64::Engine;
+
+    if event.is_done() || event.data.trim().is_empty() {
+        return None;
+    }
+
+    let parsed = match serde_json::from_str::<SpeechStreamEvent>(&event.data) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return Some(Err(FoundryError::stream_with_source(
+                "failed to parse speech event",
+                e,
+            )))
+        }
+    };
+    if parsed.event_type != "speech.audio.delta" {
+        return None;
+    }
+
+    let audio = parsed.audio.unwrap_or_default();
+    Some(
+        base64::engine::general_purpose::STANDARD
+            .decode(audio)
+            .map(bytes::Bytes::from)
+            .map_err(|e| FoundryError::stream_with_source("invalid base64 audio delta", e)),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Long-audio transcription
+// ---------------------------------------------------------------------------
+
+/// Maximum audio file size accepted by the transcription API (25 MB).
+pub const MAX_TRANSCRIPTION_FILE_SIZE: usize = 25 * 1024 * 1024;
+
+/// Size of the canonical WAV header written for each chunk.
+const WAV_HEADER_LEN: usize = 44;
+
+const WAVE_FORMAT_PCM: u16 = 1;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Bytes 2..16 of every `KSDATAFORMAT_SUBTYPE_*` GUID; bytes 0..2 hold the
+/// format tag.
+const KSDATAFORMAT_SUBTYPE_BASE: [u8; 14] = [
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71,
+];
+
+/// Length of the analysis frames used to look for silence.
+const SILENCE_FRAME: Duration = Duration::from_millis(20);
+
+/// Layout of raw PCM audio (little-endian, interleaved channels).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcmFormat {
+    /// Samples per second, per channel.
+    pub sample_rate: u32,
+    /// Number of interleaved channels.
+    pub channels: u16,
+    /// Bits per sample (8, 16, 24 or 32).
+    pub bits_per_sample: u16,
+}
+
+impl PcmFormat {
+    /// Bytes per sample frame (one sample for every channel).
+    fn block_align(&self) -> usize {
+        self.channels as usize * (self.bits_per_sample as usize).div_ceil(8)
+    }
+
+    /// Number of sample frames covering `duration`.
+    fn frames(&self, duration: Duration) -> usize {
+        (duration.as_secs_f64() * self.sample_rate as f64).round() as usize
+    }
+
+    /// Duration in seconds of `frames` sample frames.
+    fn seconds(&self, frames: usize) -> f64 {
+        frames as f64 / self.sample_rate as f64
+    }
+
+    fn validate(&self) -> FoundryResult<()> {
+        if self.sample_rate == 0 || self.channels == 0 {
+            return Err(FoundryError::validation(
+                "PCM sample rate and channel count must be non-zero",
+            ));
+        }
+        if ![8, 16, 24, 32].contains(&self.bits_per_sample) {
+            return Err(FoundryError::validation(format!(
+                "unsupported PCM bit depth: {}",
+                self.bits_per_sample
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Options for [`transcribe_long`].
+#[derive(Debug, Clone)]
+pub struct LongTranscriptionOptions {
+    /// Target length of each chunk. Chunks are also capped so they stay under
+    /// [`MAX_TRANSCRIPTION_FILE_SIZE`].
+    pub chunk_duration: Duration,
+    /// Audio shared by consecutive chunks when no silence is found near a
+    /// boundary, so words cut at the boundary are heard whole at least once.
+    pub overlap: Duration,
+    /// How far before each boundary to look for a silent point to cut at.
+    /// `Duration::ZERO` always uses fixed windows with overlap.
+    pub silence_search: Duration,
+    /// Peak level (0.0 to 1.0 of full scale) under which a frame counts as silence.
+    ///
+    /// Works for 8-, 16-, 24- and 32-bit integer PCM; floating-point WAV
+    /// files are rejected before chunking.
+    pub silence_threshold: f32,
+    /// Maximum number of chunks transcribed at the same time.
+    pub max_concurrency: usize,
+    /// Layout of the input when it is raw PCM rather than a WAV file.
+    pub pcm_format: Option<PcmFormat>,
+}
+
+impl Default for LongTranscriptionOptions {
+    fn default() -> Self {
+        Self {
+            chunk_duration: Duration::from_secs(300),
+            overlap: Duration::from_secs(2),
+            silence_search: Duration::from_secs(10),
+            silence_threshold: 0.02,
+            max_concurrency: 4,
+            pcm_format: None,
+        }
+    }
+}
+
+/// Decoded PCM audio borrowed from the request payload.
+struct PcmAudio<'a> {
+    format: PcmFormat,
+    samples: &'a [u8],
+}
+
+impl PcmAudio<'_> {
+    fn frame_count(&self) -> usize {
+        self.samples.len() / self.format.block_align()
+    }
+
+    fn frames(&self, range: std::ops::Range<usize>) -> &[u8] {
+        let align = self.format.block_align();
+        &self.samples[range.start * align..range.end * align]
+    }
+
+    /// Peak absolute amplitude of `range`, normalised to 0.0..=1.0.
+    ///
+    /// 8-bit samples are unsigned (centred on 128); wider samples are signed.
+    fn peak(&self, range: std::ops::Range<usize>) -> f32 {
+        let bits = self.format.bits_per_sample;
+        let width = (bits as usize).div_ceil(8);
+        let peak = self
+            .frames(range)
+            .chunks_exact(width)
+            .map(|b| match width {
+                1 => (i32::from(b[0]) - 128).unsigned_abs(),
+                2 => i32::from(i16::from_le_bytes([b[0], b[1]])).unsigned_abs(),
+                3 => (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8).unsigned_abs(),
+                _ => i32::from_le_bytes([b[0], b[1], b[2], b[3]]).unsigned_abs(),
+            })
+            .max()
+            .unwrap_or(0);
+        (peak as f64 / (1_u64 << (bits - 1)) as f64) as f32
+    }
+}
+
+/// A planned chunk, in sample frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ChunkPlan {
+    start: usize,
+    end: usize,
+}
+
+/// Parse a RIFF/WAVE file, returning `None` if `data` is not a WAV file.
+fn parse_wav(data: &[u8]) -> FoundryResult<Option<PcmAudio<'_>>> {
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        return Ok(None);
+    }
+
+    let mut format = None;
+    let mut pos = 12;
+    while pos + 8 <= data.len() {
+        let id = &data[pos..pos + 4];
+        let size = u32::from_le_bytes([data[pos + 4], data[pos + 5], data[pos + 6], data[pos + 7]])
+            as usize;
+        let body_start = pos + 8;
+        let body_end = body_start.saturating_add(size).min(data.len());
+        let body = &data[body_start..body_end];
+
+        match id {
+            b"fmt " => {
+                if body.len() < 16 {
+                    return Err(FoundryError::validation("WAV fmt chunk is truncated"));
+                }
+                let mut audio_format = u16::from_le_bytes([body[0], body[1]]);
+                // WAVE_FORMAT_EXTENSIBLE carries the real encoding in its
+                // sub-format GUID, whose first two bytes are the format tag.
+                if audio_format == WAVE_FORMAT_EXTENSIBLE {
+                    audio_format = match body.get(24..40) {
+                        Some(guid) if guid[2..] == KSDATAFORMAT_SUBTYPE_BASE => {
+                            u16::from_le_bytes([guid[0], guid[1]])
+                        }
+                        Some(_) => 0,
+                        None => {
+                            return Err(FoundryError::validation(
+                                "WAV extensible fmt chunk is truncated",
+                            ))
+                        }
+                    };
+                }
+                if audio_format != WAVE_FORMAT_PCM {
+                    return Err(FoundryError::validation(format!(
+                        "unsupported WAV encoding (format tag {audio_format}); only PCM is supported"
+                    )));
+                }
+                format = Some(PcmFormat {
+                    channels: u16::from_le_bytes([body[2], body[3]]),
+                    sample_rate: u32::from_le_bytes([body[4], body[5], body[6], body[7]]),
+                    bits_per_sample: u16::from_le_bytes([body[14], body[15]]),
+                });
+            }
+            b"data" => {
+                let format = format.ok_or_else(|| {
+                    FoundryError::validation("WAV data chunk appears before fmt chunk")
+                })?;
+                format.validate()?;
+                return Ok(Some(PcmAudio {
+                    format,
+                    samples: body,
+                }));
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to an even size.
+        pos = body_start.saturating_add(size).saturating_add(size & 1);
+    }
+
+    Err(FoundryError::validation("WAV file has no data chunk"))
+}
+
+/// Wrap PCM samples in a canonical 44-byte WAV header.
+fn encode_wav(format: &PcmFormat, samples: &[u8]) -> Vec<u8> {
+    let block_align = format.block_align() as u16;
+    let byte_rate = format.sample_rate * block_align as u32;
+    let data_len = samples.len() as u32;
+
+    let mut out = Vec::with_capacity(WAV_HEADER_LEN + samples.len());
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(36 + data_len).to_le_bytes());
+    out.extend_from_slice(b"WAVEfmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&WAVE_FORMAT_PCM.to_le_bytes());
+    out.extend_from_slice(&format.channels.to_le_bytes());
+    out.extend_from_slice(&format.sample_rate.to_le_bytes());
+    out.extend_from_slice(&byte_rate.to_le_bytes());
+    out.extend_from_slice(&block_align.to_le_bytes());
+    out.extend_from_slice(&format.bits_per_sample.to_le_bytes());
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_len.to_le_bytes());
+    out.extend_from_slice(samples);
+    out
+}
+
+/// Split `audio` into chunks, preferring silent cut points near each boundary.
+fn plan_chunks(audio: &PcmAudio<'_>, options: &LongTranscriptionOptions) -> Vec<ChunkPlan> {
+    let format = &audio.format;
+    let total = audio.frame_count();
+    let max_by_size = (MAX_TRANSCRIPTION_FILE_SIZE - WAV_HEADER_LEN) / format.block_align();
+    let chunk_frames = format.frames(options.chunk_duration).clamp(1, max_by_size);
+    let overlap = format
+        .frames(options.overlap)
+        .min(chunk_frames.saturating_sub(1) / 2);
+    let search = format
+        .frames(options.silence_search)
+        .min(chunk_frames.saturating_sub(overlap) / 2);
+    let frame = format.frames(SILENCE_FRAME).max(1);
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    loop {
+        let target = start + chunk_frames;
+        if target >= total {
+            chunks.push(ChunkPlan { start, end: total });
+            return chunks;
+        }
+
+        // Quietest frame in the search window before the target boundary.
+        let silent_cut = (search >= frame)
+            .then(|| {
+                let mut best: Option<(f32, usize)> = None;
+                let mut pos = target - search;
+                while pos + frame <= target {
+                    let peak = audio.peak(pos..pos + frame);
+                    if best.is_none_or(|(b, _)| peak < b) {
+                        best = Some((peak, pos + frame / 2));
+                    }
+                    pos += frame;
+                }
+                best
+            })
+            .flatten()
+            .filter(|(peak, _)| *peak <= options.silence_threshold)
+            .map(|(_, cut)| cut);
+
+        match silent_cut {
+            Some(cut) => {
+                chunks.push(ChunkPlan { start, end: cut });
+                start = cut;
+            }
+            None => {
+                chunks.push(ChunkPlan { start, end: target });
+                start = target - overlap;
+            }
+        }
+    }
+}
+
+/// Remove the words at the start of `next` that repeat the end of `previous`.
+///
+/// Used to stitch plain-text results of overlapping chunks.
+fn strip_repeated_prefix<'a>(previous: &str, next: &'a str) -> &'a str {
+    const MAX_OVERLAP_WORDS: usize = 30;
+
+    fn normalize(word: &str) -> String {
+        word.trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase()
+    }
+
+    let prev: Vec<String> = previous
+        .split_whitespace()
+        .rev()
+        .take(MAX_OVERLAP_WORDS)
+        .map(normalize)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    let next_words: Vec<(usize, &str)> = next
+        .split_whitespace()
+        .map(|w| (w.as_ptr() as usize - next.as_ptr() as usize, w))
+        .take(MAX_OVERLAP_WORDS)
+        .collect();
+
+    for len in (1..=prev.len().min(next_words.len())).rev() {
+        let matches = prev[prev.len() - len..]
+            .iter()
+            .zip(&next_words[..len])
+            .all(|(a, (_, b))| *a == normalize(b));
+        if matches {
+            return match next_words.get(len) {
+                Some((offset, _)) => &next[*offset..],
+                None => "",
+            };
+        }
+    }
+    next
+}
+
+/// Merge per-chunk responses into one, shifting timestamps by each chunk's offset.
+///
+/// Segments and words in overlapping audio are kept from the chunk that owns
+/// their midpoint (each overlap is split in half).
+fn stitch_transcriptions(
+    parts: Vec<(ChunkPlan, VerboseTranscriptionResponse)>,
+    format: &PcmFormat,
+    total_frames: usize,
+) -> VerboseTranscriptionResponse {
+    let bounds: Vec<(f64, f64)> = parts
+        .iter()
+        .enumerate()
+        .map(|(i, (plan, _))| {
+            let lower = match i.checked_sub(1).map(|p| parts[p].0) {
+                Some(prev) => format.seconds(plan.start + prev.end) / 2.0,
+                None => f64::NEG_INFINITY,
+            };
+            let upper = match parts.get(i + 1).map(|(p, _)| *p) {
+                Some(next) => format.seconds(next.start + plan.end) / 2.0,
+                None => f64::INFINITY,
+            };
+            (lower, upper)
+        })
+        .collect();
+
+    let language = parts
+        .first()
+        .map(|(_, r)| r.language.clone())
+        .unwrap_or_default();
+    let mut segments: Option<Vec<TranscriptionSegment>> = None;
+    let mut words: Option<Vec<TranscriptionWord>> = None;
+    let mut text = String::new();
+    let mut previous_end = None;
+
+    for ((plan, response), (lower, upper)) in parts.into_iter().zip(bounds) {
+        let offset = format.seconds(plan.start);
+        let owns = |start: f64, end: f64| {
+            let mid = (start + end) / 2.0;
+            mid >= lower && mid < upper
+        };
+
+        if let Some(chunk_segments) = response.segments {
+            let merged = segments.get_or_insert_with(Vec::new);
+            for mut segment in chunk_segments {
+                segment.start += offset;
+                segment.end += offset;
+                if owns(segment.start, segment.end) {
+                    segment.id = merged.len() as u32;
+                    segment.seek += (offset * 100.0).round() as u32;
+                    merged.push(segment);
+                }
+            }
+        }
+        if let Some(chunk_words) = response.words {
+            let merged = words.get_or_insert_with(Vec::new);
+            for mut word in chunk_words {
+                word.start += offset;
+                word.end += offset;
+                if owns(word.start, word.end) {
+                    merged.push(word);
+                }
+            }
+        }
+
+        // Without segments, fall back to de-duplicating the overlapping words.
+        // Chunks cut at silence share no audio, so their text is kept whole.
+        if segments.is_none() {
+            let overlaps = previous_end.is_some_and(|end| plan.start < end);
+            let chunk_text = if overlaps {
+                strip_repeated_prefix(&text, response.text.trim())
+            } else {
+                response.text.trim()
+            };
+            if !chunk_text.is_empty() {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(chunk_text);
+            }
+        }
+        previous_end = Some(plan.end);
+    }
+
+    if let Some(segments) = &segments {
+        text = segments
+            .iter()
+            .map(|s| s.text.trim())
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+    }
+
+    VerboseTranscriptionResponse {
+        task: "transcribe".into(),
+        language,
+        duration: format.seconds(total_frames),
+        text,
+        segments,
+        words,
+    }
+}
+
+/// Transcribe audio of any length by splitting it into chunks.
+///
+/// `request.data` must be a PCM WAV file, or raw PCM described by
+/// [`LongTranscriptionOptions::pcm_format`]. The audio is cut at silent points
+/// near each [`chunk_duration`](LongTranscriptionOptions::chunk_duration)
+/// boundary, or into fixed windows that overlap by
+/// [`overlap`](LongTranscriptionOptions::overlap) when no silence is found.
+/// Each chunk is sent as its own WAV file through [`transcribe_verbose`], with
+/// at most [`max_concurrency`](LongTranscriptionOptions::max_concurrency)
+/// requests in flight. The results are merged into one
+/// [`VerboseTranscriptionResponse`] with segment and word timestamps relative
+/// to the start of the whole recording.
+///
+/// Input that already fits in a single chunk is sent unchanged.
+///
+/// # Errors
+///
+/// Returns a validation error if the input is neither WAV nor described by
+/// `pcm_format`, uses a non-PCM WAV encoding, or if `max_concurrency` is zero.
+/// Fails with the first error returned for any chunk.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::audio::*;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let request = TranscriptionRequest::builder()
+///     .model("whisper-1")
+///     .filename("meeting.wav")
+///     .data(std::fs::read("meeting.wav").unwrap())
+///     .timestamp_granularity(TimestampGranularity::Segment)
+///     .build();
+///
+/// let response = transcribe_long(client, &request, &LongTranscriptionOptions::default()).await?;
+/// println!("{:.0}s: {}", response.duration, response.text);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::audio::transcribe_long` with fields `model`
+/// and `chunks`.
+#[tracing::instrument(
+    name = "foundry::audio::transcribe_long",
+    skip(client, request, options),
+    fields(model = %request.model, chunks)
+)]
+pub async fn transcribe_long(
+    client: &FoundryClient,
+    request: &TranscriptionRequest,
+    options: &LongTranscriptionOptions,
+) -> FoundryResult<VerboseTranscriptionResponse> {
+    use futures::TryStreamExt;
+
+    if options.max_concurrency == 0 {
+        return Err(FoundryError::validation_field(
+            "max_concurrency",
+            "max_concurrency must be at least 1",
+        ));
+    }
+
+    let audio = match parse_wav(&request.data)? {
+        Some(audio) => audio,
+        None => {
+            let format = options.pcm_format.ok_or_else(|| {
+                FoundryError::validation(
+                    "transcribe_long requires WAV input or LongTranscriptionOptions::pcm_format",
+                )
+            })?;
+            format.validate()?;
+            PcmAudio {
+                format,
+                samples: &request.data,
+            }
+        }
+    };
+
+    let plans = plan_chunks(&audio, options);
+    tracing::Span::current().record("chunks", plans.len());
+    tracing::debug!(chunks = plans.len(), "transcribing long audio");
+
+    // Each chunk's WAV is encoded only when its request is started, so at
+    // most `max_concurrency` encoded chunks are held at once.
+    let split = plans.len() > 1 || options.pcm_format.is_some();
+    let audio = &audio;
+    let parts: Vec<(ChunkPlan, VerboseTranscriptionResponse)> =
+        futures::stream::iter(plans.iter().copied().enumerate())
+            .map(|(i, plan)| async move {
+                let mut chunk = request.clone();
+                if split {
+                    chunk.data =
+                        encode_wav(&audio.format, audio.frames(plan.start..plan.end)).into();
+                    chunk.filename = format!("chunk_{i:04}.wav");
+                }
+                transcribe_verbose(client, &chunk)
+                    .await
+                    .map(|response| (plan, response))
+            })
+            .buffered(options.max_concurrency)
+            .try_collect()
+            .await?;
+
+    Ok(stitch_transcriptions(
+        parts,
+        &audio.format,
+        audio.frame_count(),
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1499,6 +2912,7 @@ mod tests {
     fn test_translation_response_is_transcription_response() {
         let r = TranslationResponse {
             text: "Hello".into(),
+            logprobs: None,
         };
         assert_eq!(r.text, "Hello");
     }
@@ -1834,7 +3248,7 @@ mod tests {
     #[test]
     fn test_audio_response_format_display_matches_as_str() {
         use AudioResponseFormat::*;
-        for fmt in [Json, Text, Srt, Vtt, VerboseJson] {
+        for fmt in [Json, Text, Srt, Vtt, VerboseJson, DiarizedJson] {
             assert_eq!(fmt.to_string(), fmt.as_str());
         }
     }
@@ -1842,7 +3256,7 @@ mod tests {
     #[test]
     fn test_audio_response_format_display_matches_serde() {
         use AudioResponseFormat::*;
-        for fmt in [Json, Text, Srt, Vtt, VerboseJson] {
+        for fmt in [Json, Text, Srt, Vtt, VerboseJson, DiarizedJson] {
             let serde_val = serde_json::to_value(fmt).unwrap();
             assert_eq!(
                 serde_val.as_str().unwrap(),
@@ -1852,4 +3266,743 @@ mod tests {
             );
         }
     }
+
+    // =======================================================================
+    // Timestamps, logprobs, chunking and diarization
+    // =======================================================================
+
+    fn transcription_builder() -> TranscriptionRequestBuilder {
+        TranscriptionRequest::builder()
+            .model("whisper-1")
+            .filename("a.wav")
+            .data(vec![0u8; 10])
+    }
+
+    #[test]
+    fn test_timestamp_granularities_deduplicated() {
+        let request = transcription_builder()
+            .timestamp_granularity(TimestampGranularity::Word)
+            .timestamp_granularity(TimestampGranularity::Word)
+            .timestamp_granularity(TimestampGranularity::Segment)
+            .build();
+        assert_eq!(
+            request.timestamp_granularities,
+            vec![TimestampGranularity::Word, TimestampGranularity::Segment]
+        );
+    }
+
+    #[test]
+    fn test_timestamp_granularities_require_verbose_json() {
+        let result = transcription_builder()
+            .response_format(AudioResponseFormat::Json)
+            .timestamp_granularity(TimestampGranularity::Word)
+            .try_build();
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("timestamp_granularities require the verbose_json"));
+    }
+
+    #[test]
+    fn test_timestamp_granularities_select_verbose_json() {
+        let request = transcription_builder()
+            .timestamp_granularity(TimestampGranularity::Segment)
+            .build();
+        assert_eq!(
+            request.response_format,
+            Some(AudioResponseFormat::VerboseJson)
+        );
+
+        let request = transcription_builder().build();
+        assert_eq!(request.response_format, None);
+    }
+
+    #[test]
+    fn test_logprobs_require_json() {
+        let result = transcription_builder()
+            .response_format(AudioResponseFormat::VerboseJson)
+            .include_logprobs()
+            .try_build();
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("logprobs require the json"));
+    }
+
+    #[test]
+    fn test_known_speaker_requires_data_url() {
+        let result = transcription_builder()
+            .known_speaker("agent", "https://example.com/agent.wav")
+            .try_build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_server_vad_threshold_validated() {
+        let result = transcription_builder()
+            .chunking_strategy(ChunkingStrategy::ServerVad(ServerVadConfig {
+                threshold: Some(1.5),
+                ..Default::default()
+            }))
+            .try_build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_chunking_strategy_form_value() {
+        assert_eq!(ChunkingStrategy::Auto.to_form_value(), "auto");
+        let value: serde_json::Value = serde_json::from_str(
+            &ChunkingStrategy::ServerVad(ServerVadConfig {
+                silence_duration_ms: Some(500),
+                ..Default::default()
+            })
+            .to_form_value(),
+        )
+        .unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"type": "server_vad", "silence_duration_ms": 500})
+        );
+    }
+
+    #[test]
+    fn test_transcription_response_with_logprobs() {
+        let json = serde_json::json!({
+            "text": "Hi",
+            "logprobs": [{"token": "Hi", "logprob": -0.1, "bytes": [72, 105]}]
+        });
+        let response: TranscriptionResponse = serde_json::from_value(json).unwrap();
+        let logprobs = response.logprobs.unwrap();
+        assert_eq!(logprobs[0].token, "Hi");
+        assert_eq!(logprobs[0].bytes, vec![72, 105]);
+    }
+
+    #[test]
+    fn test_verbose_transcription_response_with_words() {
+        let json = serde_json::json!({
+            "task": "transcribe",
+            "language": "en",
+            "duration": 1.0,
+            "text": "Hello there",
+            "words": [
+                {"word": "Hello", "start": 0.0, "end": 0.4},
+                {"word": "there", "start": 0.5, "end": 0.9}
+            ]
+        });
+        let response: VerboseTranscriptionResponse = serde_json::from_value(json).unwrap();
+        let words = response.words.unwrap();
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[1].word, "there");
+    }
+
+    fn verbose_response(
+        segments: Option<Vec<(f64, f64, &str)>>,
+        words: Option<Vec<(&str, f64, f64)>>,
+    ) -> VerboseTranscriptionResponse {
+        let segments = segments.map(|segs| {
+            segs.into_iter()
+                .enumerate()
+                .map(|(i, (start, end, text))| {
+                    serde_json::json!({
+                        "id": i, "seek": 0, "start": start, "end": end, "text": text,
+                        "tokens": [], "temperature": 0.0, "avg_logprob": 0.0,
+                        "compression_ratio": 1.0, "no_speech_prob": 0.0
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+        let words = words.map(|ws| {
+            ws.into_iter()
+                .map(|(word, start, end)| serde_json::json!({"word": word, "start": start, "end": end}))
+                .collect::<Vec<_>>()
+        });
+        serde_json::from_value(serde_json::json!({
+            "task": "transcribe",
+            "language": "en",
+            "duration": 3723.5,
+            "text": "Hello world. Bye.",
+            "segments": segments,
+            "words": words
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_to_srt_from_segments() {
+        let response = verbose_response(
+            Some(vec![
+                (0.0, 1.5, " Hello world."),
+                (3661.25, 3662.0, " Bye."),
+            ]),
+            None,
+        );
+        assert_eq!(
+            response.to_srt(),
+            "1\n00:00:00,000 --> 00:00:01,500\nHello world.\n\n\
+             2\n01:01:01,250 --> 01:01:02,000\nBye.\n\n"
+        );
+    }
+
+    #[test]
+    fn test_to_vtt_from_segments() {
+        let response = verbose_response(Some(vec![(0.0, 1.5, " Hello world.")]), None);
+        assert_eq!(
+            response.to_vtt(),
+            "WEBVTT\n\n00:00:00.000 --> 00:00:01.500\nHello world.\n\n"
+        );
+    }
+
+    #[test]
+    fn test_to_srt_from_words_breaks_on_sentences() {
+        let response = verbose_response(
+            None,
+            Some(vec![
+                ("Hello", 0.0, 0.4),
+                ("world.", 0.5, 1.0),
+                ("Bye.", 2.0, 2.3),
+            ]),
+        );
+        assert_eq!(
+            response.to_srt(),
+            "1\n00:00:00,000 --> 00:00:01,000\nHello world.\n\n\
+             2\n00:00:02,000 --> 00:00:02,300\nBye.\n\n"
+        );
+    }
+
+    #[test]
+    fn test_split_words_caps_cue_length() {
+        let words: Vec<TranscriptionWord> = (0..30)
+            .map(|i| TranscriptionWord {
+                word: format!("w{i}"),
+                start: i as f64,
+                end: i as f64 + 0.5,
+            })
+            .collect();
+        let groups = split_words(&words);
+        let sizes: Vec<_> = groups.iter().map(|g| g.len()).collect();
+        assert_eq!(sizes, vec![12, 12, 6]);
+    }
+
+    #[test]
+    fn test_to_vtt_without_timestamps_uses_whole_text() {
+        let response = verbose_response(None, None);
+        assert_eq!(
+            response.to_vtt(),
+            "WEBVTT\n\n00:00:00.000 --> 01:02:03.500\nHello world. Bye.\n\n"
+        );
+    }
+
+    fn diarized_json() -> serde_json::Value {
+        serde_json::json!({
+            "task": "transcribe",
+            "duration": 3.0,
+            "text": "Hi. Hello.",
+            "segments": [
+                {"type": "transcript.text.segment", "id": "seg_0", "speaker": "A", "start": 0.0, "end": 1.0, "text": " Hi."},
+                {"type": "transcript.text.segment", "id": "seg_1", "speaker": "B", "start": 1.2, "end": 2.0, "text": " Hello."},
+                {"type": "transcript.text.segment", "id": "seg_2", "speaker": "A", "start": 2.1, "end": 3.0, "text": " Ok."}
+            ]
+        })
+    }
+
+    #[test]
+    fn test_diarized_response_speakers_and_captions() {
+        let response: DiarizedTranscriptionResponse =
+            serde_json::from_value(diarized_json()).unwrap();
+
+        assert_eq!(response.speakers(), vec!["A", "B"]);
+        assert!(response
+            .to_srt()
+            .starts_with("1\n00:00:00,000 --> 00:00:01,000\nA: Hi.\n\n"));
+        assert!(response
+            .to_vtt()
+            .contains("00:00:01.200 --> 00:00:02.000\n<v B>Hello.\n"));
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_verbose_sends_granularities() {
+        use wiremock::matchers::body_string_contains;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/audio/transcriptions"))
+            .and(body_string_contains("verbose_json"))
+            .and(body_string_contains("timestamp_granularities[]"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "task": "transcribe",
+                "language": "en",
+                "duration": 0.5,
+                "text": "Hi",
+                "words": [{"word": "Hi", "start": 0.0, "end": 0.5}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = transcription_builder()
+            .timestamp_granularity(TimestampGranularity::Word)
+            .build();
+
+        let response = transcribe_verbose(&client, &request)
+            .await
+            .expect("should succeed");
+        assert_eq!(response.words.unwrap()[0].word, "Hi");
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_diarized_defaults_chunking_strategy() {
+        use wiremock::matchers::body_string_contains;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/audio/transcriptions"))
+            .and(body_string_contains("diarized_json"))
+            .and(body_string_contains("chunking_strategy"))
+            .and(body_string_contains("known_speaker_names[]"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(diarized_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = transcription_builder()
+            .model("gpt-4o-transcribe-diarize")
+            .known_speaker("agent", "data:audio/wav;base64,AAAA")
+            .build();
+
+        let response = transcribe_diarized(&client, &request)
+            .await
+            .expect("should succeed");
+        assert_eq!(response.segments.len(), 3);
+        assert_eq!(response.segments[1].speaker, "B");
+    }
+
+    // --- Long-audio transcription ---
+
+    const TEST_PCM: PcmFormat = PcmFormat {
+        sample_rate: 1000,
+        channels: 1,
+        bits_per_sample: 16,
+    };
+
+    /// 16-bit mono samples at 1 kHz: loud, except for the given silent spans (in ms).
+    fn pcm_samples(total_ms: usize, silent: &[(usize, usize)]) -> Vec<u8> {
+        (0..total_ms)
+            .flat_map(|i| {
+                let quiet = silent.iter().any(|(s, e)| (*s..*e).contains(&i));
+                let sample: i16 = if quiet { 0 } else { 20_000 };
+                sample.to_le_bytes()
+            })
+            .collect()
+    }
+
+    fn long_options() -> LongTranscriptionOptions {
+        LongTranscriptionOptions {
+            chunk_duration: Duration::from_secs(10),
+            overlap: Duration::from_secs(1),
+            silence_search: Duration::ZERO,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_wav_round_trip() {
+        let samples = pcm_samples(50, &[]);
+        let wav = encode_wav(&TEST_PCM, &samples);
+        assert_eq!(wav.len(), WAV_HEADER_LEN + samples.len());
+
+        let audio = parse_wav(&wav).unwrap().expect("should parse as WAV");
+        assert_eq!(audio.format, TEST_PCM);
+        assert_eq!(audio.samples, samples.as_slice());
+        assert_eq!(audio.frame_count(), 50);
+    }
+
+    #[test]
+    fn test_parse_wav_rejects_non_pcm_and_ignores_other_data() {
+        assert!(parse_wav(b"ID3 not a wav file").unwrap().is_none());
+
+        let mut wav = encode_wav(&TEST_PCM, &[0, 0]);
+        wav[20] = 3; // IEEE float
+        let err = parse_wav(&wav).err().expect("should reject float WAV");
+        assert!(err.to_string().contains("only PCM"));
+    }
+
+    /// `samples` in a WAVE_FORMAT_EXTENSIBLE file with the given sub-format tag.
+    fn extensible_wav(sub_format: u16, samples: &[u8]) -> Vec<u8> {
+        let canonical = encode_wav(&TEST_PCM, samples);
+        let mut fmt = canonical[20..36].to_vec();
+        fmt[0..2].copy_from_slice(&WAVE_FORMAT_EXTENSIBLE.to_le_bytes());
+        fmt.extend_from_slice(&22u16.to_le_bytes());
+        fmt.extend_from_slice(&TEST_PCM.bits_per_sample.to_le_bytes());
+        fmt.extend_from_slice(&4u32.to_le_bytes()); // front center
+        fmt.extend_from_slice(&sub_format.to_le_bytes());
+        fmt.extend_from_slice(&KSDATAFORMAT_SUBTYPE_BASE);
+
+        let mut wav = b"RIFF\0\0\0\0WAVEfmt ".to_vec();
+        wav.extend_from_slice(&(fmt.len() as u32).to_le_bytes());
+        wav.extend_from_slice(&fmt);
+        wav.extend_from_slice(&canonical[36..]);
+        wav
+    }
+
+    #[test]
+    fn test_parse_wav_checks_extensible_sub_format() {
+        let samples = pcm_samples(10, &[]);
+        let wav = extensible_wav(WAVE_FORMAT_PCM, &samples);
+        let audio = parse_wav(&wav).unwrap().expect("should parse as WAV");
+        assert_eq!(audio.format, TEST_PCM);
+        assert_eq!(audio.samples, samples.as_slice());
+
+        let wav = extensible_wav(3, &samples); // IEEE float
+        let err = parse_wav(&wav).err().expect("should reject float WAV");
+        assert!(err.to_string().contains("only PCM"));
+
+        let mut wav = extensible_wav(WAVE_FORMAT_PCM, &samples);
+        wav[50] ^= 0xFF; // not a KSDATAFORMAT_SUBTYPE GUID
+        assert!(parse_wav(&wav).is_err());
+    }
+
+    #[test]
+    fn test_plan_chunks_fixed_windows_with_overlap() {
+        let samples = pcm_samples(25_000, &[]);
+        let audio = PcmAudio {
+            format: TEST_PCM,
+            samples: &samples,
+        };
+
+        let plans = plan_chunks(&audio, &long_options());
+        assert_eq!(
+            plans,
+            vec![
+                ChunkPlan {
+                    start: 0,
+                    end: 10_000
+                },
+                ChunkPlan {
+                    start: 9_000,
+                    end: 19_000
+                },
+                ChunkPlan {
+                    start: 18_000,
+                    end: 25_000
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_chunks_cuts_at_silence() {
+        let samples = pcm_samples(18_000, &[(8_400, 8_600)]);
+        let audio = PcmAudio {
+            format: TEST_PCM,
+            samples: &samples,
+        };
+        let options = LongTranscriptionOptions {
+            silence_search: Duration::from_secs(3),
+            ..long_options()
+        };
+
+        let plans = plan_chunks(&audio, &options);
+        assert_eq!(plans.len(), 2);
+        let cut = plans[0].end;
+        assert!((8_400..8_600).contains(&cut), "cut at {cut}");
+        assert_eq!(plans[1].start, cut, "silence cuts do not overlap");
+        assert_eq!(plans[1].end, 18_000);
+    }
+
+    #[test]
+    fn test_strip_repeated_prefix() {
+        assert_eq!(
+            strip_repeated_prefix("we went to the store", "The store was closed."),
+            "was closed."
+        );
+        assert_eq!(strip_repeated_prefix("", "hello"), "hello");
+        assert_eq!(strip_repeated_prefix("one two", "three four"), "three four");
+        assert_eq!(strip_repeated_prefix("one two", "two"), "");
+    }
+
+    #[test]
+    fn test_stitch_offsets_and_deduplicates_overlap() {
+        let first = verbose_response(
+            Some(vec![(0.0, 4.0, " First."), (4.0, 9.6, " Across the seam.")]),
+            Some(vec![("First", 0.0, 1.0), ("seam", 9.2, 9.6)]),
+        );
+        // Second chunk starts at 9.0s, so its local 0.0..0.6 repeats "seam".
+        let second = verbose_response(
+            Some(vec![(0.0, 0.6, " seam."), (0.6, 5.0, " Last.")]),
+            Some(vec![("seam", 0.2, 0.6), ("Last", 1.0, 2.0)]),
+        );
+        let parts = vec![
+            (
+                ChunkPlan {
+                    start: 0,
+                    end: 10_000,
+                },
+                first,
+            ),
+            (
+                ChunkPlan {
+                    start: 9_000,
+                    end: 15_000,
+                },
+                second,
+            ),
+        ];
+
+        let merged = stitch_transcriptions(parts, &TEST_PCM, 15_000);
+        assert_eq!(merged.duration, 15.0);
+        assert_eq!(merged.text, "First. Across the seam. Last.");
+
+        let segments = merged.segments.unwrap();
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[2].id, 2);
+        assert_eq!(segments[2].start, 9.6);
+        assert_eq!(segments[2].end, 14.0);
+        assert_eq!(segments[2].seek, 900);
+
+        let words = merged.words.unwrap();
+        let words: Vec<_> = words.iter().map(|w| (w.word.as_str(), w.start)).collect();
+        assert_eq!(words, vec![("First", 0.0), ("seam", 9.2), ("Last", 10.0)]);
+    }
+
+    #[test]
+    fn test_stitch_keeps_repeated_words_across_silence_cuts() {
+        let text_only = |text: &str| {
+            let mut response = verbose_response(None, None);
+            response.text = text.into();
+            response
+        };
+        let plan = |start, end| ChunkPlan { start, end };
+
+        let cut = vec![
+            (plan(0, 8_500), text_only("Say it again.")),
+            (plan(8_500, 15_000), text_only("Again.")),
+        ];
+        let merged = stitch_transcriptions(cut, &TEST_PCM, 15_000);
+        assert_eq!(merged.text, "Say it again. Again.");
+
+        let overlapping = vec![
+            (plan(0, 10_000), text_only("Say it again.")),
+            (plan(9_000, 15_000), text_only("Again. Done.")),
+        ];
+        let merged = stitch_transcriptions(overlapping, &TEST_PCM, 15_000);
+        assert_eq!(merged.text, "Say it again. Done.");
+    }
+
+    #[test]
+    fn test_peak_supports_integer_bit_depths() {
+        let format = |bits_per_sample| PcmFormat {
+            bits_per_sample,
+            ..TEST_PCM
+        };
+        let peak = |bits, samples: &[u8]| {
+            PcmAudio {
+                format: format(bits),
+                samples,
+            }
+            .peak(0..samples.len() / (bits as usize / 8))
+        };
+
+        assert_eq!(peak(8, &[128, 128]), 0.0);
+        assert_eq!(peak(8, &[128, 0]), 1.0);
+        assert_eq!(peak(16, &(-16_384_i16).to_le_bytes()), 0.5);
+        assert_eq!(peak(24, &[0x00, 0x00, 0xC0]), 0.5);
+        assert_eq!(peak(32, &(1_i32 << 30).to_le_bytes()), 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_long_sends_chunks_and_merges() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/audio/transcriptions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "task": "transcribe",
+                "language": "en",
+                "duration": 5.0,
+                "text": "Chunk.",
+                "segments": [{
+                    "id": 0, "seek": 0, "start": 1.0, "end": 2.0, "text": " Chunk.",
+                    "tokens": [], "temperature": 0.0, "avg_logprob": 0.0,
+                    "compression_ratio": 1.0, "no_speech_prob": 0.0
+                }]
+            })))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = transcription_builder()
+            .data(encode_wav(&TEST_PCM, &pcm_samples(25_000, &[])))
+            .build();
+
+        let response = transcribe_long(&client, &request, &long_options())
+            .await
+            .expect("should succeed");
+        assert_eq!(response.duration, 25.0);
+        assert_eq!(response.language, "en");
+        let starts: Vec<f64> = response.segments.unwrap().iter().map(|s| s.start).collect();
+        assert_eq!(starts, vec![1.0, 10.0, 19.0]);
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_long_requires_wav_or_pcm_format() {
+        let server = MockServer::start().await;
+        let client = setup_mock_client(&server).await;
+        let request = transcription_builder().build();
+
+        let err = transcribe_long(&client, &request, &long_options())
+            .await
+            .expect_err("should reject unknown input");
+        assert!(matches!(err, FoundryError::Validation { .. }));
+
+        let options = LongTranscriptionOptions {
+            max_concurrency: 0,
+            ..long_options()
+        };
+        let err = transcribe_long(&client, &request, &options)
+            .await
+            .expect_err("should reject zero concurrency");
+        assert!(err.to_string().contains("max_concurrency"));
+    }
+
+    // --- Voices, instructions and streaming speech ---
+
+    #[test]
+    fn test_voice_from_str_and_custom() {
+        assert_eq!(Voice::from("alloy"), Voice::Alloy);
+        assert_eq!(Voice::from("marin"), Voice::Custom("marin".into()));
+        assert_eq!(Voice::from(String::from("coral")), Voice::Coral);
+        assert_eq!(Voice::Custom("marin".into()).to_string(), "marin");
+    }
+
+    #[test]
+    fn test_voice_serde_round_trip() {
+        assert_eq!(serde_json::to_value(Voice::Shimmer).unwrap(), "shimmer");
+        assert_eq!(
+            serde_json::to_value(Voice::Custom("cedar".into())).unwrap(),
+            "cedar"
+        );
+        let voice: Voice = serde_json::from_value(serde_json::json!("verse")).unwrap();
+        assert_eq!(voice, Voice::Verse);
+    }
+
+    #[test]
+    fn test_speech_request_with_instructions_and_stream_format() {
+        let request = SpeechRequest::builder()
+            .model("gpt-4o-mini-tts")
+            .input("Hello")
+            .voice(Voice::Coral)
+            .instructions("Whisper.")
+            .stream_format(SpeechStreamFormat::Sse)
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["voice"], "coral");
+        assert_eq!(json["instructions"], "Whisper.");
+        assert_eq!(json["stream_format"], "sse");
+    }
+
+    #[test]
+    fn test_speech_request_rejects_empty_instructions() {
+        let result = SpeechRequest::builder()
+            .model("gpt-4o-mini-tts")
+            .input("Hello")
+            .voice("alloy")
+            .instructions("  ")
+            .try_build();
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("instructions cannot be empty"));
+    }
+
+    #[tokio::test]
+    async fn test_speak_stream_yields_raw_audio() {
+        use futures::TryStreamExt;
+
+        let server = MockServer::start().await;
+        let audio_bytes = vec![0x52, 0x49, 0x46, 0x46, 0x00, 0x01];
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/audio/speech"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(audio_bytes.clone()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = SpeechRequest::builder()
+            .model("tts-1")
+            .input("Hello world")
+            .voice("alloy")
+            .build();
+
+        let chunks: Vec<bytes::Bytes> = speak_stream(&client, &request)
+            .await
+            .expect("should succeed")
+            .try_collect()
+            .await
+            .expect("stream should succeed");
+        assert_eq!(chunks.concat(), audio_bytes);
+    }
+
+    #[tokio::test]
+    async fn test_speak_stream_decodes_sse_deltas() {
+        use futures::TryStreamExt;
+        use wiremock::matchers::body_partial_json;
+
+        let server = MockServer::start().await;
+        let body = concat!(
+            "data: {\"type\":\"speech.audio.delta\",\"audio\":\"AQI=\"}\n\n",
+            "data: {\"type\":\"speech.audio.delta\",\"audio\":\"AwQ=\"}\n\n",
+            "data: {\"type\":\"speech.audio.done\",\"usage\":{\"total_tokens\":5}}\n\n",
+        );
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/audio/speech"))
+            .and(body_partial_json(
+                serde_json::json!({"stream_format": "sse"}),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_string(body),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = SpeechRequest::builder()
+            .model("gpt-4o-mini-tts")
+            .input("Hello world")
+            .voice(Voice::Ash)
+            .stream_format(SpeechStreamFormat::Sse)
+            .build();
+
+        let chunks: Vec<bytes::Bytes> = speak_stream(&client, &request)
+            .await
+            .expect("should succeed")
+            .try_collect()
+            .await
+            .expect("stream should succeed");
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.concat(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_parse_speech_event_rejects_bad_base64() {
+        let event = SseEvent {
+            event: "message".into(),
+            data: r#"{"type":"speech.audio.delta","audio":"!!"}"#.into(),
+            id: None,
+            retry: None,
+        };
+        let result = parse_speech_event(&event).expect("should yield an item");
+        assert!(result.unwrap_err().to_string().contains("invalid base64"));
+    }
 }
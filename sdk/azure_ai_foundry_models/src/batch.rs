@@ -0,0 +1,1192 @@
+//! Batch API types and functions for Azure AI Foundry Models.
+//!
+//! The Batch API runs large numbers of chat completion or embedding requests
+//! asynchronously, at lower cost, within a completion window (24 hours).
+//! Requests are written as JSONL, uploaded through the files endpoint, and
+//! results are downloaded as JSONL once the batch finishes.
+//!
+//! This module builds the input file from typed requests
+//! ([`ChatCompletionRequest`], [`EmbeddingRequest`]), manages batch jobs, and
+//! parses the output and error files back into typed responses keyed by
+//! `custom_id`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_models::batch::{self, BatchInput};
+//! # use azure_ai_foundry_models::embeddings::EmbeddingRequest;
+//! # use std::time::Duration;
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let mut input = BatchInput::new();
+//! for (id, text) in [("doc-1", "first document"), ("doc-2", "second document")] {
+//!     let request = EmbeddingRequest::builder()
+//!         .model("text-embedding-3-small-batch")
+//!         .input(text)
+//!         .build();
+//!     input.push(id, &request)?;
+//! }
+//!
+//! let job = batch::submit(client, &input).await?;
+//! let job = batch::poll_until_complete(client, &job.id, Duration::from_secs(60), None).await?;
+//!
+//! let results = batch::download_results::<EmbeddingRequest>(client, &job).await?;
+//! for item in results.iter() {
+//!     match &item.result {
+//!         Ok(response) => println!("{}: {} dims", item.custom_id, response.data[0].embedding.len()),
+//!         Err(error) => println!("{} failed: {}", item.custom_id, error.message),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+use azure_ai_foundry_core::client::FoundryClient;
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::chat::{ChatCompletionRequest, ChatCompletionResponse};
+use crate::embeddings::{EmbeddingRequest, EmbeddingResponse};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Maximum number of requests in one batch input file.
+pub const MAX_BATCH_REQUESTS: usize = 100_000;
+
+/// Maximum size of a batch input file (200 MB).
+pub const MAX_BATCH_FILE_SIZE: usize = 200 * 1024 * 1024;
+
+/// Default completion window for new batches.
+pub const DEFAULT_COMPLETION_WINDOW: &str = "24h";
+
+// ---------------------------------------------------------------------------
+// Batch endpoints and requests
+// ---------------------------------------------------------------------------
+
+/// The API endpoint a batch runs against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BatchEndpoint {
+    /// Chat completions.
+    #[serde(rename = "/v1/chat/completions", alias = "/chat/completions")]
+    ChatCompletions,
+    /// Embeddings.
+    #[serde(rename = "/v1/embeddings", alias = "/embeddings")]
+    Embeddings,
+}
+
+impl BatchEndpoint {
+    /// Return the endpoint path used in batch files and requests.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ChatCompletions => "/v1/chat/completions",
+            Self::Embeddings => "/v1/embeddings",
+        }
+    }
+}
+
+/// A request type that can be run in a batch.
+///
+/// Implemented for [`ChatCompletionRequest`] and [`EmbeddingRequest`].
+pub trait BatchRequest: Serialize {
+    /// The response type found in the batch output file.
+    type Response: DeserializeOwned;
+
+    /// The endpoint these requests are sent to.
+    const ENDPOINT: BatchEndpoint;
+}
+
+impl BatchRequest for ChatCompletionRequest {
+    type Response = ChatCompletionResponse;
+    const ENDPOINT: BatchEndpoint = BatchEndpoint::ChatCompletions;
+}
+
+impl BatchRequest for EmbeddingRequest {
+    type Response = EmbeddingResponse;
+    const ENDPOINT: BatchEndpoint = BatchEndpoint::Embeddings;
+}
+
+/// One line of a batch input file.
+#[derive(Serialize)]
+struct BatchInputLine<'a, R> {
+    custom_id: &'a str,
+    method: &'static str,
+    url: &'static str,
+    body: &'a R,
+}
+
+/// A batch input file under construction.
+///
+/// Each request is serialized as one JSONL line tagged with a unique
+/// `custom_id`, which is used to match results back to requests.
+pub struct BatchInput<R: BatchRequest> {
+    jsonl: Vec<u8>,
+    custom_ids: HashSet<String>,
+    _request: PhantomData<fn(&R)>,
+}
+
+impl<R: BatchRequest> std::fmt::Debug for BatchInput<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchInput")
+            .field("endpoint", &R::ENDPOINT)
+            .field("requests", &self.custom_ids.len())
+            .field("bytes", &self.jsonl.len())
+            .finish()
+    }
+}
+
+impl<R: BatchRequest> Default for BatchInput<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: BatchRequest> BatchInput<R> {
+    /// Create an empty batch input.
+    pub fn new() -> Self {
+        Self {
+            jsonl: Vec::new(),
+            custom_ids: HashSet::new(),
+            _request: PhantomData,
+        }
+    }
+
+    /// Add a request identified by `custom_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `custom_id` is empty or already used,
+    /// or if the input would exceed [`MAX_BATCH_REQUESTS`] or
+    /// [`MAX_BATCH_FILE_SIZE`].
+    pub fn push(&mut self, custom_id: impl Into<String>, request: &R) -> FoundryResult<()> {
+        let custom_id = custom_id.into();
+        if custom_id.trim().is_empty() {
+            return Err(FoundryError::validation_field(
+                "custom_id",
+                "custom_id cannot be empty",
+            ));
+        }
+        if self.custom_ids.contains(&custom_id) {
+            return Err(FoundryError::validation_field(
+                "custom_id",
+                format!("duplicate custom_id: {}", custom_id),
+            ));
+        }
+        if self.custom_ids.len() >= MAX_BATCH_REQUESTS {
+            return Err(FoundryError::validation(format!(
+                "batch input cannot contain more than {} requests",
+                MAX_BATCH_REQUESTS
+            )));
+        }
+
+        let mut line = serde_json::to_vec(&BatchInputLine {
+            custom_id: &custom_id,
+            method: "POST",
+            url: R::ENDPOINT.as_str(),
+            body: request,
+        })?;
+        line.push(b'\n');
+        if self.jsonl.len() + line.len() > MAX_BATCH_FILE_SIZE {
+            return Err(FoundryError::validation(
+                "batch input exceeds the 200 MB file size limit",
+            ));
+        }
+
+        self.jsonl.extend_from_slice(&line);
+        self.custom_ids.insert(custom_id);
+        Ok(())
+    }
+
+    /// Number of requests in the input.
+    pub fn len(&self) -> usize {
+        self.custom_ids.len()
+    }
+
+    /// Whether the input has no requests.
+    pub fn is_empty(&self) -> bool {
+        self.custom_ids.is_empty()
+    }
+
+    /// The endpoint of the requests in this input.
+    pub fn endpoint(&self) -> BatchEndpoint {
+        R::ENDPOINT
+    }
+
+    /// The JSONL file contents.
+    pub fn to_jsonl(&self) -> bytes::Bytes {
+        bytes::Bytes::copy_from_slice(&self.jsonl)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Batch create request
+// ---------------------------------------------------------------------------
+
+/// A request to create a batch from an uploaded input file.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchCreateRequest {
+    /// ID of the uploaded JSONL input file.
+    pub input_file_id: String,
+    /// The endpoint the requests in the file are sent to.
+    pub endpoint: BatchEndpoint,
+    /// Time frame within which the batch is processed (`24h`).
+    pub completion_window: String,
+    /// Key-value pairs attached to the batch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl BatchCreateRequest {
+    /// Create a new builder.
+    pub fn builder() -> BatchCreateRequestBuilder {
+        BatchCreateRequestBuilder {
+            input_file_id: None,
+            endpoint: None,
+            completion_window: None,
+            metadata: None,
+        }
+    }
+}
+
+/// Builder for [`BatchCreateRequest`].
+#[derive(Debug)]
+pub struct BatchCreateRequestBuilder {
+    input_file_id: Option<String>,
+    endpoint: Option<BatchEndpoint>,
+    completion_window: Option<String>,
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl BatchCreateRequestBuilder {
+    /// Set the ID of the uploaded input file.
+    pub fn input_file_id(mut self, id: impl Into<String>) -> Self {
+        self.input_file_id = Some(id.into());
+        self
+    }
+
+    /// Set the endpoint the requests are sent to.
+    pub fn endpoint(mut self, endpoint: BatchEndpoint) -> Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    /// Set the completion window (defaults to [`DEFAULT_COMPLETION_WINDOW`]).
+    pub fn completion_window(mut self, window: impl Into<String>) -> Self {
+        self.completion_window = Some(window.into());
+        self
+    }
+
+    /// Attach a metadata key-value pair.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Build the request, returning an error if required fields are missing.
+    pub fn try_build(self) -> FoundryResult<BatchCreateRequest> {
+        let input_file_id = self
+            .input_file_id
+            .ok_or_else(|| FoundryError::Builder("input_file_id is required".into()))?;
+        if input_file_id.trim().is_empty() {
+            return Err(FoundryError::Builder(
+                "input_file_id cannot be empty".into(),
+            ));
+        }
+
+        let endpoint = self
+            .endpoint
+            .ok_or_else(|| FoundryError::Builder("endpoint is required".into()))?;
+
+        Ok(BatchCreateRequest {
+            input_file_id,
+            endpoint,
+            completion_window: self
+                .completion_window
+                .unwrap_or_else(|| DEFAULT_COMPLETION_WINDOW.into()),
+            metadata: self.metadata,
+        })
+    }
+
+    /// Build the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input_file_id` or `endpoint` is not set.
+    /// Use [`try_build`](Self::try_build) for fallible construction.
+    pub fn build(self) -> BatchCreateRequest {
+        self.try_build().expect("builder validation failed")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Response types
+// ---------------------------------------------------------------------------
+
+/// A file uploaded for batch processing.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchFile {
+    /// Unique identifier for the file.
+    pub id: String,
+    /// File size in bytes.
+    pub bytes: u64,
+    /// Unix timestamp when the file was created.
+    pub created_at: u64,
+    /// Original filename.
+    pub filename: String,
+    /// The purpose of the file (`batch` for input files).
+    pub purpose: String,
+    /// Processing status of the file.
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// Status of a batch job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+    /// The input file is being validated.
+    Validating,
+    /// Validation failed.
+    Failed,
+    /// Requests are being processed.
+    InProgress,
+    /// Results are being prepared.
+    Finalizing,
+    /// The batch completed; results are available.
+    Completed,
+    /// The batch did not finish within the completion window.
+    Expired,
+    /// The batch is being cancelled.
+    Cancelling,
+    /// The batch was cancelled.
+    Cancelled,
+    /// A status not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+impl BatchStatus {
+    /// Whether the batch has stopped changing.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Failed | Self::Completed | Self::Expired | Self::Cancelled
+        )
+    }
+}
+
+/// Request counts for a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct BatchRequestCounts {
+    /// Total number of requests.
+    pub total: u32,
+    /// Requests that completed successfully.
+    pub completed: u32,
+    /// Requests that failed.
+    pub failed: u32,
+}
+
+/// An error that stopped a batch, typically from input validation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchError {
+    /// Error code.
+    #[serde(default)]
+    pub code: Option<String>,
+    /// Human-readable message.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// The input line the error refers to.
+    #[serde(default)]
+    pub line: Option<u64>,
+}
+
+/// Errors reported on a batch.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchErrors {
+    /// The individual errors.
+    #[serde(default)]
+    pub data: Vec<BatchError>,
+}
+
+/// A batch job.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Batch {
+    /// Unique identifier for the batch.
+    pub id: String,
+    /// The endpoint the batch runs against.
+    pub endpoint: BatchEndpoint,
+    /// ID of the input file.
+    pub input_file_id: String,
+    /// The completion window.
+    pub completion_window: String,
+    /// Current status.
+    pub status: BatchStatus,
+    /// ID of the file with successful results.
+    #[serde(default)]
+    pub output_file_id: Option<String>,
+    /// ID of the file with failed requests.
+    #[serde(default)]
+    pub error_file_id: Option<String>,
+    /// Errors that stopped the batch.
+    #[serde(default)]
+    pub errors: Option<BatchErrors>,
+    /// Unix timestamp when the batch was created.
+    pub created_at: u64,
+    /// Unix timestamp when the batch completed.
+    #[serde(default)]
+    pub completed_at: Option<u64>,
+    /// Unix timestamp when the batch expires.
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+    /// Request counts.
+    #[serde(default)]
+    pub request_counts: Option<BatchRequestCounts>,
+    /// Metadata attached to the batch.
+    #[serde(default)]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// A page of batches.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchList {
+    /// The batches on this page.
+    pub data: Vec<Batch>,
+    /// ID of the first batch on this page.
+    #[serde(default)]
+    pub first_id: Option<String>,
+    /// ID of the last batch on this page; pass it as `after` for the next page.
+    #[serde(default)]
+    pub last_id: Option<String>,
+    /// Whether more batches are available.
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Results
+// ---------------------------------------------------------------------------
+
+/// Why a single request in a batch failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchItemError {
+    /// HTTP status of the request, when it reached the model.
+    pub status_code: Option<u16>,
+    /// Error code.
+    pub code: Option<String>,
+    /// Human-readable message.
+    pub message: String,
+}
+
+/// The outcome of one request in a batch.
+#[derive(Debug, Clone)]
+pub struct BatchResultItem<T> {
+    /// The `custom_id` given to the request.
+    pub custom_id: String,
+    /// The response, or why the request failed.
+    pub result: Result<T, BatchItemError>,
+}
+
+/// Parsed results of a batch, keyed by `custom_id`.
+#[derive(Debug, Clone)]
+pub struct BatchResults<T> {
+    items: Vec<BatchResultItem<T>>,
+    index: HashMap<String, usize>,
+}
+
+impl<T> BatchResults<T> {
+    fn new(items: Vec<BatchResultItem<T>>) -> Self {
+        let index = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (item.custom_id.clone(), i))
+            .collect();
+        Self { items, index }
+    }
+
+    /// Look up the result for a `custom_id`.
+    pub fn get(&self, custom_id: &str) -> Option<&BatchResultItem<T>> {
+        self.index.get(custom_id).map(|&i| &self.items[i])
+    }
+
+    /// Iterate over all results, successful ones first.
+    pub fn iter(&self) -> impl Iterator<Item = &BatchResultItem<T>> {
+        self.items.iter()
+    }
+
+    /// Iterate over successful responses.
+    pub fn succeeded(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.items
+            .iter()
+            .filter_map(|item| Some((item.custom_id.as_str(), item.result.as_ref().ok()?)))
+    }
+
+    /// Iterate over failed requests.
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &BatchItemError)> {
+        self.items
+            .iter()
+            .filter_map(|item| Some((item.custom_id.as_str(), item.result.as_ref().err()?)))
+    }
+
+    /// Number of results.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether there are no results.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Consume the results, returning them as a map keyed by `custom_id`.
+    pub fn into_map(self) -> HashMap<String, Result<T, BatchItemError>> {
+        self.items
+            .into_iter()
+            .map(|item| (item.custom_id, item.result))
+            .collect()
+    }
+}
+
+/// One line of a batch output or error file.
+#[derive(Deserialize)]
+struct BatchOutputLine {
+    custom_id: String,
+    #[serde(default)]
+    response: Option<BatchOutputResponse>,
+    #[serde(default)]
+    error: Option<BatchOutputError>,
+}
+
+#[derive(Deserialize)]
+struct BatchOutputResponse {
+    status_code: u16,
+    #[serde(default)]
+    body: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct BatchOutputError {
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// Parse a batch output or error file into typed results.
+///
+/// Lines with a 2xx `response` are deserialized into `T`; lines with an
+/// `error`, a non-2xx status or a body that does not match `T` become
+/// [`BatchItemError`]s.
+///
+/// # Errors
+///
+/// Returns a validation error naming the line if a line is not valid JSON.
+pub fn parse_results<T: DeserializeOwned>(jsonl: &[u8]) -> FoundryResult<Vec<BatchResultItem<T>>> {
+    let invalid = |n: usize, e: serde_json::Error| {
+        FoundryError::validation(format!("invalid batch output line {}: {}", n + 1, e))
+    };
+
+    let mut items = Vec::new();
+    for (n, line) in jsonl.split(|&b| b == b'\n').enumerate() {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let line: BatchOutputLine = serde_json::from_slice(line).map_err(|e| invalid(n, e))?;
+
+        let result = match (line.response, line.error) {
+            (Some(response), None) if (200..300).contains(&response.status_code) => {
+                serde_json::from_value(response.body).map_err(|e| BatchItemError {
+                    status_code: Some(response.status_code),
+                    code: Some("invalid_response".into()),
+                    message: format!("response body could not be parsed: {}", e),
+                })
+            }
+            (response, error) => {
+                let status_code = response.as_ref().map(|r| r.status_code);
+                let body_error = response
+                    .as_ref()
+                    .and_then(|r| r.body.get("error"))
+                    .cloned()
+                    .unwrap_or_default();
+                let field = |name: &str| {
+                    body_error
+                        .get(name)
+                        .and_then(|v| v.as_str())
+                        .map(String::from)
+                };
+                let (code, message) = match error {
+                    Some(error) => (error.code, error.message),
+                    None => (field("code"), field("message")),
+                };
+                Err(BatchItemError {
+                    status_code,
+                    code,
+                    message: message.unwrap_or_else(|| "request failed".into()),
+                })
+            }
+        };
+        items.push(BatchResultItem {
+            custom_id: line.custom_id,
+            result,
+        });
+    }
+    Ok(items)
+}
+
+// ---------------------------------------------------------------------------
+// API functions
+// ---------------------------------------------------------------------------
+
+/// Upload a batch input file.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::batch::upload_input` with fields `endpoint`
+/// and `requests`.
+#[tracing::instrument(
+    name = "foundry::batch::upload_input",
+    skip(client, input),
+    fields(endpoint = %input.endpoint().as_str(), requests = input.len())
+)]
+pub async fn upload_input<R: BatchRequest>(
+    client: &FoundryClient,
+    input: &BatchInput<R>,
+) -> FoundryResult<BatchFile> {
+    if input.is_empty() {
+        return Err(FoundryError::validation("batch input has no requests"));
+    }
+    tracing::debug!(size_bytes = input.jsonl.len(), "uploading batch input");
+
+    let data = input.to_jsonl();
+    let response = client
+        .post_multipart("/openai/v1/files", move || {
+            let part = reqwest::multipart::Part::stream_with_length(
+                reqwest::Body::from(data.clone()),
+                data.len() as u64,
+            )
+            .file_name("batch_input.jsonl");
+            reqwest::multipart::Form::new()
+                .part("file", part)
+                .text("purpose", "batch")
+        })
+        .await?;
+
+    let file = response.json::<BatchFile>().await?;
+    tracing::debug!(file_id = %file.id, "batch input uploaded");
+    Ok(file)
+}
+
+/// Create a batch from an uploaded input file.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::batch::create` with field `input_file_id`.
+#[tracing::instrument(
+    name = "foundry::batch::create",
+    skip(client, request),
+    fields(input_file_id = %request.input_file_id)
+)]
+pub async fn create(client: &FoundryClient, request: &BatchCreateRequest) -> FoundryResult<Batch> {
+    tracing::debug!("creating batch");
+    let response = client.post("/openai/v1/batches", request).await?;
+    let batch = response.json::<Batch>().await?;
+    tracing::debug!(batch_id = %batch.id, "batch created");
+    Ok(batch)
+}
+
+/// Upload `input` and create a batch from it with the default completion window.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::batch::submit`.
+#[tracing::instrument(name = "foundry::batch::submit", skip(client, input))]
+pub async fn submit<R: BatchRequest>(
+    client: &FoundryClient,
+    input: &BatchInput<R>,
+) -> FoundryResult<Batch> {
+    let file = upload_input(client, input).await?;
+    let request = BatchCreateRequest::builder()
+        .input_file_id(file.id)
+        .endpoint(R::ENDPOINT)
+        .build();
+    create(client, &request).await
+}
+
+/// Get a batch by ID.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::batch::get` with field `batch_id`.
+#[tracing::instrument(name = "foundry::batch::get", skip(client), fields(batch_id = %batch_id))]
+pub async fn get(client: &FoundryClient, batch_id: &str) -> FoundryResult<Batch> {
+    tracing::debug!("getting batch");
+    FoundryClient::validate_resource_id(batch_id)?;
+    let response = client
+        .get(&format!("/openai/v1/batches/{}", batch_id))
+        .await?;
+    let batch = response.json::<Batch>().await?;
+    Ok(batch)
+}
+
+/// Cancel a batch.
+///
+/// The batch moves to `cancelling` and then `cancelled`; results of requests
+/// that already finished remain available.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::batch::cancel` with field `batch_id`.
+#[tracing::instrument(name = "foundry::batch::cancel", skip(client), fields(batch_id = %batch_id))]
+pub async fn cancel(client: &FoundryClient, batch_id: &str) -> FoundryResult<Batch> {
+    tracing::debug!("cancelling batch");
+    FoundryClient::validate_resource_id(batch_id)?;
+    let response = client
+        .post(
+            &format!("/openai/v1/batches/{}/cancel", batch_id),
+            &serde_json::json!({}),
+        )
+        .await?;
+    let batch = response.json::<Batch>().await?;
+    Ok(batch)
+}
+
+/// List batches, newest first.
+///
+/// Pass the previous page's [`BatchList::last_id`] as `after` to get the
+/// next page.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::batch::list`.
+#[tracing::instrument(name = "foundry::batch::list", skip(client))]
+pub async fn list(
+    client: &FoundryClient,
+    after: Option<&str>,
+    limit: Option<u32>,
+) -> FoundryResult<BatchList> {
+    tracing::debug!("listing batches");
+    let mut query = Vec::new();
+    if let Some(after) = after {
+        FoundryClient::validate_resource_id(after)?;
+        query.push(format!("after={}", after));
+    }
+    if let Some(limit) = limit {
+        query.push(format!("limit={}", limit));
+    }
+    let path = if query.is_empty() {
+        "/openai/v1/batches".to_string()
+    } else {
+        format!("/openai/v1/batches?{}", query.join("&"))
+    };
+    let response = client.get(&path).await?;
+    let list = response.json::<BatchList>().await?;
+    Ok(list)
+}
+
+/// Poll a batch until it reaches a terminal state.
+///
+/// A status this SDK does not know ([`BatchStatus::Unknown`]) also ends
+/// polling, returning the batch as is, since it may never change to a known
+/// terminal state.
+///
+/// # Warning
+///
+/// Batches can take up to the full completion window. When `max_attempts` is
+/// `None` this polls until the batch finishes; use a long `poll_interval`.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::batch::poll_until_complete` with field `batch_id`.
+#[tracing::instrument(
+    name = "foundry::batch::poll_until_complete",
+    skip(client),
+    fields(batch_id = %batch_id)
+)]
+pub async fn poll_until_complete(
+    client: &FoundryClient,
+    batch_id: &str,
+    poll_interval: std::time::Duration,
+    max_attempts: Option<u32>,
+) -> FoundryResult<Batch> {
+    let mut attempts: u32 = 0;
+    loop {
+        let batch = get(client, batch_id).await?;
+        if batch.status.is_terminal() {
+            tracing::debug!(status = ?batch.status, "batch reached terminal state");
+            return Ok(batch);
+        }
+        if batch.status == BatchStatus::Unknown {
+            tracing::warn!("batch has an unknown status, stopping polling");
+            return Ok(batch);
+        }
+
+        attempts += 1;
+        if let Some(max) = max_attempts {
+            if attempts >= max {
+                return Err(FoundryError::validation(format!(
+                    "batch did not complete after {} poll attempts",
+                    max
+                )));
+            }
+        }
+        tracing::trace!(status = ?batch.status, attempt = attempts, "batch still in progress");
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Download the output and error files of a batch and parse them.
+///
+/// `R` is the request type the batch was built from; it selects the
+/// response type. Successful results come first, followed by failures.
+///
+/// # Errors
+///
+/// Returns a validation error if `R` does not match the batch endpoint or
+/// a result line cannot be parsed.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::batch::download_results` with field `batch_id`.
+#[tracing::instrument(
+    name = "foundry::batch::download_results",
+    skip(client, batch),
+    fields(batch_id = %batch.id)
+)]
+pub async fn download_results<R: BatchRequest>(
+    client: &FoundryClient,
+    batch: &Batch,
+) -> FoundryResult<BatchResults<R::Response>> {
+    if batch.endpoint != R::ENDPOINT {
+        return Err(FoundryError::validation(format!(
+            "batch endpoint {} does not match the requested result type ({})",
+            batch.endpoint.as_str(),
+            R::ENDPOINT.as_str()
+        )));
+    }
+
+    let mut items = Vec::new();
+    for file_id in [&batch.output_file_id, &batch.error_file_id]
+        .into_iter()
+        .flatten()
+    {
+        FoundryClient::validate_resource_id(file_id)?;
+        let content = client
+            .get_bytes(&format!("/openai/v1/files/{}/content", file_id))
+            .await?;
+        items.extend(parse_results::<R::Response>(&content)?);
+    }
+
+    tracing::debug!(results = items.len(), "batch results downloaded");
+    Ok(BatchResults::new(items))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat::Message;
+    use crate::test_utils::{setup_mock_client, TEST_TIMESTAMP};
+    use wiremock::matchers::{body_json, body_string_contains, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn embedding_request(text: &str) -> EmbeddingRequest {
+        EmbeddingRequest::builder()
+            .model("text-embedding-3-small")
+            .input(text)
+            .build()
+    }
+
+    fn batch_json(status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": "batch_abc",
+            "object": "batch",
+            "endpoint": "/v1/embeddings",
+            "input_file_id": "file-in",
+            "completion_window": "24h",
+            "status": status,
+            "output_file_id": "file-out",
+            "error_file_id": "file-err",
+            "created_at": TEST_TIMESTAMP,
+            "request_counts": {"total": 3, "completed": 2, "failed": 1}
+        })
+    }
+
+    #[test]
+    fn test_batch_input_jsonl_lines() {
+        let mut input = BatchInput::new();
+        input.push("a", &embedding_request("hello")).unwrap();
+        input.push("b", &embedding_request("world")).unwrap();
+
+        assert_eq!(input.len(), 2);
+        let jsonl = input.to_jsonl();
+        let lines: Vec<serde_json::Value> = jsonl
+            .split(|&b| b == b'\n')
+            .filter(|l| !l.is_empty())
+            .map(|l| serde_json::from_slice(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["custom_id"], "a");
+        assert_eq!(lines[0]["method"], "POST");
+        assert_eq!(lines[0]["url"], "/v1/embeddings");
+        assert_eq!(lines[1]["body"]["input"], "world");
+    }
+
+    #[test]
+    fn test_batch_input_rejects_duplicate_and_empty_ids() {
+        let mut input = BatchInput::new();
+        let request = ChatCompletionRequest::builder()
+            .model("gpt-4o-batch")
+            .message(Message::user("hi"))
+            .build();
+        input.push("x", &request).unwrap();
+
+        let err = input.push("x", &request).unwrap_err();
+        assert!(err.to_string().contains("duplicate custom_id"));
+        let err = input.push(" ", &request).unwrap_err();
+        assert!(err.to_string().contains("custom_id cannot be empty"));
+        assert_eq!(input.endpoint(), BatchEndpoint::ChatCompletions);
+    }
+
+    #[test]
+    fn test_parse_results_success_and_errors() {
+        let jsonl = concat!(
+            r#"{"id":"r1","custom_id":"a","response":{"status_code":200,"body":{"object":"list","model":"m","data":[{"object":"embedding","index":0,"embedding":[0.5]}],"usage":{"prompt_tokens":1,"total_tokens":1}}},"error":null}"#,
+            "\n",
+            r#"{"id":"r2","custom_id":"b","response":{"status_code":400,"body":{"error":{"code":"invalid_input","message":"too long"}}},"error":null}"#,
+            "\n",
+            r#"{"id":"r3","custom_id":"c","response":null,"error":{"code":"expired","message":"not processed"}}"#,
+            "\n\n"
+        );
+
+        let items = parse_results::<EmbeddingResponse>(jsonl.as_bytes()).unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(
+            items[0].result.as_ref().unwrap().data[0].embedding,
+            vec![0.5]
+        );
+
+        let err = items[1].result.as_ref().unwrap_err();
+        assert_eq!(err.status_code, Some(400));
+        assert_eq!(err.code.as_deref(), Some("invalid_input"));
+        assert_eq!(err.message, "too long");
+
+        let err = items[2].result.as_ref().unwrap_err();
+        assert_eq!(err.status_code, None);
+        assert_eq!(err.code.as_deref(), Some("expired"));
+
+        let results = BatchResults::new(items);
+        assert!(results.get("b").unwrap().result.is_err());
+        assert_eq!(results.succeeded().count(), 1);
+        assert_eq!(results.failed().count(), 2);
+    }
+
+    #[test]
+    fn test_parse_results_keeps_other_items_when_a_body_does_not_match() {
+        let jsonl = concat!(
+            r#"{"custom_id":"a","response":{"status_code":200,"body":{"unexpected":true}}}"#,
+            "\n",
+            r#"{"custom_id":"b","response":{"status_code":200,"body":{"object":"list","model":"m","data":[{"object":"embedding","index":0,"embedding":[0.5]}],"usage":{"prompt_tokens":1,"total_tokens":1}}}}"#,
+        );
+
+        let items = parse_results::<EmbeddingResponse>(jsonl.as_bytes()).unwrap();
+        assert_eq!(items.len(), 2);
+        let err = items[0].result.as_ref().unwrap_err();
+        assert_eq!(err.status_code, Some(200));
+        assert_eq!(err.code.as_deref(), Some("invalid_response"));
+        assert!(items[1].result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_results_reports_line_number() {
+        let err =
+            parse_results::<EmbeddingResponse>(b"{\"custom_id\":\"a\"}\nnot json\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+    }
+
+    #[test]
+    fn test_batch_status_terminal() {
+        assert!(BatchStatus::Completed.is_terminal());
+        assert!(BatchStatus::Cancelled.is_terminal());
+        assert!(!BatchStatus::Finalizing.is_terminal());
+        let status: BatchStatus = serde_json::from_value(serde_json::json!("paused")).unwrap();
+        assert_eq!(status, BatchStatus::Unknown);
+    }
+
+    #[tokio::test]
+    async fn test_submit_uploads_and_creates() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/files"))
+            .and(body_string_contains("batch_input.jsonl"))
+            .and(body_string_contains("\"custom_id\":\"a\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "file-in", "object": "file", "bytes": 120,
+                "created_at": TEST_TIMESTAMP, "filename": "batch_input.jsonl",
+                "purpose": "batch", "status": "processed"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/batches"))
+            .and(body_json(serde_json::json!({
+                "input_file_id": "file-in",
+                "endpoint": "/v1/embeddings",
+                "completion_window": "24h"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(batch_json("validating")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let mut input = BatchInput::new();
+        input.push("a", &embedding_request("hello")).unwrap();
+
+        let batch = submit(&client, &input).await.expect("should succeed");
+        assert_eq!(batch.id, "batch_abc");
+        assert_eq!(batch.status, BatchStatus::Validating);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_and_list() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/batches/batch_abc/cancel"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(batch_json("cancelling")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/batches"))
+            .and(query_param("after", "batch_prev"))
+            .and(query_param("limit", "10"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "data": [batch_json("completed")],
+                "first_id": "batch_abc",
+                "last_id": "batch_abc",
+                "has_more": false
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+
+        let batch = cancel(&client, "batch_abc").await.expect("should cancel");
+        assert_eq!(batch.status, BatchStatus::Cancelling);
+
+        let page = list(&client, Some("batch_prev"), Some(10))
+            .await
+            .expect("should list");
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.last_id.as_deref(), Some("batch_abc"));
+        assert!(!page.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_complete_returns_terminal_batch() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/batches/batch_abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(batch_json("in_progress")))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/batches/batch_abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(batch_json("completed")))
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let batch = poll_until_complete(
+            &client,
+            "batch_abc",
+            std::time::Duration::from_millis(1),
+            Some(5),
+        )
+        .await
+        .expect("should complete");
+        assert_eq!(batch.status, BatchStatus::Completed);
+        assert_eq!(batch.request_counts.unwrap().failed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_complete_stops_on_unknown_status() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/batches/batch_abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(batch_json("paused")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let batch = poll_until_complete(
+            &client,
+            "batch_abc",
+            std::time::Duration::from_millis(1),
+            None,
+        )
+        .await
+        .expect("should stop");
+        assert_eq!(batch.status, BatchStatus::Unknown);
+    }
+
+    #[tokio::test]
+    async fn test_download_results_merges_output_and_error_files() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/files/file-out/content"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(concat!(
+                r#"{"custom_id":"a","response":{"status_code":200,"body":{"object":"list","model":"m","data":[{"object":"embedding","index":0,"embedding":[1.0]}],"usage":{"prompt_tokens":1,"total_tokens":1}}}}"#,
+                "\n"
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/files/file-err/content"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"custom_id":"b","response":{"status_code":429,"body":{"error":{"message":"rate limited"}}}}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let batch: Batch = serde_json::from_value(batch_json("completed")).unwrap();
+
+        let results = download_results::<EmbeddingRequest>(&client, &batch)
+            .await
+            .expect("should download");
+        assert_eq!(results.len(), 2);
+        assert!(results.get("a").unwrap().result.is_ok());
+        let (id, err) = results.failed().next().unwrap();
+        assert_eq!(id, "b");
+        assert_eq!(err.status_code, Some(429));
+
+        let err = download_results::<ChatCompletionRequest>(&client, &batch)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+    }
+}
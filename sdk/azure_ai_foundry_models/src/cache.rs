@@ -0,0 +1,697 @@
+//! Response caching for deterministic model calls.
+//!
+//! [`CachedModel`] wraps any [`ChatModel`] or [`EmbeddingModel`] and stores
+//! responses in a [`CacheStore`], keyed on a canonical hash of the serialized
+//! request (see [`cache_key`]). Two backends are provided: [`MemoryCache`],
+//! an in-memory LRU with optional TTL, and [`FileCache`], one JSON file per
+//! entry in a directory.
+//!
+//! Embedding requests are always cached. Chat requests are cached only when
+//! they set `temperature` to `0`, unless
+//! [`cache_sampled`](CachedModel::cache_sampled) is enabled. Streamed chat
+//! responses are recorded chunk by chunk and replayed on a hit.
+//!
+//! Cache failures never fail a call: unreadable or corrupt entries count as
+//! misses and failed writes are logged.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use std::sync::Arc;
+//! # use std::time::Duration;
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! use azure_ai_foundry_models::cache::{CachedModel, MemoryCache};
+//! use azure_ai_foundry_models::embeddings::EmbeddingRequest;
+//! use azure_ai_foundry_models::model::{EmbeddingModel, FoundryModel};
+//!
+//! # async fn example(client: FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let store = Arc::new(MemoryCache::new(10_000).with_ttl(Duration::from_secs(3600)));
+//! let model = CachedModel::new(FoundryModel::new(client, "text-embedding-3-small"), store);
+//!
+//! let request = EmbeddingRequest::builder()
+//!     .model("text-embedding-3-small")
+//!     .input("Hello, world!")
+//!     .build();
+//! let first = model.embed(&request).await?; // sent to the service
+//! let second = model.embed(&request).await?; // served from the cache
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tokio::time::Instant;
+
+use crate::chat::{ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse};
+use crate::embeddings::{EmbeddingRequest, EmbeddingResponse};
+use crate::model::{ChatModel, ChatStream, EmbeddingModel};
+
+// ---------------------------------------------------------------------------
+// Keys and stores
+// ---------------------------------------------------------------------------
+
+/// Compute the cache key of a request.
+///
+/// The request is serialized to JSON with object keys sorted, so the key does
+/// not depend on field order, then hashed together with `kind` (which keeps
+/// e.g. streamed and non-streamed responses apart). The result is a
+/// 64-character lowercase hex SHA-256 digest.
+pub fn cache_key(kind: &str, request: &impl Serialize) -> FoundryResult<String> {
+    let canonical = serde_json::to_vec(&serde_json::to_value(request)?)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(kind.as_bytes());
+    hasher.update([0]);
+    hasher.update(&canonical);
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// A backend that stores serialized responses by key.
+#[async_trait]
+pub trait CacheStore: Send + Sync {
+    /// Return the value stored under `key`, if any.
+    async fn get(&self, key: &str) -> FoundryResult<Option<Vec<u8>>>;
+
+    /// Store `value` under `key`, replacing any previous value.
+    async fn put(&self, key: &str, value: Vec<u8>) -> FoundryResult<()>;
+}
+
+/// Lock a mutex, ignoring poisoning from a panicked thread.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// An in-memory least-recently-used cache with optional expiry.
+///
+/// Holds at most `capacity` entries; storing one more evicts the entry that
+/// was read or written longest ago. A capacity of zero stores nothing.
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    ttl: Option<Duration>,
+    state: Mutex<LruState>,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    entries: HashMap<String, MemoryEntry>,
+    /// Keys by last use, oldest first.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+#[derive(Debug)]
+struct MemoryEntry {
+    value: Vec<u8>,
+    stored: Instant,
+    used: u64,
+}
+
+impl LruState {
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.used);
+        }
+    }
+}
+
+impl MemoryCache {
+    /// Create a cache holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ttl: None,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Expire entries `ttl` after they were stored.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Number of entries currently held, including expired ones not yet evicted.
+    pub fn len(&self) -> usize {
+        lock(&self.state).entries.len()
+    }
+
+    /// Returns `true` if the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove every entry.
+    pub fn clear(&self) {
+        let mut state = lock(&self.state);
+        state.entries.clear();
+        state.order.clear();
+    }
+}
+
+#[async_trait]
+impl CacheStore for MemoryCache {
+    async fn get(&self, key: &str) -> FoundryResult<Option<Vec<u8>>> {
+        let mut guard = lock(&self.state);
+        let state = &mut *guard;
+        let Some(entry) = state.entries.get_mut(key) else {
+            return Ok(None);
+        };
+
+        if self.ttl.is_some_and(|ttl| entry.stored.elapsed() >= ttl) {
+            state.remove(key);
+            return Ok(None);
+        }
+
+        state.tick += 1;
+        state.order.remove(&entry.used);
+        state.order.insert(state.tick, key.to_string());
+        entry.used = state.tick;
+        Ok(Some(entry.value.clone()))
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> FoundryResult<()> {
+        if self.capacity == 0 {
+            return Ok(());
+        }
+
+        let mut state = lock(&self.state);
+        state.remove(key);
+        state.tick += 1;
+        let used = state.tick;
+        state.order.insert(used, key.to_string());
+        state.entries.insert(
+            key.to_string(),
+            MemoryEntry {
+                value,
+                stored: Instant::now(),
+                used,
+            },
+        );
+
+        while state.entries.len() > self.capacity {
+            let Some((_, oldest)) = state.order.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+        Ok(())
+    }
+}
+
+/// A cache that stores each entry as a JSON file in a directory.
+///
+/// The directory is created on first write. Entries survive restarts and can
+/// be shared between processes; writes go through a temporary file so
+/// readers never see a partial entry. With a TTL, entries older than it
+/// (by file modification time) are deleted when read.
+#[derive(Debug, Clone)]
+pub struct FileCache {
+    dir: PathBuf,
+    ttl: Option<Duration>,
+}
+
+impl FileCache {
+    /// Create a cache in `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl: None,
+        }
+    }
+
+    /// Expire entries `ttl` after they were written.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// The directory entries are stored in.
+    pub fn dir(&self) -> &std::path::Path {
+        &self.dir
+    }
+
+    fn path(&self, key: &str) -> FoundryResult<PathBuf> {
+        if key.is_empty()
+            || !key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(FoundryError::validation_field(
+                "key",
+                format!("cache key {:?} is not a valid file name", key),
+            ));
+        }
+        Ok(self.dir.join(format!("{}.json", key)))
+    }
+}
+
+#[async_trait]
+impl CacheStore for FileCache {
+    async fn get(&self, key: &str) -> FoundryResult<Option<Vec<u8>>> {
+        let path = self.path(key)?;
+        let value = match tokio::fs::read(&path).await {
+            Ok(value) => value,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        if let Some(ttl) = self.ttl {
+            let age = tokio::fs::metadata(&path)
+                .await?
+                .modified()?
+                .elapsed()
+                .unwrap_or_default();
+            if age >= ttl {
+                let _ = tokio::fs::remove_file(&path).await;
+                return Ok(None);
+            }
+        }
+        Ok(Some(value))
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> FoundryResult<()> {
+        static WRITES: AtomicU64 = AtomicU64::new(0);
+
+        let path = self.path(key)?;
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let temp = self.dir.join(format!(
+            "{}.{}-{}.tmp",
+            key,
+            std::process::id(),
+            WRITES.fetch_add(1, Ordering::Relaxed)
+        ));
+        tokio::fs::write(&temp, value).await?;
+        tokio::fs::rename(&temp, &path).await?;
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Caching model
+// ---------------------------------------------------------------------------
+
+/// A model wrapper that serves repeated requests from a [`CacheStore`].
+///
+/// Keys include the request's `model` field. When several wrapped models
+/// share a store but ignore that field (like
+/// [`FoundryModel`](crate::model::FoundryModel), which substitutes its
+/// deployment), give each its own [`namespace`](Self::namespace).
+///
+/// # Tracing
+///
+/// Cached calls emit a span named `foundry::cache::complete`,
+/// `foundry::cache::complete_stream` or `foundry::cache::embed` with fields
+/// `key` and `hit`.
+#[derive(Clone)]
+pub struct CachedModel<M> {
+    inner: M,
+    store: Arc<dyn CacheStore>,
+    namespace: String,
+    cache_sampled: bool,
+}
+
+impl<M: std::fmt::Debug> std::fmt::Debug for CachedModel<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedModel")
+            .field("inner", &self.inner)
+            .field("namespace", &self.namespace)
+            .field("cache_sampled", &self.cache_sampled)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M> CachedModel<M> {
+    /// Wrap `inner`, caching its responses in `store`.
+    pub fn new(inner: M, store: Arc<dyn CacheStore>) -> Self {
+        Self {
+            inner,
+            store,
+            namespace: String::new(),
+            cache_sampled: false,
+        }
+    }
+
+    /// Prefix every key with `namespace`.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    /// Also cache chat requests that do not set `temperature` to `0`.
+    ///
+    /// A hit then always returns the first sampled answer.
+    pub fn cache_sampled(mut self, enabled: bool) -> Self {
+        self.cache_sampled = enabled;
+        self
+    }
+
+    /// The wrapped model.
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    fn key(&self, kind: &str, request: &impl Serialize) -> FoundryResult<String> {
+        cache_key(&format!("{}/{}", self.namespace, kind), request)
+    }
+
+    fn is_cacheable(&self, request: &ChatCompletionRequest) -> bool {
+        self.cache_sampled || request.temperature == Some(0.0)
+    }
+
+    /// Look up `key`, recording the outcome on the current span.
+    async fn lookup<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let span = tracing::Span::current();
+        span.record("key", key);
+
+        let cached = match self.store.get(key).await {
+            Ok(cached) => cached,
+            Err(e) => {
+                tracing::warn!(error = %e, "cache read failed");
+                None
+            }
+        };
+        let value = cached.and_then(|bytes| match serde_json::from_slice(&bytes) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                tracing::warn!(error = %e, "ignoring unreadable cache entry");
+                None
+            }
+        });
+
+        span.record("hit", value.is_some());
+        tracing::debug!("cache lookup");
+        value
+    }
+}
+
+/// Store `value` under `key`, logging any failure.
+async fn save(store: &dyn CacheStore, key: &str, value: &impl Serialize) {
+    let result = match serde_json::to_vec(value) {
+        Ok(bytes) => store.put(key, bytes).await,
+        Err(e) => Err(e.into()),
+    };
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "cache write failed");
+    }
+}
+
+/// Pass `inner` through, storing its chunks under `key` once it ends cleanly.
+fn record_stream(inner: ChatStream, store: Arc<dyn CacheStore>, key: String) -> ChatStream {
+    let state = (inner, Some(Vec::new()), store, key);
+    futures::stream::unfold(state, |(mut inner, mut chunks, store, key)| async move {
+        match inner.next().await {
+            Some(Ok(chunk)) => {
+                if let Some(chunks) = chunks.as_mut() {
+                    chunks.push(chunk.clone());
+                }
+                Some((Ok(chunk), (inner, chunks, store, key)))
+            }
+            Some(Err(e)) => Some((Err(e), (inner, None, store, key))),
+            None => {
+                if let Some(chunks) = chunks {
+                    save(&*store, &key, &chunks).await;
+                }
+                None
+            }
+        }
+    })
+    .boxed()
+}
+
+#[async_trait]
+impl<M: ChatModel> ChatModel for CachedModel<M> {
+    #[tracing::instrument(
+        name = "foundry::cache::complete",
+        skip_all,
+        fields(model = %request.model, key, hit)
+    )]
+    async fn complete(
+        &self,
+        request: &ChatCompletionRequest,
+    ) -> FoundryResult<ChatCompletionResponse> {
+        if !self.is_cacheable(request) {
+            return self.inner.complete(request).await;
+        }
+
+        let key = self.key("chat", request)?;
+        if let Some(response) = self.lookup(&key).await {
+            return Ok(response);
+        }
+
+        let response = self.inner.complete(request).await?;
+        save(&*self.store, &key, &response).await;
+        Ok(response)
+    }
+
+    #[tracing::instrument(
+        name = "foundry::cache::complete_stream",
+        skip_all,
+        fields(model = %request.model, key, hit)
+    )]
+    async fn complete_stream(&self, request: &ChatCompletionRequest) -> FoundryResult<ChatStream> {
+        if !self.is_cacheable(request) {
+            return self.inner.complete_stream(request).await;
+        }
+
+        let key = self.key("chat_stream", request)?;
+        if let Some(chunks) = self.lookup::<Vec<ChatCompletionChunk>>(&key).await {
+            return Ok(futures::stream::iter(chunks.into_iter().map(Ok)).boxed());
+        }
+
+        let stream = self.inner.complete_stream(request).await?;
+        Ok(record_stream(stream, Arc::clone(&self.store), key))
+    }
+}
+
+#[async_trait]
+impl<M: EmbeddingModel> EmbeddingModel for CachedModel<M> {
+    #[tracing::instrument(
+        name = "foundry::cache::embed",
+        skip_all,
+        fields(model = %request.model, key, hit)
+    )]
+    async fn embed(&self, request: &EmbeddingRequest) -> FoundryResult<EmbeddingResponse> {
+        let key = self.key("embeddings", request)?;
+        if let Some(response) = self.lookup(&key).await {
+            return Ok(response);
+        }
+
+        let response = self.inner.embed(request).await?;
+        save(&*self.store, &key, &response).await;
+        Ok(response)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat::Message;
+    use crate::model::fake::{FakeChatModel, FakeEmbeddingModel};
+    use futures::TryStreamExt;
+    use tracing_test::traced_test;
+
+    fn chat_request(temperature: Option<f32>) -> ChatCompletionRequest {
+        let builder = ChatCompletionRequest::builder()
+            .model("gpt-4o")
+            .message(Message::user("What is 2 + 2?"));
+        match temperature {
+            Some(t) => builder.temperature(t).build(),
+            None => builder.build(),
+        }
+    }
+
+    fn content(response: &ChatCompletionResponse) -> &str {
+        response.choices[0].message.content.as_deref().unwrap()
+    }
+
+    #[test]
+    fn test_cache_key_is_stable_and_distinguishes_requests() {
+        let a = cache_key("chat", &chat_request(Some(0.0))).unwrap();
+        assert_eq!(a.len(), 64);
+        assert_eq!(a, cache_key("chat", &chat_request(Some(0.0))).unwrap());
+        assert_ne!(a, cache_key("chat", &chat_request(Some(0.5))).unwrap());
+        assert_ne!(
+            a,
+            cache_key("chat_stream", &chat_request(Some(0.0))).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cache_key_ignores_map_order() {
+        let a = serde_json::json!({"a": 1, "b": {"x": 1, "y": 2}});
+        let b: serde_json::Value =
+            serde_json::from_str(r#"{"b": {"y": 2, "x": 1}, "a": 1}"#).unwrap();
+        assert_eq!(cache_key("k", &a).unwrap(), cache_key("k", &b).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_evicts_least_recently_used() {
+        let cache = MemoryCache::new(2);
+        cache.put("a", b"1".to_vec()).await.unwrap();
+        cache.put("b", b"2".to_vec()).await.unwrap();
+        assert!(cache.get("a").await.unwrap().is_some());
+
+        cache.put("c", b"3".to_vec()).await.unwrap();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("a").await.unwrap(), Some(b"1".to_vec()));
+        assert_eq!(cache.get("b").await.unwrap(), None);
+        assert_eq!(cache.get("c").await.unwrap(), Some(b"3".to_vec()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_memory_cache_expires_entries() {
+        let cache = MemoryCache::new(10).with_ttl(Duration::from_secs(60));
+        cache.put("a", b"1".to_vec()).await.unwrap();
+
+        tokio::time::advance(Duration::from_secs(59)).await;
+        assert!(cache.get("a").await.unwrap().is_some());
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert!(cache.get("a").await.unwrap().is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_file_cache_round_trip() {
+        let dir = std::env::temp_dir().join(format!("foundry-cache-{}", std::process::id()));
+        let cache = FileCache::new(&dir);
+
+        assert_eq!(cache.get("abc123").await.unwrap(), None);
+        cache.put("abc123", b"{}".to_vec()).await.unwrap();
+        assert_eq!(cache.get("abc123").await.unwrap(), Some(b"{}".to_vec()));
+        assert!(cache.get("../escape").await.is_err());
+
+        let expired = FileCache::new(&dir).with_ttl(Duration::ZERO);
+        assert_eq!(expired.get("abc123").await.unwrap(), None);
+        assert!(!dir.join("abc123.json").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cached_model_caches_deterministic_chat_only() {
+        let model = CachedModel::new(FakeChatModel::new(), Arc::new(MemoryCache::new(10)));
+
+        let first = model.complete(&chat_request(Some(0.0))).await.unwrap();
+        let second = model.complete(&chat_request(Some(0.0))).await.unwrap();
+        assert_eq!(first.id, second.id);
+        assert_eq!(model.inner().call_count(), 1);
+
+        model.complete(&chat_request(None)).await.unwrap();
+        model.complete(&chat_request(None)).await.unwrap();
+        assert_eq!(model.inner().call_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_cached_model_does_not_cache_errors() {
+        let model = CachedModel::new(
+            FakeChatModel::new().fail(503, "busy").reply("4"),
+            Arc::new(MemoryCache::new(10)),
+        );
+
+        assert!(model.complete(&chat_request(Some(0.0))).await.is_err());
+        let response = model.complete(&chat_request(Some(0.0))).await.unwrap();
+        assert_eq!(content(&response), "4");
+        let response = model.complete(&chat_request(Some(0.0))).await.unwrap();
+        assert_eq!(content(&response), "4");
+        assert_eq!(model.inner().call_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cached_model_replays_stream() {
+        let model = CachedModel::new(
+            FakeChatModel::new().reply("two plus two is four"),
+            Arc::new(MemoryCache::new(10)),
+        );
+
+        let collect = |stream: ChatStream| stream.try_collect::<Vec<ChatCompletionChunk>>();
+        let live = collect(
+            model
+                .complete_stream(&chat_request(Some(0.0)))
+                .await
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+        let replayed = collect(
+            model
+                .complete_stream(&chat_request(Some(0.0)))
+                .await
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(model.inner().call_count(), 1);
+        assert_eq!(live.len(), replayed.len());
+        let text: String = replayed
+            .iter()
+            .filter_map(|c| c.choices[0].delta.content.as_deref())
+            .collect();
+        assert_eq!(text, "two plus two is four");
+    }
+
+    #[tokio::test]
+    async fn test_cached_model_shares_file_cache_for_embeddings() {
+        let dir = std::env::temp_dir().join(format!("foundry-cache-embed-{}", std::process::id()));
+        let request = EmbeddingRequest::builder()
+            .model("text-embedding-3-small")
+            .inputs(["alpha", "beta"])
+            .build();
+
+        let first = CachedModel::new(FakeEmbeddingModel::new(4), Arc::new(FileCache::new(&dir)));
+        let expected = first.embed(&request).await.unwrap();
+
+        let second = CachedModel::new(FakeEmbeddingModel::new(4), Arc::new(FileCache::new(&dir)));
+        let cached = second.embed(&request).await.unwrap();
+
+        assert_eq!(second.inner().call_count(), 0);
+        assert_eq!(cached.data[1].embedding, expected.data[1].embedding);
+        assert_eq!(cached.usage.prompt_tokens, expected.usage.prompt_tokens);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_namespaces_keep_entries_apart() {
+        let store: Arc<dyn CacheStore> = Arc::new(MemoryCache::new(10));
+        let a =
+            CachedModel::new(FakeChatModel::new().reply("a"), Arc::clone(&store)).namespace("a");
+        let b = CachedModel::new(FakeChatModel::new().reply("b"), store).namespace("b");
+
+        a.complete(&chat_request(Some(0.0))).await.unwrap();
+        let response = b.complete(&chat_request(Some(0.0))).await.unwrap();
+        assert_eq!(content(&response), "b");
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_cached_model_records_hits_in_span() {
+        let model = CachedModel::new(FakeChatModel::new(), Arc::new(MemoryCache::new(10)));
+        model.complete(&chat_request(Some(0.0))).await.unwrap();
+        model.complete(&chat_request(Some(0.0))).await.unwrap();
+
+        assert!(logs_contain("foundry::cache::complete"));
+        assert!(logs_contain("hit=false"));
+        assert!(logs_contain("hit=true"));
+    }
+}
@@ -30,8 +30,19 @@
 use azure_ai_foundry_core::client::FoundryClient;
 use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
 use azure_ai_foundry_core::models::Usage;
-use futures::stream::{self, Stream};
+use azure_ai_foundry_core::sse::{self, SseEvent};
+use futures::future;
+use futures::stream::{self, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::time::Duration;
+
+use self::data_sources::{DataSource, MessageContext};
+
+pub mod data_sources;
+pub mod prompt;
+pub mod session;
+pub mod training;
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -39,10 +50,9 @@ use serde::{Deserialize, Serialize};
 
 /// Maximum size of the SSE line buffer in bytes (1MB).
 ///
-/// This limit prevents denial-of-service attacks where a malicious server
-/// could send an extremely long line without newlines, causing unbounded
-/// memory consumption.
-pub const SSE_BUFFER_LIMIT: usize = 1_048_576;
+/// Re-exported from [`azure_ai_foundry_core::sse`], which enforces it for
+/// every streaming API.
+pub use azure_ai_foundry_core::sse::SSE_BUFFER_LIMIT;
 
 // ---------------------------------------------------------------------------
 // Request types
@@ -74,6 +84,21 @@ pub struct ChatCompletionRequest {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub frequency_penalty: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_options: Option<StreamOptions>,
+
+    /// Azure "On Your Data" sources to ground the answer in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_sources: Option<Vec<DataSource>>,
+}
+
+/// Options that only apply to streaming requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamOptions {
+    /// When `true`, the service sends a final chunk with an empty `choices`
+    /// list and the token [`Usage`] for the whole request.
+    pub include_usage: bool,
 }
 
 /// Builder for [`ChatCompletionRequest`].
@@ -87,6 +112,8 @@ pub struct ChatCompletionRequestBuilder {
     stop: Option<Vec<String>>,
     presence_penalty: Option<f32>,
     frequency_penalty: Option<f32>,
+    stream_options: Option<StreamOptions>,
+    data_sources: Option<Vec<DataSource>>,
 }
 
 impl ChatCompletionRequest {
@@ -101,6 +128,8 @@ impl ChatCompletionRequest {
             stop: None,
             presence_penalty: None,
             frequency_penalty: None,
+            stream_options: None,
+            data_sources: None,
         }
     }
 }
@@ -182,6 +211,25 @@ impl ChatCompletionRequestBuilder {
         self
     }
 
+    /// Ask the service to append a usage chunk at the end of a stream.
+    ///
+    /// Only affects [`complete_stream`]; ignored by [`complete`].
+    pub fn stream_include_usage(mut self, include: bool) -> Self {
+        self.stream_options = Some(StreamOptions {
+            include_usage: include,
+        });
+        self
+    }
+
+    /// Add an Azure "On Your Data" source to ground the answer in.
+    ///
+    /// The answer's [`Message::context`] then carries the cited documents.
+    /// See [`data_sources`] for the supported sources.
+    pub fn data_source(mut self, source: DataSource) -> Self {
+        self.data_sources.get_or_insert_with(Vec::new).push(source);
+        self
+    }
+
     /// Build the request, returning an error if required fields are missing
     /// or parameter values are out of range.
     pub fn try_build(self) -> FoundryResult<ChatCompletionRequest> {
@@ -246,6 +294,8 @@ impl ChatCompletionRequestBuilder {
             stop: self.stop,
             presence_penalty: self.presence_penalty,
             frequency_penalty: self.frequency_penalty,
+            stream_options: self.stream_options,
+            data_sources: self.data_sources,
         })
     }
 
@@ -266,34 +316,89 @@ impl ChatCompletionRequestBuilder {
 pub struct Message {
     pub role: Role,
     pub content: Option<String>,
+
+    /// Tool calls requested by the assistant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ToolCall>>,
+
+    /// ID of the tool call this message answers (tool messages only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+
+    /// Citations and search intent of an answer grounded in
+    /// [`data_sources`](ChatCompletionRequest::data_sources).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<MessageContext>,
 }
 
 impl Message {
-    /// Create a system message.
-    pub fn system(content: impl Into<String>) -> Self {
+    fn with_role(role: Role, content: impl Into<String>) -> Self {
         Self {
-            role: Role::System,
+            role,
             content: Some(content.into()),
+            tool_calls: None,
+            tool_call_id: None,
+            context: None,
         }
     }
 
+    /// Create a system message.
+    pub fn system(content: impl Into<String>) -> Self {
+        Self::with_role(Role::System, content)
+    }
+
     /// Create a user message.
     pub fn user(content: impl Into<String>) -> Self {
-        Self {
-            role: Role::User,
-            content: Some(content.into()),
-        }
+        Self::with_role(Role::User, content)
     }
 
     /// Create an assistant message.
     pub fn assistant(content: impl Into<String>) -> Self {
+        Self::with_role(Role::Assistant, content)
+    }
+
+    /// Create a tool message carrying the result of a tool call.
+    pub fn tool(tool_call_id: impl Into<String>, content: impl Into<String>) -> Self {
         Self {
-            role: Role::Assistant,
-            content: Some(content.into()),
+            tool_call_id: Some(tool_call_id.into()),
+            ..Self::with_role(Role::Tool, content)
         }
     }
 }
 
+/// The type of a tool call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolCallType {
+    /// A user-defined function.
+    #[default]
+    Function,
+}
+
+/// A tool call requested by the assistant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCall {
+    /// The ID of the tool call.
+    pub id: String,
+
+    /// The type of tool call.
+    #[serde(rename = "type")]
+    pub call_type: ToolCallType,
+
+    /// The function that was called.
+    pub function: FunctionCall,
+}
+
+/// A function call within a tool call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FunctionCall {
+    /// The name of the function.
+    pub name: String,
+
+    /// The arguments passed to the function (JSON string).
+    pub arguments: String,
+}
+
 /// The role of a message in a conversation.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
@@ -309,7 +414,7 @@ pub enum Role {
 // ---------------------------------------------------------------------------
 
 /// A chat completion response.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatCompletionResponse {
     /// Unique identifier for this completion.
     pub id: String,
@@ -331,7 +436,7 @@ pub struct ChatCompletionResponse {
 }
 
 /// A single choice in a chat completion response.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Choice {
     /// Index of this choice.
     pub index: u32,
@@ -352,7 +457,7 @@ pub struct Choice {
 /// This represents a single Server-Sent Event (SSE) from the streaming API.
 /// Each chunk contains partial content that should be concatenated to form
 /// the complete response.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatCompletionChunk {
     /// Unique identifier for this completion.
     pub id: String,
@@ -374,7 +479,7 @@ pub struct ChatCompletionChunk {
 }
 
 /// A single choice in a streaming chunk.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChunkChoice {
     /// Index of this choice.
     pub index: u32,
@@ -390,13 +495,51 @@ pub struct ChunkChoice {
 ///
 /// Contains the incremental content added in this chunk.
 /// The first chunk typically contains the role, subsequent chunks contain content.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Delta {
     /// Role of the assistant (only in first chunk).
     pub role: Option<Role>,
 
     /// Incremental content to append.
     pub content: Option<String>,
+
+    /// Incremental tool call fragments, keyed by their `index`.
+    pub tool_calls: Option<Vec<ToolCallDelta>>,
+
+    /// Citations and search intent of a grounded answer (usually only in
+    /// the first chunk).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<MessageContext>,
+}
+
+/// A fragment of a tool call in a streaming chunk.
+///
+/// The first fragment for a given `index` carries the `id`, type and function
+/// name; later fragments only carry pieces of the `arguments` string.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallDelta {
+    /// Position of this tool call in the message's `tool_calls` list.
+    pub index: u32,
+
+    /// The ID of the tool call (only in the first fragment).
+    pub id: Option<String>,
+
+    /// The type of tool call (only in the first fragment).
+    #[serde(rename = "type")]
+    pub call_type: Option<ToolCallType>,
+
+    /// The function name and argument fragments.
+    pub function: Option<FunctionCallDelta>,
+}
+
+/// A fragment of a function call in a streaming chunk.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FunctionCallDelta {
+    /// The name of the function (only in the first fragment).
+    pub name: Option<String>,
+
+    /// A piece of the JSON arguments string to append.
+    pub arguments: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -489,6 +632,15 @@ pub async fn complete(
 /// Returns a stream of [`ChatCompletionChunk`]s that can be consumed
 /// as they arrive from the server.
 ///
+/// If the connection drops or stays silent for longer than the client's
+/// [`stream_idle_timeout`](azure_ai_foundry_core::client::FoundryClientBuilder::stream_idle_timeout),
+/// the stream ends with [`FoundryError::StreamInterrupted`], whose
+/// `partial_content` holds the text received so far for each choice.
+/// When the server sent SSE event IDs and
+/// [`max_stream_resumes`](azure_ai_foundry_core::client::FoundryClientBuilder::max_stream_resumes)
+/// allows it, the request is re-sent with `Last-Event-ID` and the stream
+/// continues transparently instead.
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -567,26 +719,149 @@ pub async fn complete_stream(
 ) -> FoundryResult<impl Stream<Item = FoundryResult<ChatCompletionChunk>>> {
     tracing::debug!("initiating streaming chat completion");
 
-    // Create a modified request with stream: true
-    let stream_request = StreamingRequest {
-        model: &request.model,
-        messages: &request.messages,
-        temperature: request.temperature,
-        top_p: request.top_p,
-        max_tokens: request.max_tokens,
-        stream: true,
-        stop: request.stop.as_deref(),
-        presence_penalty: request.presence_penalty,
-        frequency_penalty: request.frequency_penalty,
-    };
-
     let response = client
-        .post_stream("/openai/v1/chat/completions", &stream_request)
+        .post_stream(CHAT_COMPLETIONS_PATH, &StreamingRequest::new(request))
         .await?;
 
     tracing::debug!("stream initiated");
 
-    Ok(parse_sse_stream(response))
+    let chunks = open_chunk_stream(response, client.stream_idle_timeout());
+    let resume = (client.max_stream_resumes() > 0).then(|| StreamResume {
+        client: client.clone(),
+        request: request.clone(),
+        remaining: client.max_stream_resumes(),
+    });
+
+    Ok(track_interruptions(chunks, resume))
+}
+
+/// API path for chat completions.
+const CHAT_COMPLETIONS_PATH: &str = "/openai/v1/chat/completions";
+
+/// A boxed stream of parsed chat completion chunks.
+pub(crate) type ChunkStream =
+    Pin<Box<dyn Stream<Item = FoundryResult<ChatCompletionChunk>> + Send>>;
+
+/// Decode a streaming response into chat completion chunks.
+pub(crate) fn open_chunk_stream(
+    response: reqwest::Response,
+    idle_timeout: Option<Duration>,
+) -> ChunkStream {
+    Box::pin(parse_sse_stream(response, parse_sse_event, idle_timeout))
+}
+
+/// What is needed to re-send a streaming request after an interruption.
+pub(crate) struct StreamResume {
+    client: FoundryClient,
+    request: ChatCompletionRequest,
+    remaining: u32,
+}
+
+impl StreamResume {
+    /// Re-send the request with `Last-Event-ID`, returning the continued stream.
+    async fn reconnect(&mut self, last_event_id: &str) -> FoundryResult<ChunkStream> {
+        self.remaining -= 1;
+        tracing::warn!(
+            last_event_id,
+            remaining = self.remaining,
+            "resuming interrupted chat stream"
+        );
+
+        let response = self
+            .client
+            .post_stream_with_headers(
+                CHAT_COMPLETIONS_PATH,
+                &StreamingRequest::new(&self.request),
+                &[("Last-Event-ID", last_event_id)],
+            )
+            .await?;
+
+        Ok(open_chunk_stream(
+            response,
+            self.client.stream_idle_timeout(),
+        ))
+    }
+}
+
+/// State threaded through [`track_interruptions`].
+struct InterruptionState {
+    inner: ChunkStream,
+    resume: Option<StreamResume>,
+    partial_content: Vec<String>,
+    finished: bool,
+}
+
+/// Record streamed content and handle [`FoundryError::StreamInterrupted`].
+///
+/// Interruptions are resumed transparently while the server provided an event
+/// ID and resume attempts remain. Otherwise, or when resuming fails, the
+/// interruption is yielded with the content received so far for each choice,
+/// and the stream ends.
+pub(crate) fn track_interruptions(
+    inner: ChunkStream,
+    resume: Option<StreamResume>,
+) -> impl Stream<Item = FoundryResult<ChatCompletionChunk>> {
+    let state = InterruptionState {
+        inner,
+        resume,
+        partial_content: Vec::new(),
+        finished: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        if state.finished {
+            return None;
+        }
+
+        loop {
+            match state.inner.next().await? {
+                Ok(chunk) => {
+                    for choice in &chunk.choices {
+                        let Some(content) = choice.delta.content.as_deref() else {
+                            continue;
+                        };
+                        let index = choice.index as usize;
+                        if state.partial_content.len() <= index {
+                            state.partial_content.resize(index + 1, String::new());
+                        }
+                        state.partial_content[index].push_str(content);
+                    }
+                    return Some((Ok(chunk), state));
+                }
+                Err(FoundryError::StreamInterrupted {
+                    mut message,
+                    last_event_id,
+                    mut source,
+                    ..
+                }) => {
+                    if let (Some(id), Some(resume)) = (&last_event_id, &mut state.resume) {
+                        if resume.remaining > 0 {
+                            match resume.reconnect(id).await {
+                                Ok(inner) => {
+                                    state.inner = inner;
+                                    continue;
+                                }
+                                Err(e) => {
+                                    message = format!("{}; resume failed: {}", message, e);
+                                    source = Some(Box::new(e));
+                                }
+                            }
+                        }
+                    }
+
+                    state.finished = true;
+                    let err = FoundryError::StreamInterrupted {
+                        message,
+                        partial_content: state.partial_content.clone(),
+                        last_event_id,
+                        source,
+                    };
+                    return Some((Err(err), state));
+                }
+                Err(e) => return Some((Err(e), state)),
+            }
+        }
+    })
 }
 
 /// Internal request type for streaming chat completions.
@@ -594,11 +869,11 @@ pub async fn complete_stream(
 /// This is a zero-copy variant of [`ChatCompletionRequest`] that:
 /// - Uses references to avoid cloning request data
 /// - Always sets `stream: true` for SSE responses
-/// - Is used internally by [`complete_stream`]
+/// - Is used internally by [`complete_stream`] and [`crate::inference::complete_stream`]
 ///
 /// Users should construct [`ChatCompletionRequest`] instead of this type directly.
 #[derive(Serialize)]
-struct StreamingRequest<'a> {
+pub(crate) struct StreamingRequest<'a> {
     /// Model ID for the completion.
     model: &'a str,
     /// Conversation messages.
@@ -623,125 +898,306 @@ struct StreamingRequest<'a> {
     /// Frequency penalty (-2.0 to 2.0).
     #[serde(skip_serializing_if = "Option::is_none")]
     frequency_penalty: Option<f32>,
+    /// Streaming options (e.g. trailing usage chunk).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_options: Option<StreamOptions>,
+    /// Azure "On Your Data" sources.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_sources: Option<&'a [DataSource]>,
 }
 
-/// Parse Server-Sent Events (SSE) stream into ChatCompletionChunks.
-///
-/// Optimized for performance:
-/// - Uses `memchr` for fast newline detection
-/// - Works with `Vec<u8>` to minimize UTF-8 validation overhead
-/// - Minimizes allocations by draining processed bytes
+impl<'a> StreamingRequest<'a> {
+    /// Borrow `request` with `stream: true`.
+    pub(crate) fn new(request: &'a ChatCompletionRequest) -> Self {
+        Self {
+            model: &request.model,
+            messages: &request.messages,
+            temperature: request.temperature,
+            top_p: request.top_p,
+            max_tokens: request.max_tokens,
+            stream: true,
+            stop: request.stop.as_deref(),
+            presence_penalty: request.presence_penalty,
+            frequency_penalty: request.frequency_penalty,
+            stream_options: request.stream_options,
+            data_sources: request.data_sources.as_deref(),
+        }
+    }
+}
+
+/// Parse a Server-Sent Events (SSE) response, turning events into items with `parse_event`.
 ///
-/// Security:
-/// - Enforces [`SSE_BUFFER_LIMIT`] to prevent DoS via unbounded memory consumption
-fn parse_sse_stream(
+/// Decoding is delegated to [`azure_ai_foundry_core::sse::decode_with_idle_timeout`],
+/// which enforces [`SSE_BUFFER_LIMIT`], yields in-stream `error` events as
+/// errors and ends a stream that stays silent for `idle_timeout`.
+/// `parse_event` returns `None` for events that should be skipped. Shared with
+/// [`crate::responses::create_stream`].
+pub(crate) fn parse_sse_stream<T, F>(
     response: reqwest::Response,
-) -> impl Stream<Item = FoundryResult<ChatCompletionChunk>> {
-    let byte_stream = response.bytes_stream();
-
-    // Buffer for incomplete lines across chunks (bytes for efficiency)
-    stream::unfold(
-        (byte_stream, Vec::<u8>::new()),
-        |(mut byte_stream, mut buffer)| async move {
-            use futures::TryStreamExt;
-
-            loop {
-                // Security: Check buffer size BEFORE processing to prevent DoS
-                if buffer.len() > SSE_BUFFER_LIMIT {
-                    buffer.clear(); // Prevent memory leak
-                    return Some((
-                        Err(FoundryError::stream(format!(
-                            "SSE buffer limit exceeded ({}MB). Possible malformed stream.",
-                            SSE_BUFFER_LIMIT / 1_048_576
-                        ))),
-                        (byte_stream, buffer),
-                    ));
-                }
+    parse_event: F,
+    idle_timeout: Option<Duration>,
+) -> impl Stream<Item = FoundryResult<T>>
+where
+    F: Fn(&SseEvent) -> Option<FoundryResult<T>>,
+{
+    sse::decode_with_idle_timeout(response.bytes_stream(), idle_timeout).filter_map(move |event| {
+        let item = match event {
+            Ok(event) => parse_event(&event),
+            Err(e) => Some(Err(e)),
+        };
+        future::ready(item)
+    })
+}
 
-                // Fast newline search using memchr
-                if let Some(newline_pos) = memchr::memchr(b'\n', &buffer) {
-                    // Extract line bytes and drain from buffer
-                    let line_bytes: Vec<u8> = buffer.drain(..=newline_pos).collect();
+/// Parse a single SSE event into a chunk, returning None for events that should be skipped.
+fn parse_sse_event(event: &SseEvent) -> Option<FoundryResult<ChatCompletionChunk>> {
+    // Skip the [DONE] marker and empty keep-alive payloads
+    if event.is_done() || event.data.trim().is_empty() {
+        return None;
+    }
 
-                    // Skip empty lines (just newline) to prevent underflow
-                    // This is defensive: memchr guarantees at least 1 byte, but we check anyway
-                    if line_bytes.len() <= 1 {
-                        continue;
-                    }
+    match serde_json::from_str::<ChatCompletionChunk>(&event.data) {
+        Ok(chunk) => Some(Ok(chunk)),
+        Err(e) => Some(Err(FoundryError::stream_with_source(
+            "failed to parse chunk",
+            e,
+        ))),
+    }
+}
 
-                    // Convert to string only when needed (skip trailing newline)
-                    let line = match std::str::from_utf8(&line_bytes[..line_bytes.len() - 1]) {
-                        Ok(s) => s,
-                        Err(_) => {
-                            // Invalid UTF-8, skip this line
-                            continue;
-                        }
-                    };
+// ---------------------------------------------------------------------------
+// Stream accumulation
+// ---------------------------------------------------------------------------
 
-                    // Parse the line
-                    if let Some(chunk) = parse_sse_line(line) {
-                        return Some((chunk, (byte_stream, buffer)));
-                    }
-                    // Continue to next line if this one was skipped
-                    continue;
-                }
+/// Rebuilds a complete [`ChatCompletionResponse`] from streaming chunks.
+///
+/// Feed every [`ChatCompletionChunk`] to [`push`](Self::push) in the order
+/// received, then call [`finish`](Self::finish). Content and tool call
+/// argument fragments are concatenated per choice, finish reasons are kept,
+/// and the trailing usage chunk (see
+/// [`ChatCompletionRequestBuilder::stream_include_usage`]) is preserved.
+///
+/// Use [`AccumulatingStream`] to do this while still forwarding the chunks.
+#[derive(Debug, Clone, Default)]
+pub struct ChatCompletionAccumulator {
+    id: Option<String>,
+    created: u64,
+    model: String,
+    choices: std::collections::BTreeMap<u32, ChoiceAccumulator>,
+    usage: Option<Usage>,
+}
 
-                // Need more data
-                match TryStreamExt::try_next(&mut byte_stream).await {
-                    Ok(Some(bytes)) => {
-                        buffer.extend_from_slice(&bytes);
-                    }
-                    Ok(None) => {
-                        // Stream ended, try remaining buffer
-                        if !buffer.is_empty() {
-                            if let Ok(line) = std::str::from_utf8(&buffer) {
-                                if let Some(chunk) = parse_sse_line(line) {
-                                    buffer.clear();
-                                    return Some((chunk, (byte_stream, buffer)));
-                                }
-                            }
-                            buffer.clear();
-                        }
-                        return None;
+/// Per-choice state of a [`ChatCompletionAccumulator`].
+#[derive(Debug, Clone, Default)]
+struct ChoiceAccumulator {
+    role: Option<Role>,
+    content: Option<String>,
+    tool_calls: std::collections::BTreeMap<u32, ToolCall>,
+    context: Option<MessageContext>,
+    finish_reason: Option<String>,
+}
+
+impl ChatCompletionAccumulator {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merge a chunk into the accumulated response.
+    pub fn push(&mut self, chunk: &ChatCompletionChunk) {
+        // Azure may send a leading chunk with empty metadata (prompt filter
+        // results), so only take the metadata from chunks that carry it.
+        if self.id.is_none() && !chunk.id.is_empty() {
+            self.id = Some(chunk.id.clone());
+        }
+        if self.created == 0 {
+            self.created = chunk.created;
+        }
+        if self.model.is_empty() {
+            self.model.clone_from(&chunk.model);
+        }
+        if chunk.usage.is_some() {
+            self.usage.clone_from(&chunk.usage);
+        }
+
+        for choice in &chunk.choices {
+            let acc = self.choices.entry(choice.index).or_default();
+            let delta = &choice.delta;
+
+            if delta.role.is_some() {
+                acc.role.clone_from(&delta.role);
+            }
+            if let Some(ref content) = delta.content {
+                acc.content
+                    .get_or_insert_with(String::new)
+                    .push_str(content);
+            }
+            for fragment in delta.tool_calls.iter().flatten() {
+                let call = acc
+                    .tool_calls
+                    .entry(fragment.index)
+                    .or_insert_with(|| ToolCall {
+                        id: String::new(),
+                        call_type: ToolCallType::default(),
+                        function: FunctionCall::default(),
+                    });
+                if let Some(ref id) = fragment.id {
+                    call.id.clone_from(id);
+                }
+                if let Some(call_type) = fragment.call_type {
+                    call.call_type = call_type;
+                }
+                if let Some(ref function) = fragment.function {
+                    if let Some(ref name) = function.name {
+                        call.function.name.push_str(name);
                     }
-                    Err(e) => {
-                        return Some((Err(FoundryError::from(e)), (byte_stream, buffer)));
+                    if let Some(ref arguments) = function.arguments {
+                        call.function.arguments.push_str(arguments);
                     }
                 }
             }
-        },
-    )
-}
+            if let Some(ref context) = delta.context {
+                acc.context
+                    .get_or_insert_with(Default::default)
+                    .merge(context);
+            }
+            if choice.finish_reason.is_some() {
+                acc.finish_reason.clone_from(&choice.finish_reason);
+            }
+        }
+    }
 
-/// Parse a single SSE line, returning None for lines that should be skipped.
-fn parse_sse_line(line: &str) -> Option<FoundryResult<ChatCompletionChunk>> {
-    let line = line.trim();
+    /// Token usage received so far, if the usage chunk has arrived.
+    pub fn usage(&self) -> Option<&Usage> {
+        self.usage.as_ref()
+    }
 
-    // Skip empty lines and comments
-    if line.is_empty() || line.starts_with(':') {
-        return None;
+    /// Build the complete response from the accumulated chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FoundryError::Stream`] if no chunk with a completion ID was
+    /// pushed (e.g. the stream failed before the first chunk).
+    pub fn finish(self) -> FoundryResult<ChatCompletionResponse> {
+        let id = self
+            .id
+            .ok_or_else(|| FoundryError::stream("no chunks received to accumulate"))?;
+
+        let choices = self
+            .choices
+            .into_iter()
+            .map(|(index, acc)| {
+                let tool_calls: Vec<ToolCall> = acc.tool_calls.into_values().collect();
+                Choice {
+                    index,
+                    message: Message {
+                        role: acc.role.unwrap_or(Role::Assistant),
+                        content: acc.content,
+                        tool_calls: (!tool_calls.is_empty()).then_some(tool_calls),
+                        tool_call_id: None,
+                        context: acc.context,
+                    },
+                    finish_reason: acc.finish_reason,
+                }
+            })
+            .collect();
+
+        Ok(ChatCompletionResponse {
+            id,
+            object: "chat.completion".into(),
+            created: self.created,
+            model: self.model,
+            choices,
+            usage: self.usage,
+        })
     }
+}
 
-    // Handle data lines
-    if let Some(data) = line.strip_prefix("data: ") {
-        let data = data.trim();
+/// A stream adapter that forwards chunks unchanged while accumulating them.
+///
+/// Wraps the stream returned by [`complete_stream`]. Once the stream is
+/// exhausted, call [`into_response`](Self::into_response) to get the full
+/// [`ChatCompletionResponse`], including usage when requested.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::chat::*;
+/// # use futures::StreamExt;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let request = ChatCompletionRequest::builder()
+///     .model("gpt-4o")
+///     .message(Message::user("Tell me a story"))
+///     .stream_include_usage(true)
+///     .build();
+///
+/// let mut stream = AccumulatingStream::new(complete_stream(client, &request).await?);
+/// while let Some(chunk) = stream.next().await {
+///     if let Some(content) = chunk?.choices.first().and_then(|c| c.delta.content.clone()) {
+///         print!("{}", content);
+///     }
+/// }
+///
+/// let response = stream.into_response()?;
+/// println!("\n{:?}", response.usage);
+/// # Ok(())
+/// # }
+/// ```
+pub struct AccumulatingStream<S> {
+    inner: std::pin::Pin<Box<S>>,
+    accumulator: ChatCompletionAccumulator,
+}
 
-        // Skip [DONE] marker
-        if data == "[DONE]" {
-            return None;
+impl<S> AccumulatingStream<S>
+where
+    S: Stream<Item = FoundryResult<ChatCompletionChunk>>,
+{
+    /// Wrap a chunk stream.
+    pub fn new(stream: S) -> Self {
+        Self {
+            inner: Box::pin(stream),
+            accumulator: ChatCompletionAccumulator::new(),
         }
+    }
+
+    /// The state accumulated from the chunks forwarded so far.
+    pub fn accumulator(&self) -> &ChatCompletionAccumulator {
+        &self.accumulator
+    }
 
-        // Parse JSON
-        match serde_json::from_str::<ChatCompletionChunk>(data) {
-            Ok(chunk) => Some(Ok(chunk)),
-            Err(e) => Some(Err(FoundryError::stream_with_source(
-                "failed to parse chunk",
-                e,
-            ))),
+    /// Consume the adapter and build the complete response.
+    ///
+    /// See [`ChatCompletionAccumulator::finish`].
+    pub fn into_response(self) -> FoundryResult<ChatCompletionResponse> {
+        self.accumulator.finish()
+    }
+}
+
+impl<S> std::fmt::Debug for AccumulatingStream<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccumulatingStream")
+            .field("accumulator", &self.accumulator)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Stream for AccumulatingStream<S>
+where
+    S: Stream<Item = FoundryResult<ChatCompletionChunk>>,
+{
+    type Item = FoundryResult<ChatCompletionChunk>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let poll = this.inner.as_mut().poll_next(cx);
+        if let std::task::Poll::Ready(Some(Ok(ref chunk))) = poll {
+            this.accumulator.push(chunk);
         }
-    } else {
-        // Skip other SSE fields (event:, id:, retry:)
-        None
+        poll
     }
 }
 
@@ -1179,7 +1635,7 @@ mod tests {
 
     // --- Integration tests with wiremock ---
 
-    use crate::test_utils::setup_mock_client;
+    use crate::test_utils::{setup_mock_client, TEST_TIMESTAMP};
 
     #[tokio::test]
     async fn complete_success() {
@@ -1414,10 +1870,23 @@ mod tests {
         assert_eq!(chunk.choices[0].finish_reason, Some("stop".into()));
     }
 
+    /// Decode a single SSE line as a complete event and parse it as a chunk.
+    fn parse_sse_line(line: &str) -> Option<FoundryResult<ChatCompletionChunk>> {
+        let mut decoder = sse::SseDecoder::new();
+        let event = decoder
+            .feed(format!("{line}\n\n").as_bytes())
+            .expect("should decode")
+            .pop()?;
+        match event.into_result() {
+            Ok(event) => parse_sse_event(&event),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
     #[test]
     fn parse_sse_line_data() {
         let line = "data: {\"id\":\"test\",\"object\":\"chat.completion.chunk\",\"created\":1234,\"model\":\"gpt-4o\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hi\"},\"finish_reason\":null}]}";
-        let result = super::parse_sse_line(line);
+        let result = parse_sse_line(line);
 
         assert!(result.is_some());
         let chunk = result.unwrap().expect("should parse");
@@ -1428,26 +1897,26 @@ mod tests {
     #[test]
     fn parse_sse_line_done() {
         let line = "data: [DONE]";
-        let result = super::parse_sse_line(line);
+        let result = parse_sse_line(line);
         assert!(result.is_none());
     }
 
     #[test]
     fn parse_sse_line_empty() {
-        let result = super::parse_sse_line("");
+        let result = parse_sse_line("");
         assert!(result.is_none());
     }
 
     #[test]
     fn parse_sse_line_comment() {
-        let result = super::parse_sse_line(": keep-alive");
+        let result = parse_sse_line(": keep-alive");
         assert!(result.is_none());
     }
 
     #[test]
     fn parse_sse_line_invalid_json() {
         let line = "data: {invalid json}";
-        let result = super::parse_sse_line(line);
+        let result = parse_sse_line(line);
 
         assert!(result.is_some());
         let err = result.unwrap();
@@ -1840,21 +2309,21 @@ mod tests {
     fn test_sse_line_single_byte_no_panic() {
         // A line with only a newline character should not panic
         // After stripping the newline, we get an empty string
-        let result = super::parse_sse_line("");
+        let result = parse_sse_line("");
         assert!(result.is_none(), "Empty line should return None");
     }
 
     #[test]
     fn test_sse_line_whitespace_only_no_panic() {
         // Line with only whitespace should not panic
-        let result = super::parse_sse_line("   ");
+        let result = parse_sse_line("   ");
         assert!(result.is_none(), "Whitespace-only line should return None");
     }
 
     #[test]
     fn test_sse_line_single_char_no_panic() {
         // Single character line should not panic
-        let result = super::parse_sse_line("x");
+        let result = parse_sse_line("x");
         assert!(result.is_none(), "Single char line should return None");
     }
 
@@ -1904,6 +2373,264 @@ mod tests {
         assert!(chunks[0].is_ok());
     }
 
+    #[test]
+    fn parse_sse_line_in_stream_error_is_api_error() {
+        let line = r#"data: {"error":{"code":"content_filter","message":"filtered"}}"#;
+        match parse_sse_line(line).expect("should yield") {
+            Err(FoundryError::Api { code, message }) => {
+                assert_eq!(code, "content_filter");
+                assert_eq!(message, "filtered");
+            }
+            other => panic!("Expected Api error, got {:?}", other),
+        }
+    }
+
+    // --- Stream interruption and resumption ---
+
+    fn content_chunk(content: &str) -> ChatCompletionChunk {
+        choice_chunk(0, content)
+    }
+
+    fn choice_chunk(index: u32, content: &str) -> ChatCompletionChunk {
+        serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion.chunk",
+            "created": 1,
+            "model": "gpt-4o",
+            "choices": [{"index": index, "delta": {"content": content}, "finish_reason": null}]
+        }))
+        .unwrap()
+    }
+
+    fn fake_chunk_stream(items: Vec<FoundryResult<ChatCompletionChunk>>) -> ChunkStream {
+        Box::pin(futures::stream::iter(items))
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_stream_carries_partial_content() {
+        let inner = fake_chunk_stream(vec![
+            Ok(content_chunk("Hel")),
+            Ok(content_chunk("lo")),
+            Err(FoundryError::stream_interrupted(
+                "no data received for 30s",
+                None,
+            )),
+            Ok(content_chunk("never yielded")),
+        ]);
+
+        let items: Vec<_> = track_interruptions(inner, None).collect().await;
+
+        assert_eq!(items.len(), 3);
+        match &items[2] {
+            Err(
+                err @ FoundryError::StreamInterrupted {
+                    partial_content, ..
+                },
+            ) => {
+                assert_eq!(partial_content, &["Hello"]);
+                assert!(err.is_retryable());
+            }
+            other => panic!("Expected StreamInterrupted, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_stream_carries_partial_content_per_choice() {
+        let inner = fake_chunk_stream(vec![
+            Ok(choice_chunk(0, "Hi")),
+            Ok(choice_chunk(1, "Hey")),
+            Ok(choice_chunk(0, " there")),
+            Err(FoundryError::stream_interrupted("connection lost", None)),
+        ]);
+
+        let items: Vec<_> = track_interruptions(inner, None).collect().await;
+
+        match items.last() {
+            Some(Err(FoundryError::StreamInterrupted {
+                partial_content, ..
+            })) => assert_eq!(partial_content, &["Hi there", "Hey"]),
+            other => panic!("Expected StreamInterrupted, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_resume_keeps_partial_content() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("Last-Event-ID", "1"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": {"code": "BadRequest", "message": "cannot resume"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let resume = StreamResume {
+            client,
+            request: ChatCompletionRequest::builder()
+                .model("gpt-4o")
+                .message(Message::user("Hello"))
+                .build(),
+            remaining: 1,
+        };
+        let inner = fake_chunk_stream(vec![
+            Ok(content_chunk("Hel")),
+            Err(FoundryError::stream_interrupted(
+                "connection lost",
+                Some("1".into()),
+            )),
+        ]);
+
+        let items: Vec<_> = track_interruptions(inner, Some(resume)).collect().await;
+
+        assert_eq!(items.len(), 2);
+        match &items[1] {
+            Err(
+                err @ FoundryError::StreamInterrupted {
+                    partial_content,
+                    last_event_id,
+                    source,
+                    ..
+                },
+            ) => {
+                assert_eq!(partial_content, &["Hel"]);
+                assert_eq!(last_event_id.as_deref(), Some("1"));
+                assert!(source.is_some());
+                assert!(err.to_string().contains("resume failed"), "{err}");
+            }
+            other => panic!("Expected StreamInterrupted, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_stream_without_event_id_is_not_resumed() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let resume = StreamResume {
+            client,
+            request: ChatCompletionRequest::builder()
+                .model("gpt-4o")
+                .message(Message::user("Hello"))
+                .build(),
+            remaining: 3,
+        };
+        let inner = fake_chunk_stream(vec![
+            Ok(content_chunk("Hi")),
+            Err(FoundryError::stream_interrupted("connection lost", None)),
+        ]);
+
+        let items: Vec<_> = track_interruptions(inner, Some(resume)).collect().await;
+
+        assert_eq!(items.len(), 2);
+        assert!(matches!(
+            items[1],
+            Err(FoundryError::StreamInterrupted { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_stream_resumes_with_last_event_id() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/chat/completions"))
+            .and(header("Last-Event-ID", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(concat!(
+                        "id: 2\n",
+                        "data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"gpt-4o\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"lo\"},\"finish_reason\":\"stop\"}]}\n\n",
+                        "data: [DONE]\n\n"
+                    ))
+                    .insert_header("content-type", "text/event-stream"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = FoundryClient::builder()
+            .endpoint(server.uri())
+            .credential(azure_ai_foundry_core::auth::FoundryCredential::api_key("k"))
+            .max_stream_resumes(1)
+            .build()
+            .expect("should build");
+        let resume = StreamResume {
+            client,
+            request: ChatCompletionRequest::builder()
+                .model("gpt-4o")
+                .message(Message::user("Hello"))
+                .build(),
+            remaining: 1,
+        };
+        let inner = fake_chunk_stream(vec![
+            Ok(content_chunk("Hel")),
+            Err(FoundryError::stream_interrupted(
+                "connection lost",
+                Some("1".into()),
+            )),
+        ]);
+
+        let items: Vec<_> = track_interruptions(inner, Some(resume)).collect().await;
+
+        let content: String = items
+            .iter()
+            .map(|r| r.as_ref().expect("should not error"))
+            .filter_map(|c| c.choices[0].delta.content.clone())
+            .collect();
+        assert_eq!(content, "Hello");
+    }
+
+    #[tokio::test]
+    async fn test_sse_stream_crlf_and_multi_line_data() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+
+        // CRLF line endings and a JSON payload split over two data lines
+        let sse_body = concat!(
+            "id: 1\r\n",
+            "data: {\"id\":\"1\",\"object\":\"chat.completion.chunk\",\"created\":1,\r\n",
+            "data: \"model\":\"gpt-4o\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hi\"},\"finish_reason\":null}]}\r\n",
+            "\r\n",
+            "data: [DONE]\r\n",
+            "\r\n"
+        );
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/chat/completions"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(sse_body)
+                    .insert_header("content-type", "text/event-stream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+
+        let request = ChatCompletionRequest::builder()
+            .model("gpt-4o")
+            .message(Message::user("Hello"))
+            .build();
+
+        let stream = complete_stream(&client, &request)
+            .await
+            .expect("should start stream");
+
+        let chunks: Vec<_> = stream.collect().await;
+
+        assert_eq!(chunks.len(), 1);
+        let chunk = chunks[0].as_ref().expect("should parse");
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hi"));
+    }
+
     #[tokio::test]
     #[traced_test]
     async fn test_complete_stream_emits_chat_stream_span() {
@@ -1997,4 +2724,277 @@ data: [DONE]
         assert_eq!(counts[&Role::User], 2);
         assert_eq!(counts[&Role::Assistant], 1);
     }
+
+    // --- Stream accumulation ---
+
+    fn chunk(value: serde_json::Value) -> ChatCompletionChunk {
+        serde_json::from_value(value).expect("valid chunk")
+    }
+
+    #[test]
+    fn test_accumulator_concatenates_content_and_keeps_finish_reason() {
+        let mut acc = ChatCompletionAccumulator::new();
+        acc.push(&chunk(serde_json::json!({
+            "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{"index": 0, "delta": {"role": "assistant", "content": "Hel"}, "finish_reason": null}]
+        })));
+        acc.push(&chunk(serde_json::json!({
+            "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{"index": 0, "delta": {"content": "lo"}, "finish_reason": null}]
+        })));
+        acc.push(&chunk(serde_json::json!({
+            "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{"index": 0, "delta": {}, "finish_reason": "stop"}]
+        })));
+
+        let response = acc.finish().expect("should finish");
+        assert_eq!(response.id, "chatcmpl-1");
+        assert_eq!(response.object, "chat.completion");
+        assert_eq!(response.model, "gpt-4o");
+        assert_eq!(response.choices.len(), 1);
+        assert_eq!(response.choices[0].message.role, Role::Assistant);
+        assert_eq!(response.choices[0].message.content, Some("Hello".into()));
+        assert_eq!(response.choices[0].finish_reason, Some("stop".into()));
+        assert!(response.choices[0].message.tool_calls.is_none());
+    }
+
+    #[test]
+    fn test_accumulator_assembles_tool_calls() {
+        let mut acc = ChatCompletionAccumulator::new();
+        acc.push(&chunk(serde_json::json!({
+            "id": "c", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{"index": 0, "delta": {"role": "assistant", "tool_calls": [
+                {"index": 0, "id": "call_a", "type": "function", "function": {"name": "get_weather", "arguments": ""}},
+                {"index": 1, "id": "call_b", "type": "function", "function": {"name": "get_time", "arguments": "{}"}}
+            ]}, "finish_reason": null}]
+        })));
+        acc.push(&chunk(serde_json::json!({
+            "id": "c", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{"index": 0, "delta": {"tool_calls": [
+                {"index": 0, "function": {"arguments": "{\"city\":"}}
+            ]}, "finish_reason": null}]
+        })));
+        acc.push(&chunk(serde_json::json!({
+            "id": "c", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{"index": 0, "delta": {"tool_calls": [
+                {"index": 0, "function": {"arguments": "\"Paris\"}"}}
+            ]}, "finish_reason": "tool_calls"}]
+        })));
+
+        let response = acc.finish().unwrap();
+        let message = &response.choices[0].message;
+        assert!(message.content.is_none());
+        let calls = message.tool_calls.as_ref().expect("tool calls");
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].id, "call_a");
+        assert_eq!(calls[0].call_type, ToolCallType::Function);
+        assert_eq!(calls[0].function.name, "get_weather");
+        assert_eq!(calls[0].function.arguments, "{\"city\":\"Paris\"}");
+        assert_eq!(calls[1].function.name, "get_time");
+        assert_eq!(response.choices[0].finish_reason, Some("tool_calls".into()));
+    }
+
+    #[test]
+    fn test_accumulator_keeps_choices_separate_and_usage() {
+        let mut acc = ChatCompletionAccumulator::new();
+        // Azure sends a leading prompt-filter chunk with empty metadata.
+        acc.push(&chunk(serde_json::json!({
+            "id": "", "object": "", "created": 0, "model": "", "choices": []
+        })));
+        acc.push(&chunk(serde_json::json!({
+            "id": "c", "object": "chat.completion.chunk", "created": 7, "model": "gpt-4o",
+            "choices": [
+                {"index": 1, "delta": {"role": "assistant", "content": "B"}, "finish_reason": "stop"},
+                {"index": 0, "delta": {"role": "assistant", "content": "A"}, "finish_reason": "length"}
+            ]
+        })));
+        assert!(acc.usage().is_none());
+        acc.push(&chunk(serde_json::json!({
+            "id": "c", "object": "chat.completion.chunk", "created": 7, "model": "gpt-4o",
+            "choices": [],
+            "usage": {"prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7}
+        })));
+
+        let response = acc.finish().unwrap();
+        assert_eq!(response.id, "c");
+        assert_eq!(response.created, 7);
+        assert_eq!(response.choices.len(), 2);
+        assert_eq!(response.choices[0].index, 0);
+        assert_eq!(response.choices[0].message.content, Some("A".into()));
+        assert_eq!(response.choices[0].finish_reason, Some("length".into()));
+        assert_eq!(response.choices[1].message.content, Some("B".into()));
+        assert_eq!(response.usage.unwrap().total_tokens, 7);
+    }
+
+    #[test]
+    fn test_accumulator_finish_without_chunks_is_error() {
+        let err = ChatCompletionAccumulator::new().finish().unwrap_err();
+        assert!(matches!(err, FoundryError::Stream { .. }));
+    }
+
+    #[test]
+    fn test_stream_include_usage_serializes_stream_options() {
+        let request = ChatCompletionRequest::builder()
+            .model("gpt-4o")
+            .message(Message::user("Hi"))
+            .stream_include_usage(true)
+            .build();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["stream_options"]["include_usage"], true);
+    }
+
+    #[test]
+    fn test_tool_message_serialization() {
+        let json = serde_json::to_value(Message::tool("call_1", "42")).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"role": "tool", "content": "42", "tool_call_id": "call_1"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_accumulating_stream_forwards_chunks_and_builds_response() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+
+        let sse_body = concat!(
+            "data: {\"id\":\"c1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"gpt-4o\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"Hi\"},\"finish_reason\":null}]}\n\n",
+            "data: {\"id\":\"c1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"gpt-4o\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\" there\"},\"finish_reason\":\"stop\"}]}\n\n",
+            "data: {\"id\":\"c1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"gpt-4o\",\"choices\":[],\"usage\":{\"prompt_tokens\":3,\"completion_tokens\":2,\"total_tokens\":5}}\n\n",
+            "data: [DONE]\n\n"
+        );
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/chat/completions"))
+            .and(body_json(serde_json::json!({
+                "model": "gpt-4o",
+                "messages": [{"role": "user", "content": "Hi"}],
+                "stream": true,
+                "stream_options": {"include_usage": true}
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(sse_body)
+                    .insert_header("content-type", "text/event-stream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = ChatCompletionRequest::builder()
+            .model("gpt-4o")
+            .message(Message::user("Hi"))
+            .stream_include_usage(true)
+            .build();
+
+        let stream = complete_stream(&client, &request).await.expect("stream");
+        let mut stream = AccumulatingStream::new(stream);
+        let mut forwarded = 0;
+        while let Some(chunk) = stream.next().await {
+            chunk.expect("chunk");
+            forwarded += 1;
+        }
+        assert_eq!(forwarded, 3);
+
+        let response = stream.into_response().expect("response");
+        assert_eq!(response.choices[0].message.content, Some("Hi there".into()));
+        assert_eq!(response.usage.unwrap().completion_tokens, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_complete_with_data_source_returns_citations() {
+        use data_sources::{DataSource, DataSourceAuth};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/chat/completions"))
+            .and(body_json(serde_json::json!({
+                "model": "gpt-4o",
+                "messages": [{"role": "user", "content": "How do I reset it?"}],
+                "data_sources": [{
+                    "type": "azure_search",
+                    "parameters": {
+                        "endpoint": "https://search.example.net",
+                        "index_name": "docs",
+                        "authentication": {"type": "system_assigned_managed_identity"}
+                    }
+                }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-1",
+                "object": "chat.completion",
+                "created": TEST_TIMESTAMP,
+                "model": "gpt-4o",
+                "choices": [{
+                    "index": 0,
+                    "message": {
+                        "role": "assistant",
+                        "content": "Hold the button for 10 seconds [doc1].",
+                        "context": {
+                            "citations": [{"content": "Hold reset for 10 s.", "title": "Manual"}],
+                            "intent": "[\"reset router\"]"
+                        }
+                    },
+                    "finish_reason": "stop"
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = ChatCompletionRequest::builder()
+            .model("gpt-4o")
+            .message(Message::user("How do I reset it?"))
+            .data_source(
+                DataSource::azure_search()
+                    .endpoint("https://search.example.net")
+                    .index_name("docs")
+                    .authentication(DataSourceAuth::SystemAssignedManagedIdentity)
+                    .build(),
+            )
+            .build();
+
+        let response = complete(&client, &request).await.expect("response");
+        let context = response.choices[0]
+            .message
+            .context
+            .as_ref()
+            .expect("context");
+        assert_eq!(
+            context.citation(1).unwrap().title.as_deref(),
+            Some("Manual")
+        );
+        assert_eq!(context.intents(), vec!["reset router"]);
+    }
+
+    #[test]
+    fn test_accumulator_merges_context() {
+        let mut acc = ChatCompletionAccumulator::new();
+        acc.push(&chunk(serde_json::json!({
+            "id": "c", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{"index": 0, "delta": {"role": "assistant", "context": {
+                "citations": [{"content": "Doc one"}],
+                "intent": "[\"q\"]"
+            }}, "finish_reason": null}]
+        })));
+        acc.push(&chunk(serde_json::json!({
+            "id": "c", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{"index": 0, "delta": {"content": "See [doc1]."}, "finish_reason": "stop"}]
+        })));
+
+        let response = acc.finish().unwrap();
+        let message = &response.choices[0].message;
+        assert_eq!(message.content, Some("See [doc1].".into()));
+        let context = message.context.as_ref().expect("context");
+        assert_eq!(context.citations[0].content, "Doc one");
+        assert_eq!(context.intent.as_deref(), Some("[\"q\"]"));
+    }
+
+    #[test]
+    fn test_message_without_context_omits_it() {
+        let json = serde_json::to_value(Message::assistant("Hi")).unwrap();
+        assert!(json.get("context").is_none());
+    }
 }
@@ -0,0 +1,931 @@
+//! Azure OpenAI "On Your Data" for chat completions.
+//!
+//! Adding a [`DataSource`] to a [`ChatCompletionRequest`](super::ChatCompletionRequest)
+//! makes the service search an index, ground the answer in the documents it
+//! finds and cite them. Supported sources are Azure AI Search indexes and
+//! Azure Cosmos DB for MongoDB vCore vector indexes.
+//!
+//! Answers refer to citations as `[doc1]`, `[doc2]`, ... and carry the
+//! cited documents in [`Message::context`](super::Message::context) (or
+//! [`Delta::context`](super::Delta::context) when streaming) as a
+//! [`MessageContext`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_core::error::FoundryResult;
+//! use azure_ai_foundry_models::chat::data_sources::{
+//!     DataSource, DataSourceAuth, EmbeddingDependency, QueryType,
+//! };
+//! use azure_ai_foundry_models::chat::{self, ChatCompletionRequest, Message};
+//!
+//! # async fn example(client: &FoundryClient) -> FoundryResult<()> {
+//! let source = DataSource::azure_search()
+//!     .endpoint("https://my-search.search.windows.net")
+//!     .index_name("product-docs")
+//!     .authentication(DataSourceAuth::SystemAssignedManagedIdentity)
+//!     .query_type(QueryType::VectorSimpleHybrid)
+//!     .embedding_dependency(EmbeddingDependency::deployment("text-embedding-3-small"))
+//!     .top_n_documents(5)
+//!     .build();
+//!
+//! let request = ChatCompletionRequest::builder()
+//!     .model("gpt-4o")
+//!     .message(Message::user("How do I reset the router?"))
+//!     .data_source(source)
+//!     .build();
+//!
+//! let response = chat::complete(client, &request).await?;
+//! let message = &response.choices[0].message;
+//! println!("{}", message.content.as_deref().unwrap_or_default());
+//! for citation in message.context.iter().flat_map(|c| &c.citations) {
+//!     println!("- {}", citation.title.as_deref().unwrap_or("untitled"));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize, Serializer};
+
+// ---------------------------------------------------------------------------
+// Request types
+// ---------------------------------------------------------------------------
+
+/// A data source to ground chat completions in.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "parameters", rename_all = "snake_case")]
+pub enum DataSource {
+    /// An Azure AI Search index.
+    AzureSearch(AzureSearchParameters),
+    /// An Azure Cosmos DB for MongoDB vCore vector index.
+    AzureCosmosDb(CosmosDbParameters),
+}
+
+impl DataSource {
+    /// Start building an Azure AI Search data source.
+    pub fn azure_search() -> AzureSearchBuilder {
+        AzureSearchBuilder::default()
+    }
+
+    /// Start building an Azure Cosmos DB data source.
+    pub fn cosmos_db() -> CosmosDbBuilder {
+        CosmosDbBuilder::default()
+    }
+}
+
+/// How the service authenticates to a data source or embedding endpoint.
+///
+/// Keys, tokens and connection strings are held as [`SecretString`]s: they
+/// are only revealed when the request is serialized, and `Debug` output
+/// hides them.
+#[derive(Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DataSourceAuth {
+    /// An API key.
+    ApiKey {
+        #[serde(serialize_with = "expose")]
+        key: SecretString,
+    },
+    /// The system-assigned managed identity of the Azure OpenAI resource.
+    SystemAssignedManagedIdentity,
+    /// A user-assigned managed identity of the Azure OpenAI resource.
+    UserAssignedManagedIdentity {
+        managed_identity_resource_id: String,
+    },
+    /// A bearer token.
+    AccessToken {
+        #[serde(serialize_with = "expose")]
+        access_token: SecretString,
+    },
+    /// A connection string (Cosmos DB only).
+    ConnectionString {
+        #[serde(serialize_with = "expose")]
+        connection_string: SecretString,
+    },
+}
+
+/// Serialize a secret as its plain value.
+fn expose<S: Serializer>(secret: &SecretString, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(secret.expose_secret())
+}
+
+impl DataSourceAuth {
+    /// Authenticate with an API key.
+    pub fn api_key(key: impl Into<String>) -> Self {
+        Self::ApiKey {
+            key: SecretString::from(key.into()),
+        }
+    }
+
+    /// Authenticate with a user-assigned managed identity.
+    pub fn user_assigned_identity(resource_id: impl Into<String>) -> Self {
+        Self::UserAssignedManagedIdentity {
+            managed_identity_resource_id: resource_id.into(),
+        }
+    }
+
+    /// Authenticate with a bearer token.
+    pub fn access_token(token: impl Into<String>) -> Self {
+        Self::AccessToken {
+            access_token: SecretString::from(token.into()),
+        }
+    }
+
+    /// Authenticate with a connection string.
+    pub fn connection_string(connection_string: impl Into<String>) -> Self {
+        Self::ConnectionString {
+            connection_string: SecretString::from(connection_string.into()),
+        }
+    }
+}
+
+impl fmt::Debug for DataSourceAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApiKey { .. } => write!(f, "DataSourceAuth::ApiKey(****)"),
+            Self::SystemAssignedManagedIdentity => {
+                write!(f, "DataSourceAuth::SystemAssignedManagedIdentity")
+            }
+            Self::UserAssignedManagedIdentity {
+                managed_identity_resource_id,
+            } => write!(
+                f,
+                "DataSourceAuth::UserAssignedManagedIdentity({})",
+                managed_identity_resource_id
+            ),
+            Self::AccessToken { .. } => write!(f, "DataSourceAuth::AccessToken(****)"),
+            Self::ConnectionString { .. } => write!(f, "DataSourceAuth::ConnectionString(****)"),
+        }
+    }
+}
+
+/// The embedding model used to vectorize the query for vector search.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EmbeddingDependency {
+    /// An embedding deployment on the same Azure OpenAI resource.
+    DeploymentName { deployment_name: String },
+    /// An embeddings endpoint URL, with `api_key` or `access_token`
+    /// authentication.
+    Endpoint {
+        endpoint: String,
+        authentication: DataSourceAuth,
+    },
+}
+
+impl EmbeddingDependency {
+    /// Use an embedding deployment on the same resource.
+    pub fn deployment(name: impl Into<String>) -> Self {
+        Self::DeploymentName {
+            deployment_name: name.into(),
+        }
+    }
+
+    /// Use an embeddings endpoint.
+    pub fn endpoint(endpoint: impl Into<String>, authentication: DataSourceAuth) -> Self {
+        Self::Endpoint {
+            endpoint: endpoint.into(),
+            authentication,
+        }
+    }
+}
+
+/// How Azure AI Search queries the index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryType {
+    /// Keyword search.
+    Simple,
+    /// Keyword search with semantic ranking.
+    Semantic,
+    /// Vector search.
+    Vector,
+    /// Keyword and vector search.
+    VectorSimpleHybrid,
+    /// Keyword and vector search with semantic ranking.
+    VectorSemanticHybrid,
+}
+
+impl QueryType {
+    fn uses_vectors(self) -> bool {
+        matches!(
+            self,
+            Self::Vector | Self::VectorSimpleHybrid | Self::VectorSemanticHybrid
+        )
+    }
+}
+
+/// Context the service returns alongside the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextProperty {
+    /// The documents cited in the answer.
+    Citations,
+    /// The search intents derived from the conversation.
+    Intent,
+    /// Every retrieved document, including ones filtered out.
+    AllRetrievedDocuments,
+}
+
+/// Which index fields hold document content and metadata.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FieldsMapping {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_fields: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_fields_separator: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filepath_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_fields: Option<Vec<String>>,
+}
+
+/// Retrieval options shared by every data source.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RetrievalOptions {
+    /// Number of documents to retrieve for the answer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_n_documents: Option<u32>,
+
+    /// Whether to answer only from the retrieved documents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_scope: Option<bool>,
+
+    /// How strictly documents are filtered for relevance, from 1 to 5.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strictness: Option<u8>,
+
+    /// Maximum number of search queries sent for one user message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_search_queries: Option<u32>,
+
+    /// Whether to answer when some search queries fail.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_partial_result: Option<bool>,
+
+    /// Which context to return in [`MessageContext`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_contexts: Option<Vec<ContextProperty>>,
+}
+
+/// Parameters of an Azure AI Search data source.
+#[derive(Debug, Clone, Serialize)]
+pub struct AzureSearchParameters {
+    pub endpoint: String,
+    pub index_name: String,
+    pub authentication: DataSourceAuth,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields_mapping: Option<FieldsMapping>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_type: Option<QueryType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_configuration: Option<String>,
+
+    /// An OData filter applied to the search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedding_dependency: Option<EmbeddingDependency>,
+
+    #[serde(flatten)]
+    pub retrieval: RetrievalOptions,
+}
+
+/// Parameters of an Azure Cosmos DB for MongoDB vCore data source.
+#[derive(Debug, Clone, Serialize)]
+pub struct CosmosDbParameters {
+    pub database_name: String,
+    pub container_name: String,
+    pub index_name: String,
+    pub authentication: DataSourceAuth,
+    pub fields_mapping: FieldsMapping,
+    pub embedding_dependency: EmbeddingDependency,
+
+    #[serde(flatten)]
+    pub retrieval: RetrievalOptions,
+}
+
+/// Adds setters for [`RetrievalOptions`] to a data source builder.
+macro_rules! retrieval_setters {
+    () => {
+        /// Set the number of documents to retrieve for the answer.
+        pub fn top_n_documents(mut self, n: u32) -> Self {
+            self.retrieval.top_n_documents = Some(n);
+            self
+        }
+
+        /// Answer only from the retrieved documents when `true`.
+        pub fn in_scope(mut self, in_scope: bool) -> Self {
+            self.retrieval.in_scope = Some(in_scope);
+            self
+        }
+
+        /// Set how strictly documents are filtered for relevance (1 to 5).
+        pub fn strictness(mut self, strictness: u8) -> Self {
+            self.retrieval.strictness = Some(strictness);
+            self
+        }
+
+        /// Set the maximum number of search queries per user message.
+        pub fn max_search_queries(mut self, max: u32) -> Self {
+            self.retrieval.max_search_queries = Some(max);
+            self
+        }
+
+        /// Answer even when some search queries fail.
+        pub fn allow_partial_result(mut self, allow: bool) -> Self {
+            self.retrieval.allow_partial_result = Some(allow);
+            self
+        }
+
+        /// Choose which context to return with the answer.
+        pub fn include_contexts(
+            mut self,
+            contexts: impl IntoIterator<Item = ContextProperty>,
+        ) -> Self {
+            self.retrieval.include_contexts = Some(contexts.into_iter().collect());
+            self
+        }
+    };
+}
+
+fn required(value: Option<String>, name: &str) -> FoundryResult<String> {
+    match value {
+        Some(value) if !value.trim().is_empty() => Ok(value),
+        Some(_) => Err(FoundryError::Builder(format!("{} cannot be empty", name))),
+        None => Err(FoundryError::Builder(format!("{} is required", name))),
+    }
+}
+
+fn validate_retrieval(retrieval: &RetrievalOptions) -> FoundryResult<()> {
+    if let Some(strictness) = retrieval.strictness {
+        if !(1..=5).contains(&strictness) {
+            return Err(FoundryError::Builder(
+                "strictness must be between 1 and 5".into(),
+            ));
+        }
+    }
+    if retrieval.top_n_documents == Some(0) {
+        return Err(FoundryError::Builder(
+            "top_n_documents must be at least 1".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Builder for an Azure AI Search [`DataSource`].
+#[derive(Debug, Default)]
+pub struct AzureSearchBuilder {
+    endpoint: Option<String>,
+    index_name: Option<String>,
+    authentication: Option<DataSourceAuth>,
+    fields_mapping: Option<FieldsMapping>,
+    query_type: Option<QueryType>,
+    semantic_configuration: Option<String>,
+    filter: Option<String>,
+    embedding_dependency: Option<EmbeddingDependency>,
+    retrieval: RetrievalOptions,
+}
+
+impl AzureSearchBuilder {
+    /// Set the search service endpoint.
+    ///
+    /// **Required.** Example: `"https://my-search.search.windows.net"`.
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Set the index to search.
+    ///
+    /// **Required.**
+    pub fn index_name(mut self, index_name: impl Into<String>) -> Self {
+        self.index_name = Some(index_name.into());
+        self
+    }
+
+    /// Set how the service authenticates to the search service.
+    ///
+    /// **Required.** Connection strings are not supported.
+    pub fn authentication(mut self, authentication: DataSourceAuth) -> Self {
+        self.authentication = Some(authentication);
+        self
+    }
+
+    /// Map index fields to document content and metadata.
+    pub fn fields_mapping(mut self, mapping: FieldsMapping) -> Self {
+        self.fields_mapping = Some(mapping);
+        self
+    }
+
+    /// Set the query type. Vector query types need an
+    /// [`embedding_dependency`](Self::embedding_dependency).
+    pub fn query_type(mut self, query_type: QueryType) -> Self {
+        self.query_type = Some(query_type);
+        self
+    }
+
+    /// Set the semantic configuration for semantic query types.
+    pub fn semantic_configuration(mut self, name: impl Into<String>) -> Self {
+        self.semantic_configuration = Some(name.into());
+        self
+    }
+
+    /// Set an OData filter applied to the search.
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Set the embedding model used for vector search.
+    pub fn embedding_dependency(mut self, dependency: EmbeddingDependency) -> Self {
+        self.embedding_dependency = Some(dependency);
+        self
+    }
+
+    retrieval_setters!();
+
+    /// Build the data source, returning an error if required fields are
+    /// missing or values are out of range.
+    pub fn try_build(self) -> FoundryResult<DataSource> {
+        let endpoint = required(self.endpoint, "endpoint")?;
+        let index_name = required(self.index_name, "index_name")?;
+        let authentication = self
+            .authentication
+            .ok_or_else(|| FoundryError::Builder("authentication is required".into()))?;
+        if matches!(authentication, DataSourceAuth::ConnectionString { .. }) {
+            return Err(FoundryError::Builder(
+                "Azure AI Search does not support connection string authentication".into(),
+            ));
+        }
+        if self.query_type.is_some_and(QueryType::uses_vectors)
+            && self.embedding_dependency.is_none()
+        {
+            return Err(FoundryError::Builder(
+                "vector query types require an embedding_dependency".into(),
+            ));
+        }
+        validate_retrieval(&self.retrieval)?;
+
+        Ok(DataSource::AzureSearch(AzureSearchParameters {
+            endpoint,
+            index_name,
+            authentication,
+            fields_mapping: self.fields_mapping,
+            query_type: self.query_type,
+            semantic_configuration: self.semantic_configuration,
+            filter: self.filter,
+            embedding_dependency: self.embedding_dependency,
+            retrieval: self.retrieval,
+        }))
+    }
+
+    /// Build the data source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `endpoint`, `index_name` or `authentication` is not set, or
+    /// if values are invalid. Use [`try_build`](Self::try_build) for fallible
+    /// construction.
+    pub fn build(self) -> DataSource {
+        self.try_build().expect("builder validation failed")
+    }
+}
+
+/// Builder for an Azure Cosmos DB [`DataSource`].
+#[derive(Debug, Default)]
+pub struct CosmosDbBuilder {
+    database_name: Option<String>,
+    container_name: Option<String>,
+    index_name: Option<String>,
+    authentication: Option<DataSourceAuth>,
+    fields_mapping: Option<FieldsMapping>,
+    embedding_dependency: Option<EmbeddingDependency>,
+    retrieval: RetrievalOptions,
+}
+
+impl CosmosDbBuilder {
+    /// Set the database name.
+    ///
+    /// **Required.**
+    pub fn database_name(mut self, name: impl Into<String>) -> Self {
+        self.database_name = Some(name.into());
+        self
+    }
+
+    /// Set the container name.
+    ///
+    /// **Required.**
+    pub fn container_name(mut self, name: impl Into<String>) -> Self {
+        self.container_name = Some(name.into());
+        self
+    }
+
+    /// Set the vector index name.
+    ///
+    /// **Required.**
+    pub fn index_name(mut self, name: impl Into<String>) -> Self {
+        self.index_name = Some(name.into());
+        self
+    }
+
+    /// Set the connection string used to reach the database.
+    ///
+    /// **Required.**
+    pub fn connection_string(mut self, connection_string: impl Into<String>) -> Self {
+        self.authentication = Some(DataSourceAuth::connection_string(connection_string));
+        self
+    }
+
+    /// Map document fields to content, metadata and vectors.
+    ///
+    /// **Required,** with `content_fields` and `vector_fields` set.
+    pub fn fields_mapping(mut self, mapping: FieldsMapping) -> Self {
+        self.fields_mapping = Some(mapping);
+        self
+    }
+
+    /// Set the embedding model used to vectorize queries.
+    ///
+    /// **Required.**
+    pub fn embedding_dependency(mut self, dependency: EmbeddingDependency) -> Self {
+        self.embedding_dependency = Some(dependency);
+        self
+    }
+
+    retrieval_setters!();
+
+    /// Build the data source, returning an error if required fields are
+    /// missing or values are out of range.
+    pub fn try_build(self) -> FoundryResult<DataSource> {
+        let database_name = required(self.database_name, "database_name")?;
+        let container_name = required(self.container_name, "container_name")?;
+        let index_name = required(self.index_name, "index_name")?;
+        let authentication = self
+            .authentication
+            .ok_or_else(|| FoundryError::Builder("connection_string is required".into()))?;
+        let fields_mapping = self
+            .fields_mapping
+            .ok_or_else(|| FoundryError::Builder("fields_mapping is required".into()))?;
+        if fields_mapping
+            .content_fields
+            .as_ref()
+            .is_none_or(Vec::is_empty)
+            || fields_mapping
+                .vector_fields
+                .as_ref()
+                .is_none_or(Vec::is_empty)
+        {
+            return Err(FoundryError::Builder(
+                "fields_mapping must set content_fields and vector_fields".into(),
+            ));
+        }
+        let embedding_dependency = self
+            .embedding_dependency
+            .ok_or_else(|| FoundryError::Builder("embedding_dependency is required".into()))?;
+        validate_retrieval(&self.retrieval)?;
+
+        Ok(DataSource::AzureCosmosDb(CosmosDbParameters {
+            database_name,
+            container_name,
+            index_name,
+            authentication,
+            fields_mapping,
+            embedding_dependency,
+            retrieval: self.retrieval,
+        }))
+    }
+
+    /// Build the data source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a required field is not set or values are invalid. Use
+    /// [`try_build`](Self::try_build) for fallible construction.
+    pub fn build(self) -> DataSource {
+        self.try_build().expect("builder validation failed")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Response types
+// ---------------------------------------------------------------------------
+
+/// Grounding context returned with an answer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MessageContext {
+    /// The documents cited in the answer; `[docN]` refers to entry `N - 1`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub citations: Vec<Citation>,
+
+    /// The search intents, as a JSON-encoded list of strings. See
+    /// [`intents`](Self::intents).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub intent: Option<String>,
+
+    /// Every retrieved document, when requested with
+    /// [`ContextProperty::AllRetrievedDocuments`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub all_retrieved_documents: Vec<RetrievedDocument>,
+}
+
+impl MessageContext {
+    /// The search intents, decoded from [`intent`](Self::intent).
+    ///
+    /// Returns the raw string as the only intent if it is not a JSON list.
+    pub fn intents(&self) -> Vec<String> {
+        match self.intent.as_deref() {
+            None => Vec::new(),
+            Some(intent) => {
+                serde_json::from_str(intent).unwrap_or_else(|_| vec![intent.to_string()])
+            }
+        }
+    }
+
+    /// The citation a `[docN]` marker refers to (`n` starts at 1).
+    pub fn citation(&self, n: usize) -> Option<&Citation> {
+        self.citations.get(n.checked_sub(1)?)
+    }
+
+    /// Merge context from a later streaming chunk.
+    pub(crate) fn merge(&mut self, other: &MessageContext) {
+        self.citations.extend_from_slice(&other.citations);
+        if other.intent.is_some() {
+            self.intent.clone_from(&other.intent);
+        }
+        self.all_retrieved_documents
+            .extend_from_slice(&other.all_retrieved_documents);
+    }
+}
+
+/// A document cited in an answer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Citation {
+    /// The cited text.
+    pub content: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filepath: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk_id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f64>,
+}
+
+/// A document retrieved while answering, cited or not.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RetrievedDocument {
+    /// The document and its metadata.
+    #[serde(flatten)]
+    pub document: Citation,
+
+    /// The search queries that retrieved the document.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_queries: Vec<String>,
+
+    /// Index of the data source the document came from.
+    #[serde(default)]
+    pub data_source_index: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_search_score: Option<f64>,
+
+    /// Why the document was not used: `"score"` or `"rerank"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_reason: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_azure_search_serialization() {
+        let source = DataSource::azure_search()
+            .endpoint("https://search.example.net")
+            .index_name("docs")
+            .authentication(DataSourceAuth::api_key("search-key"))
+            .fields_mapping(FieldsMapping {
+                content_fields: Some(vec!["content".into()]),
+                title_field: Some("title".into()),
+                ..Default::default()
+            })
+            .query_type(QueryType::VectorSemanticHybrid)
+            .semantic_configuration("default")
+            .embedding_dependency(EmbeddingDependency::deployment("ada"))
+            .top_n_documents(5)
+            .strictness(3)
+            .in_scope(true)
+            .include_contexts([ContextProperty::Citations, ContextProperty::Intent])
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&source).unwrap(),
+            json!({
+                "type": "azure_search",
+                "parameters": {
+                    "endpoint": "https://search.example.net",
+                    "index_name": "docs",
+                    "authentication": {"type": "api_key", "key": "search-key"},
+                    "fields_mapping": {"content_fields": ["content"], "title_field": "title"},
+                    "query_type": "vector_semantic_hybrid",
+                    "semantic_configuration": "default",
+                    "embedding_dependency": {"type": "deployment_name", "deployment_name": "ada"},
+                    "top_n_documents": 5,
+                    "in_scope": true,
+                    "strictness": 3,
+                    "include_contexts": ["citations", "intent"]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_cosmos_db_serialization() {
+        let source = DataSource::cosmos_db()
+            .database_name("db")
+            .container_name("items")
+            .index_name("vectors")
+            .connection_string("mongodb+srv://secret")
+            .fields_mapping(FieldsMapping {
+                content_fields: Some(vec!["text".into()]),
+                vector_fields: Some(vec!["embedding".into()]),
+                ..Default::default()
+            })
+            .embedding_dependency(EmbeddingDependency::endpoint(
+                "https://aoai.example.net/embeddings",
+                DataSourceAuth::access_token("token"),
+            ))
+            .build();
+
+        let json = serde_json::to_value(&source).unwrap();
+        assert_eq!(json["type"], "azure_cosmos_db");
+        assert_eq!(
+            json["parameters"]["authentication"],
+            json!({"type": "connection_string", "connection_string": "mongodb+srv://secret"})
+        );
+        assert_eq!(
+            json["parameters"]["embedding_dependency"],
+            json!({
+                "type": "endpoint",
+                "endpoint": "https://aoai.example.net/embeddings",
+                "authentication": {"type": "access_token", "access_token": "token"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_auth_serialization_and_redaction() {
+        assert_eq!(
+            serde_json::to_value(DataSourceAuth::SystemAssignedManagedIdentity).unwrap(),
+            json!({"type": "system_assigned_managed_identity"})
+        );
+        assert_eq!(
+            serde_json::to_value(DataSourceAuth::user_assigned_identity("/subscriptions/x"))
+                .unwrap(),
+            json!({
+                "type": "user_assigned_managed_identity",
+                "managed_identity_resource_id": "/subscriptions/x"
+            })
+        );
+
+        let debug = format!(
+            "{:?}",
+            DataSource::azure_search()
+                .endpoint("https://search.example.net")
+                .index_name("docs")
+                .authentication(DataSourceAuth::api_key("super-secret"))
+                .build()
+        );
+        assert!(!debug.contains("super-secret"));
+        assert!(debug.contains("ApiKey(****)"));
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        let search = || {
+            DataSource::azure_search()
+                .endpoint("https://search.example.net")
+                .index_name("docs")
+                .authentication(DataSourceAuth::SystemAssignedManagedIdentity)
+        };
+
+        let cases = [
+            (
+                DataSource::azure_search().index_name("docs").try_build(),
+                "endpoint is required",
+            ),
+            (
+                search().query_type(QueryType::Vector).try_build(),
+                "require an embedding_dependency",
+            ),
+            (
+                search().strictness(6).try_build(),
+                "strictness must be between 1 and 5",
+            ),
+            (
+                search().top_n_documents(0).try_build(),
+                "top_n_documents must be at least 1",
+            ),
+            (
+                search()
+                    .authentication(DataSourceAuth::connection_string("x"))
+                    .try_build(),
+                "does not support connection string",
+            ),
+            (
+                DataSource::cosmos_db()
+                    .database_name("db")
+                    .container_name("items")
+                    .index_name("vectors")
+                    .connection_string("x")
+                    .fields_mapping(FieldsMapping::default())
+                    .try_build(),
+                "content_fields and vector_fields",
+            ),
+        ];
+        for (result, expected) in cases {
+            let err = result.unwrap_err();
+            assert!(matches!(err, FoundryError::Builder(_)));
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_message_context_deserialization() {
+        let context: MessageContext = serde_json::from_value(json!({
+            "citations": [{
+                "content": "Hold the reset button for 10 seconds.",
+                "title": "Router manual",
+                "url": "https://docs.example.net/router",
+                "filepath": "router.md",
+                "chunk_id": "3"
+            }],
+            "intent": "[\"reset router\", \"router factory reset\"]",
+            "all_retrieved_documents": [{
+                "content": "Warranty terms.",
+                "title": "Warranty",
+                "search_queries": ["reset router"],
+                "data_source_index": 0,
+                "original_search_score": 2.5,
+                "filter_reason": "score"
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            context.citation(1).unwrap().title.as_deref(),
+            Some("Router manual")
+        );
+        assert!(context.citation(0).is_none());
+        assert_eq!(
+            context.intents(),
+            vec!["reset router", "router factory reset"]
+        );
+        assert_eq!(
+            context.all_retrieved_documents[0].document.title.as_deref(),
+            Some("Warranty")
+        );
+        assert_eq!(
+            context.all_retrieved_documents[0].filter_reason.as_deref(),
+            Some("score")
+        );
+
+        let plain = MessageContext {
+            intent: Some("reset router".into()),
+            ..Default::default()
+        };
+        assert_eq!(plain.intents(), vec!["reset router"]);
+    }
+}
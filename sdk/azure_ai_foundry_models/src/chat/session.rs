@@ -0,0 +1,670 @@
+//! Multi-turn conversations with context-window management.
+//!
+//! A [`ChatSession`] holds a system prompt and the conversation history,
+//! including tool calls and results, and produces the messages to send on
+//! each turn. A [`ContextPolicy`] keeps that list within the model's
+//! context window by sliding a window over recent turns, dropping turns to
+//! a token budget, or summarizing older turns with a model call.
+//!
+//! History is trimmed by whole turns: a turn starts at a user message and
+//! includes the assistant replies, tool calls and tool results after it, so
+//! a tool result is never sent without its call.
+//!
+//! Sessions serialize to JSON for persistence. The token counter is not
+//! serialized and must be set again after loading.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_core::error::FoundryResult;
+//! use azure_ai_foundry_models::chat::session::{ChatSession, ContextPolicy};
+//! use azure_ai_foundry_models::chat::{self, Message};
+//!
+//! # async fn example(client: FoundryClient) -> FoundryResult<()> {
+//! let mut session = ChatSession::new("gpt-4o")
+//!     .with_system_prompt("You are a helpful assistant.")
+//!     .with_policy(ContextPolicy::SlidingWindow { max_turns: 10 });
+//!
+//! session.push(Message::user("What is the capital of France?"));
+//! let request = session.request_builder().temperature(0.2).build();
+//! let response = chat::complete(&client, &request).await?;
+//! session.push_response(&response)?;
+//!
+//! let saved = serde_json::to_string(&session)?;
+//! let session: ChatSession = serde_json::from_str(&saved)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ChatCompletionRequest, ChatCompletionRequestBuilder, ChatCompletionResponse, Message, Role,
+};
+use crate::model::ChatModel;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Instructions sent with older turns when they are summarized.
+pub const SUMMARY_PROMPT: &str =
+    "Summarize the conversation below for the assistant that will continue it. \
+Keep names, facts, decisions, open questions and user preferences. \
+Write plain prose in at most a few paragraphs.";
+
+/// Heading of the system message that carries the summary of older turns.
+const SUMMARY_HEADING: &str = "Summary of the earlier conversation:";
+
+// ---------------------------------------------------------------------------
+// Policy
+// ---------------------------------------------------------------------------
+
+/// How a [`ChatSession`] keeps its messages within the context window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContextPolicy {
+    /// Send the whole history.
+    #[default]
+    Unbounded,
+    /// Send only the most recent `max_turns` turns. The latest turn is
+    /// always sent, so `max_turns: 0` behaves like `max_turns: 1`.
+    SlidingWindow { max_turns: usize },
+    /// Drop the oldest turns until the messages fit in `max_tokens`. The
+    /// latest turn is always sent.
+    TokenBudget { max_tokens: usize },
+    /// When the messages exceed `max_tokens`, replace all but the most
+    /// recent `keep_turns` turns with a summary written by the model. With
+    /// `keep_turns: 0` the whole history is summarized, except for the
+    /// message being sent by [`ChatSession::send`].
+    ///
+    /// Summaries are made by [`ChatSession::compact`], which
+    /// [`ChatSession::send`] calls before each request.
+    Summarize {
+        max_tokens: usize,
+        keep_turns: usize,
+    },
+}
+
+/// A function that counts the tokens of one message.
+#[derive(Clone)]
+struct TokenCounter(Arc<dyn Fn(&Message) -> usize + Send + Sync>);
+
+impl fmt::Debug for TokenCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TokenCounter")
+    }
+}
+
+impl Default for TokenCounter {
+    fn default() -> Self {
+        Self(Arc::new(super::training::message_tokens))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Session
+// ---------------------------------------------------------------------------
+
+/// A conversation with a model.
+///
+/// Token counts are estimated at about four characters per token unless a
+/// counter is set with [`with_token_counter`](Self::with_token_counter).
+/// With the `tokenizer` feature, pass
+/// `|m| Encoding::for_model("gpt-4o").count_message(m)` for exact counts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatSession {
+    model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    system_prompt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(default)]
+    history: Vec<Message>,
+    #[serde(default)]
+    policy: ContextPolicy,
+    #[serde(skip)]
+    token_counter: TokenCounter,
+}
+
+impl ChatSession {
+    /// Create an empty session for `model`.
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            system_prompt: None,
+            summary: None,
+            history: Vec::new(),
+            policy: ContextPolicy::default(),
+            token_counter: TokenCounter::default(),
+        }
+    }
+
+    /// Set the system prompt sent at the start of every request.
+    pub fn with_system_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.system_prompt = Some(prompt.into());
+        self
+    }
+
+    /// Set the context-window policy.
+    pub fn with_policy(mut self, policy: ContextPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Count message tokens with `counter` instead of the estimate.
+    pub fn with_token_counter(
+        mut self,
+        counter: impl Fn(&Message) -> usize + Send + Sync + 'static,
+    ) -> Self {
+        self.token_counter = TokenCounter(Arc::new(counter));
+        self
+    }
+
+    /// The model requests are built for.
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// The system prompt.
+    pub fn system_prompt(&self) -> Option<&str> {
+        self.system_prompt.as_deref()
+    }
+
+    /// The summary of turns removed by [`compact`](Self::compact).
+    pub fn summary(&self) -> Option<&str> {
+        self.summary.as_deref()
+    }
+
+    /// The context-window policy.
+    pub fn policy(&self) -> ContextPolicy {
+        self.policy
+    }
+
+    /// The full history, excluding the system prompt and summary.
+    pub fn history(&self) -> &[Message] {
+        &self.history
+    }
+
+    /// Replace the system prompt.
+    pub fn set_system_prompt(&mut self, prompt: impl Into<String>) {
+        self.system_prompt = Some(prompt.into());
+    }
+
+    /// Append a message to the history.
+    pub fn push(&mut self, message: Message) {
+        self.history.push(message);
+    }
+
+    /// Append the result of a tool call.
+    pub fn push_tool_result(
+        &mut self,
+        tool_call_id: impl Into<String>,
+        content: impl Into<String>,
+    ) {
+        self.history.push(Message::tool(tool_call_id, content));
+    }
+
+    /// Append the first choice of `response`, including any tool calls.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FoundryError::Validation`] if the response has no choices.
+    pub fn push_response(&mut self, response: &ChatCompletionResponse) -> FoundryResult<()> {
+        let choice = response
+            .choices
+            .first()
+            .ok_or_else(|| FoundryError::validation("response has no choices"))?;
+        self.history.push(choice.message.clone());
+        Ok(())
+    }
+
+    /// Remove the history and summary, keeping the system prompt.
+    pub fn clear(&mut self) {
+        self.history.clear();
+        self.summary = None;
+    }
+
+    /// The messages to send on the next request, with the policy applied.
+    pub fn messages(&self) -> Vec<Message> {
+        let history = match self.policy {
+            ContextPolicy::Unbounded | ContextPolicy::Summarize { .. } => &self.history[..],
+            ContextPolicy::SlidingWindow { max_turns } => {
+                let starts = turn_starts(&self.history);
+                let first = starts
+                    .len()
+                    .checked_sub(max_turns.max(1))
+                    .map_or(0, |i| starts[i]);
+                &self.history[first..]
+            }
+            ContextPolicy::TokenBudget { max_tokens } => {
+                let mut total = self.count(&self.preamble()) + self.count(&self.history);
+                let mut first = 0;
+                for &start in turn_starts(&self.history).iter().skip(1) {
+                    if total <= max_tokens {
+                        break;
+                    }
+                    total -= self.count(&self.history[first..start]);
+                    first = start;
+                }
+                &self.history[first..]
+            }
+        };
+
+        let mut messages = self.preamble();
+        messages.extend_from_slice(history);
+        messages
+    }
+
+    /// A request builder for the session's model with
+    /// [`messages`](Self::messages) set.
+    pub fn request_builder(&self) -> ChatCompletionRequestBuilder {
+        ChatCompletionRequest::builder()
+            .model(&self.model)
+            .messages(self.messages())
+    }
+
+    /// Summarize older turns if the [`ContextPolicy::Summarize`] budget is
+    /// exceeded, returning whether the history changed.
+    ///
+    /// The oldest turns, and any previous summary, are sent to `model` with
+    /// [`SUMMARY_PROMPT`]. The reply becomes the new summary and those turns
+    /// are removed from the history. Other policies do nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns the model's error, or [`FoundryError::Validation`] if its
+    /// reply has no content. The session is unchanged on error.
+    pub async fn compact<M: ChatModel + ?Sized>(&mut self, model: &M) -> FoundryResult<bool> {
+        self.compact_before(model, self.history.len()).await
+    }
+
+    /// [`compact`](Self::compact), never summarizing messages from `end` on.
+    #[tracing::instrument(
+        name = "foundry::chat::session::compact",
+        skip(self, model),
+        fields(model = %self.model, summarized)
+    )]
+    async fn compact_before<M: ChatModel + ?Sized>(
+        &mut self,
+        model: &M,
+        end: usize,
+    ) -> FoundryResult<bool> {
+        let ContextPolicy::Summarize {
+            max_tokens,
+            keep_turns,
+        } = self.policy
+        else {
+            return Ok(false);
+        };
+        if self.count(&self.messages()) <= max_tokens {
+            return Ok(false);
+        }
+        let starts = turn_starts(&self.history);
+        let Some(split) = starts
+            .len()
+            .checked_sub(keep_turns)
+            .map(|i| {
+                starts
+                    .get(i)
+                    .copied()
+                    .unwrap_or(self.history.len())
+                    .min(end)
+            })
+            .filter(|&s| s > 0)
+        else {
+            return Ok(false);
+        };
+
+        let mut transcript = String::new();
+        if let Some(summary) = &self.summary {
+            transcript.push_str(&format!("{}\n{}\n\n", SUMMARY_HEADING, summary));
+        }
+        for message in &self.history[..split] {
+            if let Some(content) = message.content.as_deref().filter(|c| !c.is_empty()) {
+                transcript.push_str(&format!("{}: {}\n", role_label(&message.role), content));
+            }
+            for call in message.tool_calls.iter().flatten() {
+                transcript.push_str(&format!(
+                    "assistant called {}({})\n",
+                    call.function.name, call.function.arguments
+                ));
+            }
+        }
+
+        let request = ChatCompletionRequest::builder()
+            .model(&self.model)
+            .message(Message::system(SUMMARY_PROMPT))
+            .message(Message::user(transcript))
+            .try_build()?;
+        let response = model.complete(&request).await?;
+        let summary = response
+            .choices
+            .first()
+            .and_then(|c| c.message.content.as_deref())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| FoundryError::validation("summary response has no content"))?;
+
+        self.summary = Some(summary.to_string());
+        self.history.drain(..split);
+        tracing::Span::current().record("summarized", split);
+        Ok(true)
+    }
+
+    /// Send a user message and record the reply.
+    ///
+    /// Appends `content` as a user message, [`compact`](Self::compact)s the
+    /// history before it, sends [`messages`](Self::messages) to `model` and
+    /// appends the first choice. The new message is never summarized. If
+    /// anything fails, the history and summary are restored so the message
+    /// can be sent again.
+    ///
+    /// # Errors
+    ///
+    /// Returns the model's error, or an error from [`compact`](Self::compact)
+    /// or [`push_response`](Self::push_response).
+    pub async fn send<M: ChatModel + ?Sized>(
+        &mut self,
+        model: &M,
+        content: impl Into<String>,
+    ) -> FoundryResult<ChatCompletionResponse> {
+        let saved = (self.history.clone(), self.summary.clone());
+        let pending = self.history.len();
+        self.history.push(Message::user(content));
+        let result = async {
+            self.compact_before(model, pending).await?;
+            let request = self.request_builder().try_build()?;
+            let response = model.complete(&request).await?;
+            self.push_response(&response)?;
+            Ok(response)
+        }
+        .await;
+        if result.is_err() {
+            (self.history, self.summary) = saved;
+        }
+        result
+    }
+
+    /// System prompt and summary messages.
+    fn preamble(&self) -> Vec<Message> {
+        let mut messages = Vec::new();
+        if let Some(prompt) = &self.system_prompt {
+            messages.push(Message::system(prompt.clone()));
+        }
+        if let Some(summary) = &self.summary {
+            messages.push(Message::system(format!("{}\n{}", SUMMARY_HEADING, summary)));
+        }
+        messages
+    }
+
+    fn count(&self, messages: &[Message]) -> usize {
+        messages.iter().map(|m| (self.token_counter.0)(m)).sum()
+    }
+}
+
+/// Indexes where turns start: each user message, and the first message.
+fn turn_starts(history: &[Message]) -> Vec<usize> {
+    history
+        .iter()
+        .enumerate()
+        .filter(|(i, m)| *i == 0 || m.role == Role::User)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn role_label(role: &Role) -> &'static str {
+    match role {
+        Role::System => "system",
+        Role::User => "user",
+        Role::Assistant => "assistant",
+        Role::Tool => "tool result",
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat::{FunctionCall, ToolCall, ToolCallType};
+    use crate::model::fake::FakeChatModel;
+
+    fn contents(messages: &[Message]) -> Vec<&str> {
+        messages
+            .iter()
+            .map(|m| m.content.as_deref().unwrap_or_default())
+            .collect()
+    }
+
+    /// A session with three turns; the second calls a tool.
+    fn three_turns(policy: ContextPolicy) -> ChatSession {
+        let mut session = ChatSession::new("gpt-4o")
+            .with_system_prompt("Be brief.")
+            .with_policy(policy)
+            .with_token_counter(|m| {
+                m.content
+                    .as_deref()
+                    .map_or(1, |c| c.split_whitespace().count())
+            });
+        session.push(Message::user("one"));
+        session.push(Message::assistant("reply one"));
+        session.push(Message::user("two"));
+        session.push(Message {
+            tool_calls: Some(vec![ToolCall {
+                id: "call_1".into(),
+                call_type: ToolCallType::Function,
+                function: FunctionCall {
+                    name: "lookup".into(),
+                    arguments: "{}".into(),
+                },
+            }]),
+            ..Message::assistant("")
+        });
+        session.push_tool_result("call_1", "tool output");
+        session.push(Message::assistant("reply two"));
+        session.push(Message::user("three"));
+        session
+    }
+
+    #[test]
+    fn test_unbounded_sends_everything() {
+        let session = three_turns(ContextPolicy::Unbounded);
+        let messages = session.messages();
+        assert_eq!(messages.len(), 8);
+        assert_eq!(messages[0].role, Role::System);
+        assert_eq!(messages[0].content.as_deref(), Some("Be brief."));
+    }
+
+    #[test]
+    fn test_sliding_window_keeps_whole_turns() {
+        let session = three_turns(ContextPolicy::SlidingWindow { max_turns: 2 });
+        assert_eq!(
+            contents(&session.messages()),
+            vec!["Be brief.", "two", "", "tool output", "reply two", "three"]
+        );
+
+        let session = three_turns(ContextPolicy::SlidingWindow { max_turns: 5 });
+        assert_eq!(session.messages().len(), 8);
+    }
+
+    #[test]
+    fn test_token_budget_drops_oldest_turns() {
+        // Sizes in words: system 2, turn one 3, turn two 5, turn three 1.
+        let session = three_turns(ContextPolicy::TokenBudget { max_tokens: 9 });
+        assert_eq!(
+            contents(&session.messages()),
+            vec!["Be brief.", "two", "", "tool output", "reply two", "three"]
+        );
+
+        let session = three_turns(ContextPolicy::TokenBudget { max_tokens: 1 });
+        assert_eq!(contents(&session.messages()), vec!["Be brief.", "three"]);
+    }
+
+    #[tokio::test]
+    async fn test_summarize_replaces_old_turns() {
+        let model = FakeChatModel::new().reply("User counted to two.");
+        let mut session = three_turns(ContextPolicy::Summarize {
+            max_tokens: 5,
+            keep_turns: 1,
+        });
+
+        assert!(session.compact(&model).await.unwrap());
+        assert_eq!(session.summary(), Some("User counted to two."));
+        assert_eq!(contents(session.history()), vec!["three"]);
+        assert_eq!(
+            contents(&session.messages()),
+            vec![
+                "Be brief.",
+                "Summary of the earlier conversation:\nUser counted to two.",
+                "three"
+            ]
+        );
+
+        let request = &model.requests()[0];
+        assert_eq!(request.messages[0].content.as_deref(), Some(SUMMARY_PROMPT));
+        let transcript = request.messages[1].content.as_deref().unwrap();
+        assert!(transcript.contains("user: one\nassistant: reply one\nuser: two\n"));
+        assert!(transcript.contains("assistant called lookup({})\ntool result: tool output\n"));
+
+        // Only the kept turn is left, so there is nothing more to summarize.
+        assert!(!session.compact(&model).await.unwrap());
+        assert_eq!(model.call_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_summarize_keep_zero_turns_summarizes_everything() {
+        let model = FakeChatModel::new().reply("Everything so far.");
+        let mut session = three_turns(ContextPolicy::Summarize {
+            max_tokens: 5,
+            keep_turns: 0,
+        });
+
+        assert!(session.compact(&model).await.unwrap());
+        assert!(session.history().is_empty());
+        assert_eq!(session.summary(), Some("Everything so far."));
+        let transcript = model.requests()[0].messages[1].content.clone().unwrap();
+        assert!(transcript.ends_with("user: three\n"));
+    }
+
+    #[tokio::test]
+    async fn test_compact_failure_leaves_session_unchanged() {
+        let model = FakeChatModel::new().fail(500, "boom");
+        let mut session = three_turns(ContextPolicy::Summarize {
+            max_tokens: 5,
+            keep_turns: 1,
+        });
+
+        assert!(session.compact(&model).await.is_err());
+        assert_eq!(session.history().len(), 7);
+        assert!(session.summary().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_send_records_turns() {
+        let model = FakeChatModel::new().reply("Paris.").fail(503, "busy");
+        let mut session = ChatSession::new("gpt-4o").with_system_prompt("Be brief.");
+
+        let response = session.send(&model, "Capital of France?").await.unwrap();
+        assert_eq!(
+            response.choices[0].message.content.as_deref(),
+            Some("Paris.")
+        );
+        assert_eq!(
+            contents(session.history()),
+            vec!["Capital of France?", "Paris."]
+        );
+        assert_eq!(model.requests()[0].model, "gpt-4o");
+        assert_eq!(model.requests()[0].messages.len(), 2);
+
+        assert!(session.send(&model, "And Spain?").await.is_err());
+        assert_eq!(session.history().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_with_keep_zero_turns_sends_the_new_message() {
+        let model = FakeChatModel::new().reply("SUMMARY").reply("ANSWER");
+        let mut session = three_turns(ContextPolicy::Summarize {
+            max_tokens: 5,
+            keep_turns: 0,
+        });
+
+        session.send(&model, "four?").await.unwrap();
+        let transcript = model.requests()[0].messages[1].content.clone().unwrap();
+        assert!(transcript.ends_with("user: three\n"));
+        assert!(!transcript.contains("four?"));
+        assert_eq!(
+            contents(&model.requests()[1].messages),
+            vec![
+                "Be brief.",
+                "Summary of the earlier conversation:\nSUMMARY",
+                "four?"
+            ]
+        );
+        assert_eq!(contents(session.history()), vec!["four?", "ANSWER"]);
+    }
+
+    #[tokio::test]
+    async fn test_send_failure_after_compacting_restores_the_session() {
+        let model = FakeChatModel::new().reply("SUMMARY").fail(503, "busy");
+        let mut session = three_turns(ContextPolicy::Summarize {
+            max_tokens: 5,
+            keep_turns: 0,
+        });
+
+        assert!(session.send(&model, "four?").await.is_err());
+        assert_eq!(model.call_count(), 2);
+        assert!(session.summary().is_none());
+        assert_eq!(session.history().len(), 7);
+        assert_eq!(session.history()[6].content.as_deref(), Some("three"));
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let session = three_turns(ContextPolicy::SlidingWindow { max_turns: 2 });
+        let json = serde_json::to_value(&session).unwrap();
+        assert_eq!(json["model"], "gpt-4o");
+        assert_eq!(
+            json["policy"],
+            serde_json::json!({"type": "sliding_window", "max_turns": 2})
+        );
+        assert!(json.get("summary").is_none());
+
+        let restored: ChatSession = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.system_prompt(), Some("Be brief."));
+        assert_eq!(restored.policy(), session.policy());
+        assert_eq!(restored.history().len(), 7);
+        assert_eq!(
+            restored.history()[4].tool_call_id.as_deref(),
+            Some("call_1")
+        );
+        assert_eq!(
+            contents(&restored.messages()),
+            contents(&session.messages())
+        );
+    }
+
+    #[test]
+    fn test_push_response_requires_a_choice() {
+        let response: ChatCompletionResponse = serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": crate::test_utils::TEST_TIMESTAMP,
+            "model": "gpt-4o",
+            "choices": []
+        }))
+        .unwrap();
+
+        let mut session = ChatSession::new("gpt-4o");
+        assert!(session.push_response(&response).is_err());
+        session.clear();
+        assert!(session.history().is_empty());
+    }
+}
@@ -0,0 +1,655 @@
+//! Offline validation and token estimates for chat fine-tuning data.
+//!
+//! Checks chat-formatted JSONL training data against the same [`Message`]
+//! and [`Role`] types used for chat completions, so the rules stay in sync
+//! with what the chat API accepts. Nothing here touches the network.
+//!
+//! Token counts are estimates of roughly four characters per token plus a
+//! fixed per-message overhead; they are meant for budgeting, not billing.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use azure_ai_foundry_models::chat::training;
+//!
+//! let data = std::fs::read("training.jsonl").unwrap();
+//! let report = training::validate_jsonl(&data);
+//! for issue in &report.issues {
+//!     eprintln!("{}", issue);
+//! }
+//! println!(
+//!     "{} examples, ~{} tokens per epoch, ~${:.2} for 3 epochs",
+//!     report.examples.len(),
+//!     report.total_tokens(),
+//!     report.estimated_cost(25.0, 3),
+//! );
+//! ```
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Message, Role};
+
+/// Tokens added for each message's role and delimiters.
+pub const TOKENS_PER_MESSAGE: usize = 3;
+
+/// Tokens added once per example to prime the assistant reply.
+pub const TOKENS_PER_REPLY: usize = 3;
+
+/// Fields a training message may carry: those of [`Message`] plus `weight`.
+///
+/// [`Message::context`] is deliberately left out: it only appears in On Your
+/// Data responses and fine-tuning rejects it, so it is reported as unknown.
+const MESSAGE_FIELDS: &[&str] = &["role", "content", "tool_calls", "tool_call_id", "weight"];
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// One line of a chat-formatted training file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingExample {
+    /// The conversation to learn from.
+    pub messages: Vec<Message>,
+
+    /// Function tool definitions available in the conversation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<serde_json::Value>>,
+}
+
+/// The kind of problem found in an example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrainingIssueKind {
+    /// The line is not valid JSON or does not match the chat schema.
+    InvalidJson,
+    /// A message has a field the chat API does not accept.
+    UnknownField,
+    /// The example has no messages.
+    EmptyExample,
+    /// A message is missing required content.
+    MissingContent,
+    /// Messages are in an order the service rejects.
+    RoleOrder,
+    /// The example has no assistant message to learn from.
+    MissingAssistant,
+    /// Tool calls and tool responses do not match up.
+    ToolCallMismatch,
+    /// Tool call arguments are not valid JSON.
+    InvalidToolArguments,
+    /// A tool call names a function missing from `tools`.
+    UnknownTool,
+    /// A `weight` is not 0 or 1, or is set on a non-assistant message.
+    InvalidWeight,
+}
+
+/// A problem found in one training example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrainingIssue {
+    /// 1-based line number in the JSONL data.
+    pub line: usize,
+    /// 0-based index of the offending message, if the issue is message-specific.
+    pub message_index: Option<usize>,
+    /// What kind of problem this is.
+    pub kind: TrainingIssueKind,
+    /// Human-readable description.
+    pub description: String,
+}
+
+impl std::fmt::Display for TrainingIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.message_index {
+            Some(index) => write!(
+                f,
+                "line {}, message {}: {}",
+                self.line, index, self.description
+            ),
+            None => write!(f, "line {}: {}", self.line, self.description),
+        }
+    }
+}
+
+/// Token estimates for one training example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExampleStats {
+    /// 1-based line number in the JSONL data.
+    pub line: usize,
+    /// Number of messages.
+    pub messages: usize,
+    /// Estimated tokens in the whole example, including tool definitions.
+    pub tokens: usize,
+    /// Estimated tokens in assistant messages the model is trained on.
+    pub assistant_tokens: usize,
+}
+
+/// Result of validating a training file.
+#[derive(Debug, Clone, Default)]
+pub struct TrainingReport {
+    /// Statistics for every example that could be parsed, in file order.
+    pub examples: Vec<ExampleStats>,
+    /// Problems found, in file order.
+    pub issues: Vec<TrainingIssue>,
+}
+
+impl TrainingReport {
+    /// Whether no issues were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Estimated tokens processed per training epoch.
+    pub fn total_tokens(&self) -> usize {
+        self.examples.iter().map(|e| e.tokens).sum()
+    }
+
+    /// Estimated cost of one epoch at `price_per_million_tokens`.
+    pub fn estimated_cost_per_epoch(&self, price_per_million_tokens: f64) -> f64 {
+        self.total_tokens() as f64 * price_per_million_tokens / 1_000_000.0
+    }
+
+    /// Estimated cost of training for `epochs` epochs at `price_per_million_tokens`.
+    pub fn estimated_cost(&self, price_per_million_tokens: f64, epochs: u32) -> f64 {
+        self.estimated_cost_per_epoch(price_per_million_tokens) * f64::from(epochs)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+/// Validate chat-formatted JSONL training data and estimate its token usage.
+///
+/// Blank lines are skipped. Every other line is checked for:
+///
+/// - valid JSON matching [`TrainingExample`], with no message fields beyond
+///   those of [`Message`] (plus `weight`)
+/// - an optional leading system message, a user message first, and an
+///   assistant message last
+/// - non-empty content on system, user and tool messages, and content or
+///   tool calls on assistant messages
+/// - every assistant tool call answered by a tool message with its ID, valid
+///   JSON arguments, and a function declared in `tools` when tools are given
+/// - `weight` only on assistant messages, and only 0 or 1
+pub fn validate_jsonl(data: &[u8]) -> TrainingReport {
+    let mut report = TrainingReport::default();
+
+    for (n, line) in data.split(|&b| b == b'\n').enumerate() {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let line_number = n + 1;
+
+        let value: serde_json::Value = match serde_json::from_slice(line) {
+            Ok(value) => value,
+            Err(e) => {
+                report.issues.push(TrainingIssue {
+                    line: line_number,
+                    message_index: None,
+                    kind: TrainingIssueKind::InvalidJson,
+                    description: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        let mut issues = ExampleChecker {
+            line: line_number,
+            issues: &mut report.issues,
+        };
+        let weights = issues.check_raw_messages(&value);
+        let example: TrainingExample = match serde_json::from_value(value) {
+            Ok(example) => example,
+            Err(e) => {
+                issues.push(None, TrainingIssueKind::InvalidJson, e.to_string());
+                continue;
+            }
+        };
+        issues.check_example(&example);
+
+        report
+            .examples
+            .push(example_stats(line_number, &example, &weights));
+    }
+
+    report
+}
+
+/// Collects issues for one example.
+struct ExampleChecker<'a> {
+    line: usize,
+    issues: &'a mut Vec<TrainingIssue>,
+}
+
+impl ExampleChecker<'_> {
+    fn push(
+        &mut self,
+        index: Option<usize>,
+        kind: TrainingIssueKind,
+        description: impl Into<String>,
+    ) {
+        self.issues.push(TrainingIssue {
+            line: self.line,
+            message_index: index,
+            kind,
+            description: description.into(),
+        });
+    }
+
+    /// Check fields that [`Message`] would silently ignore and return each
+    /// message's training weight.
+    fn check_raw_messages(&mut self, value: &serde_json::Value) -> Vec<bool> {
+        let Some(messages) = value.get("messages").and_then(|m| m.as_array()) else {
+            return Vec::new();
+        };
+
+        let mut weights = Vec::with_capacity(messages.len());
+        for (i, message) in messages.iter().enumerate() {
+            let Some(fields) = message.as_object() else {
+                weights.push(true);
+                continue;
+            };
+            for key in fields.keys() {
+                if !MESSAGE_FIELDS.contains(&key.as_str()) {
+                    self.push(
+                        Some(i),
+                        TrainingIssueKind::UnknownField,
+                        format!("unknown message field `{}`", key),
+                    );
+                }
+            }
+
+            let weight = match fields.get("weight") {
+                None => true,
+                Some(weight) => {
+                    if fields.get("role").and_then(|r| r.as_str()) != Some("assistant") {
+                        self.push(
+                            Some(i),
+                            TrainingIssueKind::InvalidWeight,
+                            "weight is only allowed on assistant messages",
+                        );
+                    }
+                    match weight.as_u64() {
+                        Some(0) => false,
+                        Some(1) => true,
+                        _ => {
+                            self.push(
+                                Some(i),
+                                TrainingIssueKind::InvalidWeight,
+                                "weight must be 0 or 1",
+                            );
+                            true
+                        }
+                    }
+                }
+            };
+            weights.push(weight);
+        }
+        weights
+    }
+
+    fn check_example(&mut self, example: &TrainingExample) {
+        use TrainingIssueKind::*;
+
+        let messages = &example.messages;
+        if messages.is_empty() {
+            self.push(None, EmptyExample, "example has no messages");
+            return;
+        }
+
+        let tool_names: Option<HashSet<&str>> = example.tools.as_ref().map(|tools| {
+            tools
+                .iter()
+                .filter_map(|t| t.pointer("/function/name").and_then(|n| n.as_str()))
+                .collect()
+        });
+
+        // Tool call IDs issued by the latest assistant message and not yet answered.
+        let mut pending: Vec<&str> = Vec::new();
+
+        for (i, message) in messages.iter().enumerate() {
+            let has_content = message
+                .content
+                .as_deref()
+                .is_some_and(|c| !c.trim().is_empty());
+            let tool_calls = message.tool_calls.as_deref().unwrap_or_default();
+
+            if message.role != Role::Tool && !pending.is_empty() {
+                self.push(
+                    Some(i),
+                    ToolCallMismatch,
+                    format!("tool calls without a tool response: {}", pending.join(", ")),
+                );
+                pending.clear();
+            }
+
+            match message.role {
+                Role::System => {
+                    if i != 0 {
+                        self.push(
+                            Some(i),
+                            RoleOrder,
+                            "system message must be the first message",
+                        );
+                    }
+                    if !has_content {
+                        self.push(Some(i), MissingContent, "system message has no content");
+                    }
+                }
+                Role::User => {
+                    if !has_content {
+                        self.push(Some(i), MissingContent, "user message has no content");
+                    }
+                }
+                Role::Assistant => {
+                    if !has_content && tool_calls.is_empty() {
+                        self.push(
+                            Some(i),
+                            MissingContent,
+                            "assistant message needs content or tool_calls",
+                        );
+                    }
+                    for call in tool_calls {
+                        if serde_json::from_str::<serde_json::Value>(&call.function.arguments)
+                            .is_err()
+                        {
+                            self.push(
+                                Some(i),
+                                InvalidToolArguments,
+                                format!("arguments of tool call `{}` are not valid JSON", call.id),
+                            );
+                        }
+                        if let Some(ref names) = tool_names {
+                            if !names.contains(call.function.name.as_str()) {
+                                self.push(
+                                    Some(i),
+                                    UnknownTool,
+                                    format!(
+                                        "function `{}` is not declared in tools",
+                                        call.function.name
+                                    ),
+                                );
+                            }
+                        }
+                        pending.push(&call.id);
+                    }
+                }
+                Role::Tool => {
+                    match message.tool_call_id.as_deref() {
+                        None => self.push(
+                            Some(i),
+                            ToolCallMismatch,
+                            "tool message has no tool_call_id",
+                        ),
+                        Some(id) => match pending.iter().position(|p| *p == id) {
+                            Some(p) => {
+                                pending.remove(p);
+                            }
+                            None => self.push(
+                                Some(i),
+                                ToolCallMismatch,
+                                format!("tool message answers unknown tool call `{}`", id),
+                            ),
+                        },
+                    }
+                    if !has_content {
+                        self.push(Some(i), MissingContent, "tool message has no content");
+                    }
+                }
+            }
+        }
+
+        if !pending.is_empty() {
+            self.push(
+                None,
+                ToolCallMismatch,
+                format!("tool calls without a tool response: {}", pending.join(", ")),
+            );
+        }
+
+        if let Some(first) = messages.iter().position(|m| m.role != Role::System) {
+            if messages[first].role != Role::User {
+                self.push(
+                    Some(first),
+                    RoleOrder,
+                    "conversation must start with a user message",
+                );
+            }
+        }
+
+        if !messages.iter().any(|m| m.role == Role::Assistant) {
+            self.push(None, MissingAssistant, "example has no assistant message");
+        } else if messages.last().is_some_and(|m| m.role != Role::Assistant) {
+            self.push(
+                Some(messages.len() - 1),
+                RoleOrder,
+                "last message must be from the assistant",
+            );
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Token estimates
+// ---------------------------------------------------------------------------
+
+/// Approximate token count of `text` (about four characters per token).
+pub(crate) fn approx_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Estimated tokens of one message, including per-message overhead.
+pub(crate) fn message_tokens(message: &Message) -> usize {
+    let role = match message.role {
+        Role::System => "system",
+        Role::User => "user",
+        Role::Assistant => "assistant",
+        Role::Tool => "tool",
+    };
+    let calls: usize = message
+        .tool_calls
+        .iter()
+        .flatten()
+        .map(|c| approx_tokens(&c.function.name) + approx_tokens(&c.function.arguments))
+        .sum();
+    TOKENS_PER_MESSAGE
+        + approx_tokens(role)
+        + message.content.as_deref().map_or(0, approx_tokens)
+        + calls
+}
+
+fn example_stats(line: usize, example: &TrainingExample, weights: &[bool]) -> ExampleStats {
+    let tools = example
+        .tools
+        .as_ref()
+        .and_then(|t| serde_json::to_string(t).ok())
+        .map_or(0, |t| approx_tokens(&t));
+
+    let mut tokens = TOKENS_PER_REPLY + tools;
+    let mut assistant_tokens = 0;
+    for (i, message) in example.messages.iter().enumerate() {
+        let count = message_tokens(message);
+        tokens += count;
+        if message.role == Role::Assistant && weights.get(i).copied().unwrap_or(true) {
+            assistant_tokens += count;
+        }
+    }
+
+    ExampleStats {
+        line,
+        messages: example.messages.len(),
+        tokens,
+        assistant_tokens,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat::data_sources::MessageContext;
+
+    fn kinds(report: &TrainingReport) -> Vec<TrainingIssueKind> {
+        report.issues.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn test_message_fields_cover_message() {
+        let message = Message {
+            role: Role::Assistant,
+            content: Some("Hi".into()),
+            tool_calls: Some(Vec::new()),
+            tool_call_id: Some("call_1".into()),
+            context: Some(MessageContext {
+                intent: Some("[]".into()),
+                ..Default::default()
+            }),
+        };
+        // Fails to compile when `Message` gains a field: decide whether
+        // training data may carry it and update `MESSAGE_FIELDS`.
+        let Message {
+            role: _,
+            content: _,
+            tool_calls: _,
+            tool_call_id: _,
+            context: _,
+        } = &message;
+
+        let value = serde_json::to_value(&message).unwrap();
+        for key in value.as_object().unwrap().keys() {
+            assert!(
+                MESSAGE_FIELDS.contains(&key.as_str()) || key == "context",
+                "`{key}` is neither allowed nor deliberately excluded"
+            );
+        }
+    }
+
+    #[test]
+    fn test_valid_example_has_no_issues() {
+        let data = br#"{"messages":[{"role":"system","content":"Be brief."},{"role":"user","content":"Hi"},{"role":"assistant","content":"Hello!"}]}"#;
+        let report = validate_jsonl(data);
+        assert!(report.is_valid(), "{:?}", report.issues);
+        assert_eq!(report.examples.len(), 1);
+
+        let stats = report.examples[0];
+        assert_eq!(stats.messages, 3);
+        // 3 reply + (3 + 2 + 3) + (3 + 1 + 1) + (3 + 3 + 2)
+        assert_eq!(stats.tokens, 24);
+        assert_eq!(stats.assistant_tokens, 8);
+    }
+
+    #[test]
+    fn test_role_ordering() {
+        let data = concat!(
+            r#"{"messages":[{"role":"assistant","content":"Hi"},{"role":"user","content":"Hi"}]}"#,
+            "\n",
+            r#"{"messages":[{"role":"user","content":"Hi"},{"role":"system","content":"Late"},{"role":"assistant","content":"Ok"}]}"#,
+        );
+        let report = validate_jsonl(data.as_bytes());
+        assert_eq!(
+            kinds(&report),
+            [
+                TrainingIssueKind::RoleOrder,
+                TrainingIssueKind::RoleOrder,
+                TrainingIssueKind::RoleOrder
+            ]
+        );
+        assert_eq!(
+            report.issues[0].to_string(),
+            "line 1, message 0: conversation must start with a user message"
+        );
+        assert_eq!(report.issues[2].line, 2);
+        assert_eq!(report.issues[2].message_index, Some(1));
+    }
+
+    #[test]
+    fn test_tool_call_consistency() {
+        let data = concat!(
+            r#"{"messages":[{"role":"user","content":"Weather?"},"#,
+            r#"{"role":"assistant","content":null,"tool_calls":[{"id":"call_1","type":"function","function":{"name":"get_weather","arguments":"{\"city\":\"Oslo\"}"}},{"id":"call_2","type":"function","function":{"name":"get_time","arguments":"not json"}}]},"#,
+            r#"{"role":"tool","tool_call_id":"call_1","content":"Sunny"},"#,
+            r#"{"role":"tool","tool_call_id":"call_9","content":"?"},"#,
+            r#"{"role":"assistant","content":"It is sunny."}],"#,
+            r#""tools":[{"type":"function","function":{"name":"get_weather"}}]}"#,
+        );
+        let report = validate_jsonl(data.as_bytes());
+        assert_eq!(
+            kinds(&report),
+            [
+                TrainingIssueKind::InvalidToolArguments,
+                TrainingIssueKind::UnknownTool,
+                TrainingIssueKind::ToolCallMismatch,
+                TrainingIssueKind::ToolCallMismatch,
+            ]
+        );
+        assert!(report.issues[3].description.contains("call_2"));
+    }
+
+    #[test]
+    fn test_unknown_fields_and_weights() {
+        let data = br#"{"messages":[{"role":"user","content":"Hi","name":"bob","weight":1},{"role":"assistant","content":"Hello","weight":0},{"role":"user","content":"Again"},{"role":"assistant","content":"Hello again","weight":2}]}"#;
+        let report = validate_jsonl(data);
+        assert_eq!(
+            kinds(&report),
+            [
+                TrainingIssueKind::UnknownField,
+                TrainingIssueKind::InvalidWeight,
+                TrainingIssueKind::InvalidWeight,
+            ]
+        );
+        // The weight-0 assistant message is not trained on.
+        let stats = report.examples[0];
+        assert_eq!(stats.assistant_tokens, 3 + 3 + 3);
+    }
+
+    #[test]
+    fn test_invalid_lines_and_missing_content() {
+        let data = concat!(
+            "not json\n",
+            r#"{"messages":[{"role":"robot","content":"beep"}]}"#,
+            "\n\n",
+            r#"{"messages":[]}"#,
+            "\n",
+            r#"{"messages":[{"role":"user","content":" "},{"role":"assistant"}]}"#,
+        );
+        let report = validate_jsonl(data.as_bytes());
+        assert_eq!(
+            kinds(&report),
+            [
+                TrainingIssueKind::InvalidJson,
+                TrainingIssueKind::InvalidJson,
+                TrainingIssueKind::EmptyExample,
+                TrainingIssueKind::MissingContent,
+                TrainingIssueKind::MissingContent,
+            ]
+        );
+        assert_eq!(report.issues[2].line, 4);
+        assert_eq!(report.examples.len(), 2);
+    }
+
+    #[test]
+    fn test_cost_estimates() {
+        let report = TrainingReport {
+            examples: vec![
+                ExampleStats {
+                    line: 1,
+                    messages: 2,
+                    tokens: 400_000,
+                    assistant_tokens: 0,
+                },
+                ExampleStats {
+                    line: 2,
+                    messages: 2,
+                    tokens: 100_000,
+                    assistant_tokens: 0,
+                },
+            ],
+            issues: Vec::new(),
+        };
+        assert_eq!(report.total_tokens(), 500_000);
+        assert!((report.estimated_cost_per_epoch(2.0) - 1.0).abs() < 1e-9);
+        assert!((report.estimated_cost(2.0, 3) - 3.0).abs() < 1e-9);
+    }
+}
@@ -42,11 +42,29 @@
 //! # }
 //! ```
 
-use azure_ai_foundry_core::client::FoundryClient;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use azure_ai_foundry_core::client::{FoundryClient, RetryPolicy, MAX_BACKOFF};
 use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
 use azure_ai_foundry_core::models::Usage;
+use futures::{Stream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Maximum number of inputs in one embedding request.
+pub const MAX_EMBEDDING_INPUTS: usize = 2_048;
+
+/// Maximum tokens in a single embedding input.
+pub const MAX_EMBEDDING_INPUT_TOKENS: usize = 8_192;
+
+/// Maximum tokens across all inputs of one embedding request.
+pub const MAX_EMBEDDING_REQUEST_TOKENS: usize = 300_000;
+
 // ---------------------------------------------------------------------------
 // Request types
 // ---------------------------------------------------------------------------
@@ -89,6 +107,9 @@ impl Serialize for EmbeddingInput {
 }
 
 /// Encoding format for embeddings.
+///
+/// `Base64` roughly halves the response size; the SDK decodes it back into
+/// `f32` vectors, so [`EmbeddingData::embedding`] is the same either way.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum EncodingFormat {
@@ -101,7 +122,7 @@ pub enum EncodingFormat {
 // ---------------------------------------------------------------------------
 
 /// An embedding response.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmbeddingResponse {
     pub object: String,
     pub model: String,
@@ -114,16 +135,167 @@ pub struct EmbeddingResponse {
 }
 
 /// A single embedding in the response.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmbeddingData {
     /// The object type, always `"embedding"`.
     pub object: String,
     /// The zero-based index of this embedding in the response batch.
     pub index: u32,
     /// The embedding vector as a list of floating-point numbers.
+    ///
+    /// Responses requested with [`EncodingFormat::Base64`] are decoded
+    /// transparently from little-endian `f32` bytes.
+    #[serde(deserialize_with = "deserialize_embedding")]
     pub embedding: Vec<f32>,
 }
 
+impl EmbeddingData {
+    /// Return a copy of the embedding scaled to unit length.
+    ///
+    /// See [`normalize`].
+    pub fn normalized(&self) -> Vec<f32> {
+        normalize(&self.embedding)
+    }
+
+    /// Compute the cosine similarity with another embedding.
+    ///
+    /// See [`cosine_similarity`].
+    pub fn cosine_similarity(&self, other: &EmbeddingData) -> FoundryResult<f32> {
+        cosine_similarity(&self.embedding, &other.embedding)
+    }
+
+    /// Return a half-precision copy of the embedding.
+    pub fn to_f16(&self) -> Vec<half::f16> {
+        self.embedding
+            .iter()
+            .copied()
+            .map(half::f16::from_f32)
+            .collect()
+    }
+
+    /// Return an int8-quantized copy of the embedding.
+    ///
+    /// See [`QuantizedEmbedding`].
+    pub fn quantize_i8(&self) -> QuantizedEmbedding {
+        QuantizedEmbedding::from_f32(&self.embedding)
+    }
+}
+
+/// An int8 scalar-quantized embedding.
+///
+/// Uses symmetric quantization: each component is stored as
+/// `round(value / scale)` with `scale = max(|value|) / 127`, so the vector
+/// takes a quarter of the `f32` size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizedEmbedding {
+    /// The quantized components.
+    pub values: Vec<i8>,
+    /// The factor that maps a quantized component back to `f32`.
+    pub scale: f32,
+}
+
+impl QuantizedEmbedding {
+    /// Quantize an `f32` vector.
+    pub fn from_f32(vector: &[f32]) -> Self {
+        let max_abs = vector.iter().fold(0.0_f32, |max, v| max.max(v.abs()));
+        let scale = if max_abs > 0.0 { max_abs / 127.0 } else { 1.0 };
+        let values = vector
+            .iter()
+            .map(|v| (v / scale).round().clamp(-127.0, 127.0) as i8)
+            .collect();
+        Self { values, scale }
+    }
+
+    /// Reconstruct an approximate `f32` vector.
+    pub fn dequantize(&self) -> Vec<f32> {
+        self.values
+            .iter()
+            .map(|&v| f32::from(v) * self.scale)
+            .collect()
+    }
+}
+
+/// Return a copy of `vector` scaled to unit (L2) length.
+///
+/// A zero vector is returned unchanged.
+pub fn normalize(vector: &[f32]) -> Vec<f32> {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return vector.to_vec();
+    }
+    vector.iter().map(|v| v / norm).collect()
+}
+
+/// Compute the cosine similarity of two vectors, in `[-1.0, 1.0]`.
+///
+/// Returns `0.0` if either vector has zero length.
+///
+/// # Errors
+///
+/// Returns [`FoundryError::Validation`] if the vectors have different dimensions.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> FoundryResult<f32> {
+    if a.len() != b.len() {
+        return Err(FoundryError::validation(format!(
+            "cannot compare embeddings of different dimensions ({} and {})",
+            a.len(),
+            b.len()
+        )));
+    }
+
+    let (mut dot, mut norm_a, mut norm_b) = (0.0_f32, 0.0_f32, 0.0_f32);
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return Ok(0.0);
+    }
+    Ok(dot / (norm_a.sqrt() * norm_b.sqrt()))
+}
+
+/// Deserialize an embedding given either as a float array or as base64.
+fn deserialize_embedding<'de, D>(deserializer: D) -> Result<Vec<f32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawEmbedding {
+        Float(Vec<f32>),
+        Base64(String),
+    }
+
+    match RawEmbedding::deserialize(deserializer)? {
+        RawEmbedding::Float(values) => Ok(values),
+        RawEmbedding::Base64(encoded) => {
+            decode_base64_embedding(&encoded).map_err(|e| serde::de::Error::custom(e.to_string()))
+        }
+    }
+}
+
+/// Decode a base64 string of little-endian `f32` values.
+fn decode_base64_embedding(encoded: &str) -> FoundryResult<Vec<f32>> {
+    use base64::Engine;
+
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| FoundryError::validation(format!("invalid base64 embedding: {}", e)))?;
+
+    if bytes.len() % 4 != 0 {
+        return Err(FoundryError::validation(format!(
+            "base64 embedding length {} is not a multiple of 4 bytes",
+            bytes.len()
+        )));
+    }
+
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
 // ---------------------------------------------------------------------------
 // API functions
 // ---------------------------------------------------------------------------
@@ -312,9 +484,475 @@ impl EmbeddingRequestBuilder {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Batching
+// ---------------------------------------------------------------------------
+
+/// A function that counts the tokens of one input.
+#[derive(Clone)]
+struct TokenCounter(Arc<dyn Fn(&str) -> usize + Send + Sync>);
+
+impl fmt::Debug for TokenCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TokenCounter")
+    }
+}
+
+impl Default for TokenCounter {
+    fn default() -> Self {
+        Self(Arc::new(crate::chat::training::approx_tokens))
+    }
+}
+
+/// Embeds any number of inputs by splitting them across requests.
+///
+/// Inputs are grouped, in order, into requests that respect
+/// [`max_inputs_per_request`](EmbeddingBatcherBuilder::max_inputs_per_request)
+/// and [`max_tokens_per_request`](EmbeddingBatcherBuilder::max_tokens_per_request).
+/// At most [`max_concurrency`](EmbeddingBatcherBuilder::max_concurrency)
+/// requests are in flight at once. A request that fails with a retryable
+/// error is sent again on its own, after the client's own retries are
+/// exhausted. Embeddings are returned in input order.
+///
+/// By default, token counts are estimated at about four characters per
+/// token. The estimate undercounts code, non-English text and other dense
+/// input, so requests can still exceed the service's limits. To enforce the
+/// limits exactly, enable the `tokenizer` feature and pass an exact counter
+/// to [`token_counter`](EmbeddingBatcherBuilder::token_counter).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::embeddings::*;
+/// # async fn example(client: &FoundryClient, chunks: Vec<String>) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let batcher = EmbeddingBatcher::builder()
+///     .model("text-embedding-3-small")
+///     .max_concurrency(8)
+///     .build();
+///
+/// let result = batcher.embed_all(client, chunks).await?;
+/// println!(
+///     "{} embeddings from {} requests, {} tokens",
+///     result.embeddings.len(),
+///     result.requests,
+///     result.usage.total_tokens
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmbeddingBatcher {
+    model: String,
+    dimensions: Option<u32>,
+    encoding_format: Option<EncodingFormat>,
+    user: Option<String>,
+    max_inputs_per_request: usize,
+    max_tokens_per_request: usize,
+    max_concurrency: usize,
+    max_retries: u32,
+    retry_backoff: Duration,
+    token_counter: TokenCounter,
+}
+
+/// The combined result of an [`EmbeddingBatcher`] run.
+#[derive(Debug, Clone)]
+pub struct BatchedEmbeddings {
+    /// The model reported by the service.
+    pub model: String,
+    /// One embedding per input, in input order.
+    pub embeddings: Vec<Vec<f32>>,
+    /// Usage summed over all requests.
+    pub usage: Usage,
+    /// Number of requests the inputs were split into.
+    pub requests: usize,
+}
+
+/// A run of consecutive inputs sent as one request.
+struct InputChunk {
+    offset: usize,
+    texts: Vec<String>,
+}
+
+impl EmbeddingBatcher {
+    /// Create a new builder.
+    pub fn builder() -> EmbeddingBatcherBuilder {
+        EmbeddingBatcherBuilder {
+            model: None,
+            dimensions: None,
+            encoding_format: None,
+            user: None,
+            max_inputs_per_request: MAX_EMBEDDING_INPUTS,
+            max_tokens_per_request: MAX_EMBEDDING_REQUEST_TOKENS,
+            max_concurrency: 4,
+            max_retries: 2,
+            retry_backoff: Duration::from_secs(1),
+            token_counter: TokenCounter::default(),
+        }
+    }
+
+    /// Embed every input of an iterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if an input is empty, exceeds
+    /// [`MAX_EMBEDDING_INPUT_TOKENS`] or alone exceeds
+    /// `max_tokens_per_request`. Fails with the first error of any request
+    /// that is not retryable or is still failing after `max_retries`.
+    pub async fn embed_all<I>(
+        &self,
+        client: &FoundryClient,
+        inputs: I,
+    ) -> FoundryResult<BatchedEmbeddings>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.embed_stream(client, futures::stream::iter(inputs))
+            .await
+    }
+
+    /// Embed every input of a stream.
+    ///
+    /// Requests are sent as soon as enough inputs have arrived to fill them,
+    /// so the stream does not need to be collected first.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`embed_all`](Self::embed_all).
+    ///
+    /// # Tracing
+    ///
+    /// Emits a span named `foundry::embeddings::embed_batched` with fields
+    /// `model`, `requests` and `prompt_tokens`.
+    #[tracing::instrument(
+        name = "foundry::embeddings::embed_batched",
+        skip(self, client, inputs),
+        fields(model = %self.model, requests, prompt_tokens)
+    )]
+    pub async fn embed_stream<S>(
+        &self,
+        client: &FoundryClient,
+        inputs: S,
+    ) -> FoundryResult<BatchedEmbeddings>
+    where
+        S: Stream,
+        S::Item: Into<String>,
+    {
+        let initial = BatchedEmbeddings {
+            model: self.model.clone(),
+            embeddings: Vec::new(),
+            usage: Usage {
+                prompt_tokens: 0,
+                completion_tokens: None,
+                total_tokens: 0,
+            },
+            requests: 0,
+        };
+
+        let result = self
+            .chunk_inputs(inputs)
+            .map(|chunk| async move { self.embed_chunk(client, chunk?).await })
+            .buffered(self.max_concurrency)
+            .try_fold(initial, |mut acc, response| async move {
+                acc.model = response.model;
+                acc.embeddings
+                    .extend(response.data.into_iter().map(|d| d.embedding));
+                acc.usage.prompt_tokens += response.usage.prompt_tokens;
+                acc.usage.total_tokens += response.usage.total_tokens;
+                acc.requests += 1;
+                Ok(acc)
+            })
+            .await?;
+
+        let span = tracing::Span::current();
+        span.record("requests", result.requests);
+        span.record("prompt_tokens", result.usage.prompt_tokens);
+
+        Ok(result)
+    }
+
+    /// Group inputs into request-sized chunks as they arrive.
+    fn chunk_inputs<'a, S>(
+        &'a self,
+        inputs: S,
+    ) -> impl Stream<Item = FoundryResult<InputChunk>> + 'a
+    where
+        S: Stream + 'a,
+        S::Item: Into<String>,
+    {
+        let state = (Box::pin(inputs), None::<(String, usize)>, 0_usize, false);
+        futures::stream::unfold(
+            state,
+            move |(mut inputs, mut carry, offset, done)| async move {
+                if done {
+                    return None;
+                }
+
+                let mut texts = Vec::new();
+                let mut tokens = 0;
+                if let Some((text, count)) = carry.take() {
+                    texts.push(text);
+                    tokens = count;
+                }
+
+                while texts.len() < self.max_inputs_per_request {
+                    let Some(text) = inputs.next().await else {
+                        break;
+                    };
+                    let text: String = text.into();
+                    let index = offset + texts.len();
+
+                    let count = (self.token_counter.0)(&text);
+                    let invalid = if text.is_empty() {
+                        Some(format!("input {} is empty", index))
+                    } else if count > MAX_EMBEDDING_INPUT_TOKENS {
+                        Some(format!(
+                            "input {} has about {} tokens, more than the {} allowed per input",
+                            index, count, MAX_EMBEDDING_INPUT_TOKENS
+                        ))
+                    } else if count > self.max_tokens_per_request {
+                        Some(format!(
+                            "input {} has about {} tokens, more than the {} allowed per request",
+                            index, count, self.max_tokens_per_request
+                        ))
+                    } else {
+                        None
+                    };
+                    if let Some(message) = invalid {
+                        let e = FoundryError::validation_field("input", message);
+                        return Some((Err(e), (inputs, None, offset, true)));
+                    }
+
+                    if !texts.is_empty() && tokens + count > self.max_tokens_per_request {
+                        carry = Some((text, count));
+                        break;
+                    }
+                    texts.push(text);
+                    tokens += count;
+                }
+
+                if texts.is_empty() {
+                    return None;
+                }
+                let next_offset = offset + texts.len();
+                Some((
+                    Ok(InputChunk { offset, texts }),
+                    (inputs, carry, next_offset, false),
+                ))
+            },
+        )
+    }
+
+    /// Send one chunk, retrying it on retryable errors.
+    async fn embed_chunk(
+        &self,
+        client: &FoundryClient,
+        chunk: InputChunk,
+    ) -> FoundryResult<EmbeddingResponse> {
+        let count = chunk.texts.len();
+        let request = EmbeddingRequest {
+            model: self.model.clone(),
+            input: EmbeddingInput::Multiple(chunk.texts),
+            dimensions: self.dimensions,
+            encoding_format: self.encoding_format,
+            user: self.user.clone(),
+        };
+
+        let mut attempt = 0;
+        let mut response = loop {
+            match embed(client, &request).await {
+                Ok(response) => break response,
+                Err(e) if e.is_retryable() && attempt < self.max_retries => {
+                    tracing::warn!(
+                        offset = chunk.offset,
+                        inputs = count,
+                        attempt,
+                        error = %e,
+                        "embedding sub-batch failed, will retry"
+                    );
+                    let backoff = self
+                        .retry_backoff
+                        .saturating_mul(2_u32.saturating_pow(attempt))
+                        .min(MAX_BACKOFF);
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        if response.data.len() != count {
+            return Err(FoundryError::Api {
+                code: "invalid_response".into(),
+                message: format!(
+                    "expected {} embeddings for inputs {}..{}, got {}",
+                    count,
+                    chunk.offset,
+                    chunk.offset + count,
+                    response.data.len()
+                ),
+            });
+        }
+        response.data.sort_by_key(|d| d.index);
+        Ok(response)
+    }
+}
+
+/// Builder for [`EmbeddingBatcher`].
+#[derive(Debug)]
+pub struct EmbeddingBatcherBuilder {
+    model: Option<String>,
+    dimensions: Option<u32>,
+    encoding_format: Option<EncodingFormat>,
+    user: Option<String>,
+    max_inputs_per_request: usize,
+    max_tokens_per_request: usize,
+    max_concurrency: usize,
+    max_retries: u32,
+    retry_backoff: Duration,
+    token_counter: TokenCounter,
+}
+
+impl EmbeddingBatcherBuilder {
+    /// Set the model ID to use for embedding generation.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Set the number of dimensions for the output embeddings.
+    pub fn dimensions(mut self, dimensions: u32) -> Self {
+        self.dimensions = Some(dimensions);
+        self
+    }
+
+    /// Set the encoding format used on the wire.
+    pub fn encoding_format(mut self, format: EncodingFormat) -> Self {
+        self.encoding_format = Some(format);
+        self
+    }
+
+    /// Set a unique identifier for the end-user.
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Set the maximum number of inputs per request.
+    ///
+    /// Defaults to [`MAX_EMBEDDING_INPUTS`].
+    pub fn max_inputs_per_request(mut self, max: usize) -> Self {
+        self.max_inputs_per_request = max;
+        self
+    }
+
+    /// Set the maximum total tokens per request.
+    ///
+    /// Defaults to [`MAX_EMBEDDING_REQUEST_TOKENS`].
+    pub fn max_tokens_per_request(mut self, max: usize) -> Self {
+        self.max_tokens_per_request = max;
+        self
+    }
+
+    /// Set the maximum number of requests in flight at once. Defaults to 4.
+    pub fn max_concurrency(mut self, max: usize) -> Self {
+        self.max_concurrency = max;
+        self
+    }
+
+    /// Set how many times a failed request is retried. Defaults to 2.
+    ///
+    /// At most [`RetryPolicy::MAX_ALLOWED_RETRIES`].
+    pub fn max_retries(mut self, max: u32) -> Self {
+        self.max_retries = max;
+        self
+    }
+
+    /// Set the delay before the first retry of a request, doubled on each
+    /// further attempt up to [`MAX_BACKOFF`]. Defaults to one second.
+    pub fn retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Set the function used to count the tokens of an input.
+    ///
+    /// The default is a rough estimate; see [`EmbeddingBatcher`]. With the
+    /// `tokenizer` feature, pass an exact counter such as
+    /// `move |text| encoding.count(text)`.
+    pub fn token_counter(
+        mut self,
+        counter: impl Fn(&str) -> usize + Send + Sync + 'static,
+    ) -> Self {
+        self.token_counter = TokenCounter(Arc::new(counter));
+        self
+    }
+
+    /// Build the batcher, returning an error if required fields are missing
+    /// or limits are invalid.
+    pub fn try_build(self) -> FoundryResult<EmbeddingBatcher> {
+        let model = self
+            .model
+            .ok_or_else(|| FoundryError::Builder("model is required".into()))?;
+
+        if self.dimensions == Some(0) {
+            return Err(FoundryError::Builder(
+                "dimensions must be greater than 0".into(),
+            ));
+        }
+        if !(1..=MAX_EMBEDDING_INPUTS).contains(&self.max_inputs_per_request) {
+            return Err(FoundryError::Builder(format!(
+                "max_inputs_per_request must be between 1 and {}",
+                MAX_EMBEDDING_INPUTS
+            )));
+        }
+        if self.max_tokens_per_request == 0 {
+            return Err(FoundryError::Builder(
+                "max_tokens_per_request must be at least 1".into(),
+            ));
+        }
+        if self.max_concurrency == 0 {
+            return Err(FoundryError::Builder(
+                "max_concurrency must be at least 1".into(),
+            ));
+        }
+        if self.max_retries > RetryPolicy::MAX_ALLOWED_RETRIES {
+            return Err(FoundryError::Builder(format!(
+                "max_retries must be at most {}",
+                RetryPolicy::MAX_ALLOWED_RETRIES
+            )));
+        }
+
+        Ok(EmbeddingBatcher {
+            model,
+            dimensions: self.dimensions,
+            encoding_format: self.encoding_format,
+            user: self.user,
+            max_inputs_per_request: self.max_inputs_per_request,
+            max_tokens_per_request: self.max_tokens_per_request,
+            max_concurrency: self.max_concurrency,
+            max_retries: self.max_retries,
+            retry_backoff: self.retry_backoff,
+            token_counter: self.token_counter,
+        })
+    }
+
+    /// Build the batcher.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `model` is not set or a limit is invalid.
+    /// Use [`try_build`](Self::try_build) for fallible construction.
+    pub fn build(self) -> EmbeddingBatcher {
+        self.try_build().expect("builder validation failed")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use azure_ai_foundry_core::auth::FoundryCredential;
     use tracing_test::traced_test;
 
     // --- Ciclo 1: Builder with required fields only ---
@@ -475,9 +1113,9 @@ mod tests {
 
     // --- Ciclo 8: Basic embed() function ---
 
-    use crate::test_utils::setup_mock_client;
+    use crate::test_utils::{setup_mock_client, TEST_API_KEY};
     use wiremock::matchers::{body_json, header, method, path};
-    use wiremock::{Mock, MockServer, ResponseTemplate};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
 
     #[tokio::test]
     async fn test_embed_single_input_success() {
@@ -891,4 +1529,335 @@ mod tests {
         assert_eq!(data.index, 0);
         assert_eq!(data.embedding.len(), 3);
     }
+
+    // --- Base64 decoding and vector helpers ---
+
+    fn encode_f32(values: &[f32]) -> String {
+        use base64::Engine;
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    }
+
+    fn data(embedding: Vec<f32>) -> EmbeddingData {
+        EmbeddingData {
+            object: "embedding".into(),
+            index: 0,
+            embedding,
+        }
+    }
+
+    #[test]
+    fn test_embedding_data_decodes_base64() {
+        let json = serde_json::json!({
+            "object": "embedding",
+            "index": 0,
+            "embedding": encode_f32(&[0.5, -1.25, 3.0])
+        });
+        let data: EmbeddingData = serde_json::from_value(json).unwrap();
+        assert_eq!(data.embedding, vec![0.5, -1.25, 3.0]);
+    }
+
+    #[test]
+    fn test_embedding_data_rejects_invalid_base64() {
+        let json = r#"{"object": "embedding", "index": 0, "embedding": "not base64!"}"#;
+        let err = serde_json::from_str::<EmbeddingData>(json).unwrap_err();
+        assert!(err.to_string().contains("invalid base64 embedding"));
+    }
+
+    #[test]
+    fn test_embedding_data_rejects_truncated_base64() {
+        // 3 bytes cannot hold a whole f32.
+        let json = r#"{"object": "embedding", "index": 0, "embedding": "AAAA"}"#;
+        let err = serde_json::from_str::<EmbeddingData>(json).unwrap_err();
+        assert!(err.to_string().contains("multiple of 4"));
+    }
+
+    #[tokio::test]
+    async fn test_embed_with_base64_encoding_decodes_vectors() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/embeddings"))
+            .and(body_json(serde_json::json!({
+                "model": "text-embedding-3-small",
+                "input": "Hello",
+                "encoding_format": "base64"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "model": "text-embedding-3-small",
+                "data": [{"object": "embedding", "index": 0, "embedding": encode_f32(&[0.1, 0.2])}],
+                "usage": {"prompt_tokens": 1, "total_tokens": 1}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = EmbeddingRequest::builder()
+            .model("text-embedding-3-small")
+            .input("Hello")
+            .encoding_format(EncodingFormat::Base64)
+            .build();
+
+        let response = embed(&client, &request).await.expect("should succeed");
+        assert_eq!(response.data[0].embedding, vec![0.1, 0.2]);
+    }
+
+    #[test]
+    fn test_normalize_produces_unit_vector() {
+        let normalized = normalize(&[3.0, 4.0]);
+        assert_eq!(normalized, vec![0.6, 0.8]);
+        assert_eq!(normalize(&[0.0, 0.0]), vec![0.0, 0.0]);
+        assert_eq!(data(vec![0.0, 2.0]).normalized(), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_cosine_similarity() {
+        let a = data(vec![1.0, 0.0]);
+        let b = data(vec![0.0, 1.0]);
+        let c = data(vec![2.0, 0.0]);
+        assert_eq!(a.cosine_similarity(&b).unwrap(), 0.0);
+        assert!((a.cosine_similarity(&c).unwrap() - 1.0).abs() < 1e-6);
+        assert!((cosine_similarity(&[1.0, 0.0], &[-1.0, 0.0]).unwrap() + 1.0).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_cosine_similarity_rejects_dimension_mismatch() {
+        let err = cosine_similarity(&[1.0], &[1.0, 2.0]).unwrap_err();
+        assert!(matches!(err, FoundryError::Validation { .. }));
+    }
+
+    #[test]
+    fn test_to_f16_copies_values() {
+        let halves = data(vec![0.5, -2.0]).to_f16();
+        assert_eq!(
+            halves,
+            vec![half::f16::from_f32(0.5), half::f16::from_f32(-2.0)]
+        );
+    }
+
+    #[test]
+    fn test_quantize_i8_round_trip() {
+        let quantized = data(vec![1.27, -0.635, 0.0]).quantize_i8();
+        assert_eq!(quantized.values, vec![127, -64, 0]);
+        let restored = quantized.dequantize();
+        assert!((restored[0] - 1.27).abs() < 1e-6);
+        assert!((restored[1] + 0.635).abs() < 0.01);
+        assert_eq!(restored[2], 0.0);
+    }
+
+    #[test]
+    fn test_quantize_zero_vector() {
+        let quantized = QuantizedEmbedding::from_f32(&[0.0, 0.0]);
+        assert_eq!(quantized.values, vec![0, 0]);
+        assert_eq!(quantized.dequantize(), vec![0.0, 0.0]);
+    }
+
+    // --- EmbeddingBatcher ---
+
+    /// Answer with one `[text length]` embedding per input, in reverse order.
+    fn echo_lengths(request: &Request) -> ResponseTemplate {
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        let inputs = body["input"].as_array().unwrap();
+        let data: Vec<_> = inputs
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, text)| {
+                serde_json::json!({
+                    "object": "embedding",
+                    "index": i,
+                    "embedding": [text.as_str().unwrap().len() as f32]
+                })
+            })
+            .collect();
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "list",
+            "model": "text-embedding-3-small",
+            "data": data,
+            "usage": {"prompt_tokens": inputs.len(), "total_tokens": inputs.len()}
+        }))
+    }
+
+    async fn collect_chunks(
+        batcher: &EmbeddingBatcher,
+        inputs: Vec<&str>,
+    ) -> Vec<FoundryResult<Vec<String>>> {
+        batcher
+            .chunk_inputs(futures::stream::iter(inputs))
+            .map(|chunk| chunk.map(|c| c.texts))
+            .collect()
+            .await
+    }
+
+    #[test]
+    fn test_batcher_builder_validation() {
+        let err = EmbeddingBatcher::builder().try_build().unwrap_err();
+        assert!(err.to_string().contains("model is required"));
+
+        for (builder, field) in [
+            (
+                EmbeddingBatcher::builder().max_inputs_per_request(0),
+                "max_inputs_per_request",
+            ),
+            (
+                EmbeddingBatcher::builder().max_inputs_per_request(MAX_EMBEDDING_INPUTS + 1),
+                "max_inputs_per_request",
+            ),
+            (
+                EmbeddingBatcher::builder().max_tokens_per_request(0),
+                "max_tokens_per_request",
+            ),
+            (
+                EmbeddingBatcher::builder().max_concurrency(0),
+                "max_concurrency",
+            ),
+            (
+                EmbeddingBatcher::builder().max_retries(RetryPolicy::MAX_ALLOWED_RETRIES + 1),
+                "max_retries",
+            ),
+            (EmbeddingBatcher::builder().dimensions(0), "dimensions"),
+        ] {
+            let err = builder.model("m").try_build().unwrap_err();
+            assert!(err.to_string().contains(field), "{err}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chunks_respect_input_and_token_limits() {
+        let batcher = EmbeddingBatcher::builder()
+            .model("m")
+            .max_inputs_per_request(3)
+            .max_tokens_per_request(10)
+            .token_counter(|text| text.len())
+            .build();
+
+        let chunks = collect_chunks(&batcher, vec!["a", "b", "c", "d", "eeeeeeeee", "ff"]).await;
+        let chunks: Vec<Vec<String>> = chunks.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            chunks,
+            vec![vec!["a", "b", "c"], vec!["d", "eeeeeeeee"], vec!["ff"],]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chunks_reject_empty_and_oversized_inputs() {
+        let batcher = EmbeddingBatcher::builder()
+            .model("m")
+            .max_tokens_per_request(4)
+            .token_counter(|text| text.len())
+            .build();
+
+        let chunks = collect_chunks(&batcher, vec!["ok", ""]).await;
+        assert_eq!(chunks.len(), 1);
+        let err = chunks.into_iter().next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("input 1 is empty"), "{err}");
+
+        let chunks = collect_chunks(&batcher, vec!["ok", "much too long", "ok"]).await;
+        assert_eq!(chunks.len(), 1);
+        let err = chunks.into_iter().next().unwrap().unwrap_err();
+        assert!(
+            err.to_string().contains("input 1 has about 13 tokens"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chunks_reject_inputs_over_the_per_input_limit() {
+        let batcher = EmbeddingBatcher::builder()
+            .model("m")
+            .token_counter(|text| text.len())
+            .build();
+
+        let long = "x".repeat(MAX_EMBEDDING_INPUT_TOKENS + 1);
+        let chunks = collect_chunks(&batcher, vec!["ok", &long]).await;
+        assert_eq!(chunks.len(), 1);
+        let err = chunks.into_iter().next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("input 1"), "{err}");
+        assert!(err.to_string().contains("allowed per input"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_embed_all_returns_embeddings_in_input_order() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/embeddings"))
+            .respond_with(echo_lengths)
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let batcher = EmbeddingBatcher::builder()
+            .model("text-embedding-3-small")
+            .max_inputs_per_request(2)
+            .build();
+
+        let inputs = ["a", "bb", "ccc", "dddd", "eeeee"];
+        let result = batcher.embed_all(&client, inputs).await.unwrap();
+
+        let lengths: Vec<f32> = result.embeddings.iter().map(|e| e[0]).collect();
+        assert_eq!(lengths, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(result.requests, 3);
+        assert_eq!(result.usage.prompt_tokens, 5);
+        assert_eq!(result.usage.total_tokens, 5);
+        assert_eq!(result.model, "text-embedding-3-small");
+    }
+
+    #[tokio::test]
+    async fn test_embed_stream_retries_failed_sub_batch() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/embeddings"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("busy"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/embeddings"))
+            .respond_with(echo_lengths)
+            .mount(&server)
+            .await;
+
+        let client = FoundryClient::builder()
+            .endpoint(server.uri())
+            .credential(FoundryCredential::api_key(TEST_API_KEY))
+            .retry_policy(RetryPolicy::new(0, Duration::ZERO).unwrap())
+            .build()
+            .unwrap();
+        let batcher = EmbeddingBatcher::builder()
+            .model("text-embedding-3-small")
+            .max_inputs_per_request(1)
+            .max_concurrency(1)
+            .retry_backoff(Duration::ZERO)
+            .build();
+
+        let inputs = futures::stream::iter(vec!["a".to_string(), "bb".to_string()]);
+        let result = batcher.embed_stream(&client, inputs).await.unwrap();
+
+        assert_eq!(result.embeddings, vec![vec![1.0], vec![2.0]]);
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_embed_all_fails_on_non_retryable_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/embeddings"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": {"code": "invalid_request", "message": "bad input"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let batcher = EmbeddingBatcher::builder()
+            .model("text-embedding-3-small")
+            .build();
+
+        let err = batcher.embed_all(&client, ["a", "b"]).await.unwrap_err();
+        assert!(err.to_string().contains("bad input"), "{err}");
+    }
 }
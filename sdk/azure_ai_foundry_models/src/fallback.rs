@@ -0,0 +1,698 @@
+//! Fallback across model deployments.
+//!
+//! A [`FallbackPolicy`] holds an ordered list of model or deployment names.
+//! [`complete`], [`complete_stream`] and [`create_response`] send the request
+//! to the first one and, when it fails with an error class the policy falls
+//! through on (see [`FallbackTrigger`]), retry it on the next. Other errors
+//! are returned at once. The result is [`Served`], which records the model
+//! that answered and the ones skipped on the way.
+//!
+//! [`FallbackPolicy::run`] applies the same policy to any other call.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! use azure_ai_foundry_models::chat::{ChatCompletionRequest, Message};
+//! use azure_ai_foundry_models::fallback::{self, FallbackPolicy};
+//!
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let policy = FallbackPolicy::builder()
+//!     .models(["gpt-4o", "gpt-4o-eastus", "gpt-4o-mini"])
+//!     .build();
+//!
+//! let request = ChatCompletionRequest::builder()
+//!     .model("gpt-4o")
+//!     .message(Message::user("Hello!"))
+//!     .build();
+//!
+//! let served = fallback::complete(client, &request, &policy).await?;
+//! println!("answered by {}", served.model);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use azure_ai_foundry_core::client::FoundryClient;
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use futures::StreamExt;
+
+use crate::chat::{self, ChatCompletionRequest, ChatCompletionResponse};
+use crate::model::ChatStream;
+use crate::responses::{self, CreateResponseRequest, Response};
+
+// ---------------------------------------------------------------------------
+// Policy
+// ---------------------------------------------------------------------------
+
+/// A class of error that can make a [`FallbackPolicy`] try the next model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FallbackTrigger {
+    /// HTTP 429 or a rate-limit error code.
+    RateLimited,
+    /// HTTP 5xx or a server error code.
+    ServerError,
+    /// The prompt was rejected by the content filter.
+    ContentFilter,
+    /// The prompt does not fit in the model's context window.
+    ContextLengthExceeded,
+}
+
+impl FallbackTrigger {
+    /// All triggers, the default set of a [`FallbackPolicy`].
+    pub const ALL: [FallbackTrigger; 4] = [
+        Self::RateLimited,
+        Self::ServerError,
+        Self::ContentFilter,
+        Self::ContextLengthExceeded,
+    ];
+
+    /// Classify an error, or return `None` if it is not a fallback trigger.
+    ///
+    /// JSON error bodies surface as [`FoundryError::Api`] and are classified
+    /// by their `code`, which Azure sets to either the HTTP status or a
+    /// descriptive name.
+    pub fn classify(error: &FoundryError) -> Option<Self> {
+        match error {
+            FoundryError::Http { status, .. } => Self::from_status(*status),
+            FoundryError::Api { code, message } => {
+                if let Ok(status) = code.parse::<u16>() {
+                    return Self::from_status(status);
+                }
+                match code.to_ascii_lowercase().as_str() {
+                    "rate_limit_exceeded" | "ratelimitexceeded" | "too_many_requests" => {
+                        Some(Self::RateLimited)
+                    }
+                    "server_error"
+                    | "internal_server_error"
+                    | "internalservererror"
+                    | "service_unavailable"
+                    | "serviceunavailable"
+                    | "timeout" => Some(Self::ServerError),
+                    "content_filter"
+                    | "content_policy_violation"
+                    | "responsibleaipolicyviolation" => Some(Self::ContentFilter),
+                    "context_length_exceeded" => Some(Self::ContextLengthExceeded),
+                    _ if message.contains("maximum context length") => {
+                        Some(Self::ContextLengthExceeded)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn from_status(status: u16) -> Option<Self> {
+        match status {
+            429 => Some(Self::RateLimited),
+            500..=599 => Some(Self::ServerError),
+            _ => None,
+        }
+    }
+}
+
+/// An ordered list of models to try, and the errors that move on to the next.
+#[derive(Debug, Clone)]
+pub struct FallbackPolicy {
+    models: Vec<String>,
+    triggers: Vec<FallbackTrigger>,
+}
+
+impl FallbackPolicy {
+    /// Create a new builder.
+    pub fn builder() -> FallbackPolicyBuilder {
+        FallbackPolicyBuilder {
+            models: Vec::new(),
+            triggers: FallbackTrigger::ALL.to_vec(),
+        }
+    }
+
+    /// The models to try, in order.
+    pub fn models(&self) -> &[String] {
+        &self.models
+    }
+
+    /// The error classes that fall through to the next model.
+    pub fn triggers(&self) -> &[FallbackTrigger] {
+        &self.triggers
+    }
+
+    /// Return the trigger if `error` should fall through to the next model.
+    pub fn falls_through(&self, error: &FoundryError) -> Option<FallbackTrigger> {
+        FallbackTrigger::classify(error).filter(|t| self.triggers.contains(t))
+    }
+
+    /// Call `call` with each model in turn until one succeeds or fails with
+    /// an error that does not fall through.
+    ///
+    /// If every model fails, the last error is returned.
+    pub async fn run<T, F, Fut>(&self, mut call: F) -> FoundryResult<Served<T>>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = FoundryResult<T>>,
+    {
+        let mut skipped = Vec::new();
+        let mut models = self.models.iter().peekable();
+
+        while let Some(model) = models.next() {
+            let error = match call(model.clone()).await {
+                Ok(value) => return Ok(Served::new(value, model.clone(), skipped)),
+                Err(e) => e,
+            };
+            let trigger = match self.falls_through(&error) {
+                Some(trigger) if models.peek().is_some() => trigger,
+                _ => return Err(error),
+            };
+
+            tracing::warn!(model = %model, ?trigger, error = %error, "falling back to next model");
+            skipped.push(SkippedModel {
+                model: model.clone(),
+                trigger,
+                message: error.to_string(),
+            });
+        }
+
+        Err(FoundryError::validation("fallback policy has no models"))
+    }
+}
+
+/// Builder for [`FallbackPolicy`].
+#[derive(Debug)]
+pub struct FallbackPolicyBuilder {
+    models: Vec<String>,
+    triggers: Vec<FallbackTrigger>,
+}
+
+impl FallbackPolicyBuilder {
+    /// Add a model or deployment to try.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.models.push(model.into());
+        self
+    }
+
+    /// Add several models or deployments to try, in order.
+    pub fn models<I, S>(mut self, models: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.models.extend(models.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set the error classes that fall through to the next model.
+    ///
+    /// Defaults to [`FallbackTrigger::ALL`].
+    pub fn fall_through_on(mut self, triggers: impl IntoIterator<Item = FallbackTrigger>) -> Self {
+        self.triggers = triggers.into_iter().collect();
+        self
+    }
+
+    /// Build the policy, returning an error if no model was added.
+    pub fn try_build(self) -> FoundryResult<FallbackPolicy> {
+        if self.models.is_empty() {
+            return Err(FoundryError::Builder(
+                "at least one model is required".into(),
+            ));
+        }
+        if self.models.iter().any(|m| m.trim().is_empty()) {
+            return Err(FoundryError::Builder("model names cannot be empty".into()));
+        }
+
+        Ok(FallbackPolicy {
+            models: self.models,
+            triggers: self.triggers,
+        })
+    }
+
+    /// Build the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no model was added.
+    /// Use [`try_build`](Self::try_build) for fallible construction.
+    pub fn build(self) -> FallbackPolicy {
+        self.try_build().expect("builder validation failed")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Results
+// ---------------------------------------------------------------------------
+
+/// A result together with the model that produced it.
+#[derive(Debug)]
+pub struct Served<T> {
+    /// The result.
+    pub value: T,
+    /// The model or deployment that served the request.
+    pub model: String,
+    /// Models tried before it, in order.
+    pub skipped: Vec<SkippedModel>,
+}
+
+impl<T> Served<T> {
+    fn new(value: T, model: String, skipped: Vec<SkippedModel>) -> Self {
+        tracing::Span::current().record("served_by", model.as_str());
+        Self {
+            value,
+            model,
+            skipped,
+        }
+    }
+
+    /// Returns `true` if the first model did not serve the request.
+    pub fn fell_back(&self) -> bool {
+        !self.skipped.is_empty()
+    }
+}
+
+/// A model skipped by a [`FallbackPolicy`].
+#[derive(Debug, Clone)]
+pub struct SkippedModel {
+    /// The model or deployment name.
+    pub model: String,
+    /// Why it was skipped.
+    pub trigger: FallbackTrigger,
+    /// The error it failed with.
+    pub message: String,
+}
+
+// ---------------------------------------------------------------------------
+// API functions
+// ---------------------------------------------------------------------------
+
+/// Send a chat completion request, falling back across `policy`'s models.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::fallback::complete` with fields `models`
+/// and `served_by`.
+#[tracing::instrument(
+    name = "foundry::fallback::complete",
+    skip_all,
+    fields(models = policy.models.len(), served_by)
+)]
+pub async fn complete(
+    client: &FoundryClient,
+    request: &ChatCompletionRequest,
+    policy: &FallbackPolicy,
+) -> FoundryResult<Served<ChatCompletionResponse>> {
+    policy
+        .run(|model| async move {
+            let mut request = request.clone();
+            request.model = model;
+            chat::complete(client, &request).await
+        })
+        .await
+}
+
+/// Stream a chat completion, falling back across `policy`'s models.
+///
+/// A model also falls through if its stream opens but yields an error before
+/// the first chunk, which is how in-stream content filter errors arrive.
+/// Errors after the first chunk are passed to the caller.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::fallback::complete_stream` with fields
+/// `models` and `served_by`.
+#[tracing::instrument(
+    name = "foundry::fallback::complete_stream",
+    skip_all,
+    fields(models = policy.models.len(), served_by)
+)]
+pub async fn complete_stream(
+    client: &FoundryClient,
+    request: &ChatCompletionRequest,
+    policy: &FallbackPolicy,
+) -> FoundryResult<Served<ChatStream>> {
+    policy
+        .run(|model| async move {
+            let mut request = request.clone();
+            request.model = model;
+            let mut stream = chat::complete_stream(client, &request).await?.boxed();
+            match stream.next().await {
+                Some(Err(e)) => Err(e),
+                Some(Ok(first)) => Ok(futures::stream::iter([Ok(first)]).chain(stream).boxed()),
+                None => Ok(futures::stream::empty().boxed()),
+            }
+        })
+        .await
+}
+
+/// Create a response, falling back across `policy`'s models.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::fallback::create_response` with fields
+/// `models` and `served_by`.
+#[tracing::instrument(
+    name = "foundry::fallback::create_response",
+    skip_all,
+    fields(models = policy.models.len(), served_by)
+)]
+pub async fn create_response(
+    client: &FoundryClient,
+    request: &CreateResponseRequest,
+    policy: &FallbackPolicy,
+) -> FoundryResult<Served<Response>> {
+    policy
+        .run(|model| async move {
+            let mut request = request.clone();
+            request.model = model;
+            responses::create(client, &request).await
+        })
+        .await
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat::Message;
+    use crate::test_utils::{TEST_API_KEY, TEST_TIMESTAMP};
+    use azure_ai_foundry_core::auth::FoundryCredential;
+    use azure_ai_foundry_core::client::RetryPolicy;
+    use futures::TryStreamExt;
+    use std::time::Duration;
+    use tracing_test::traced_test;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const CHAT_PATH: &str = "/openai/v1/chat/completions";
+
+    /// A client that does not retry, so each model is called exactly once.
+    fn client_without_retries(server: &MockServer) -> FoundryClient {
+        FoundryClient::builder()
+            .endpoint(server.uri())
+            .credential(FoundryCredential::api_key(TEST_API_KEY))
+            .retry_policy(RetryPolicy::new(0, Duration::ZERO).unwrap())
+            .build()
+            .unwrap()
+    }
+
+    fn policy() -> FallbackPolicy {
+        FallbackPolicy::builder()
+            .models(["gpt-4o", "gpt-4o-mini"])
+            .build()
+    }
+
+    fn chat_request() -> ChatCompletionRequest {
+        ChatCompletionRequest::builder()
+            .model("gpt-4o")
+            .message(Message::user("Hi"))
+            .build()
+    }
+
+    fn completion(model: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": TEST_TIMESTAMP,
+            "model": model,
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": format!("from {}", model)},
+                "finish_reason": "stop"
+            }]
+        })
+    }
+
+    fn api_error(code: &str) -> serde_json::Value {
+        serde_json::json!({"error": {"code": code, "message": "nope"}})
+    }
+
+    async fn mount_for_model(
+        server: &MockServer,
+        route: &str,
+        model: &str,
+        response: ResponseTemplate,
+        calls: u64,
+    ) {
+        Mock::given(method("POST"))
+            .and(path(route))
+            .and(body_partial_json(serde_json::json!({"model": model})))
+            .respond_with(response)
+            .expect(calls)
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn test_classify_errors() {
+        let cases = [
+            (
+                FoundryError::http(429, "slow"),
+                Some(FallbackTrigger::RateLimited),
+            ),
+            (
+                FoundryError::http(503, "down"),
+                Some(FallbackTrigger::ServerError),
+            ),
+            (FoundryError::http(400, "bad"), None),
+            (
+                FoundryError::Api {
+                    code: "429".into(),
+                    message: "Rate limit".into(),
+                },
+                Some(FallbackTrigger::RateLimited),
+            ),
+            (
+                FoundryError::Api {
+                    code: "content_filter".into(),
+                    message: "filtered".into(),
+                },
+                Some(FallbackTrigger::ContentFilter),
+            ),
+            (
+                FoundryError::Api {
+                    code: "invalid_request_error".into(),
+                    message: "This model's maximum context length is 128000 tokens".into(),
+                },
+                Some(FallbackTrigger::ContextLengthExceeded),
+            ),
+            (
+                FoundryError::Api {
+                    code: "invalid_request_error".into(),
+                    message: "bad parameter".into(),
+                },
+                None,
+            ),
+            (FoundryError::validation("bad"), None),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(FallbackTrigger::classify(&error), expected, "{error}");
+        }
+    }
+
+    #[test]
+    fn test_policy_builder_validation() {
+        let err = FallbackPolicy::builder().try_build().unwrap_err();
+        assert!(err.to_string().contains("at least one model"));
+
+        let err = FallbackPolicy::builder()
+            .model(" ")
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("cannot be empty"));
+
+        let policy = FallbackPolicy::builder()
+            .model("a")
+            .fall_through_on([FallbackTrigger::RateLimited])
+            .build();
+        assert_eq!(policy.triggers(), &[FallbackTrigger::RateLimited]);
+        assert!(policy
+            .falls_through(&FoundryError::http(500, "down"))
+            .is_none());
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_complete_falls_back_on_rate_limit() {
+        let server = MockServer::start().await;
+        mount_for_model(
+            &server,
+            CHAT_PATH,
+            "gpt-4o",
+            ResponseTemplate::new(429).set_body_json(api_error("429")),
+            1,
+        )
+        .await;
+        mount_for_model(
+            &server,
+            CHAT_PATH,
+            "gpt-4o-mini",
+            ResponseTemplate::new(200).set_body_json(completion("gpt-4o-mini")),
+            1,
+        )
+        .await;
+
+        let client = client_without_retries(&server);
+        let served = complete(&client, &chat_request(), &policy()).await.unwrap();
+
+        assert_eq!(served.model, "gpt-4o-mini");
+        assert!(served.fell_back());
+        assert_eq!(served.skipped[0].model, "gpt-4o");
+        assert_eq!(served.skipped[0].trigger, FallbackTrigger::RateLimited);
+        assert_eq!(
+            served.value.choices[0].message.content.as_deref(),
+            Some("from gpt-4o-mini")
+        );
+        assert!(logs_contain("foundry::fallback::complete"));
+        assert!(logs_contain("falling back to next model"));
+    }
+
+    #[tokio::test]
+    async fn test_complete_returns_non_trigger_errors_immediately() {
+        let server = MockServer::start().await;
+        mount_for_model(
+            &server,
+            CHAT_PATH,
+            "gpt-4o",
+            ResponseTemplate::new(400).set_body_json(api_error("invalid_request_error")),
+            1,
+        )
+        .await;
+        mount_for_model(
+            &server,
+            CHAT_PATH,
+            "gpt-4o-mini",
+            ResponseTemplate::new(200).set_body_json(completion("gpt-4o-mini")),
+            0,
+        )
+        .await;
+
+        let client = client_without_retries(&server);
+        let err = complete(&client, &chat_request(), &policy())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, FoundryError::Api { ref code, .. } if code == "invalid_request_error")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_returns_last_error_when_all_models_fail() {
+        let server = MockServer::start().await;
+        mount_for_model(
+            &server,
+            CHAT_PATH,
+            "gpt-4o",
+            ResponseTemplate::new(503).set_body_string("down"),
+            1,
+        )
+        .await;
+        mount_for_model(
+            &server,
+            CHAT_PATH,
+            "gpt-4o-mini",
+            ResponseTemplate::new(429).set_body_string("slow"),
+            1,
+        )
+        .await;
+
+        let client = client_without_retries(&server);
+        let err = complete(&client, &chat_request(), &policy())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FoundryError::Http { status: 429, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_complete_stream_falls_back_on_first_event_error() {
+        let server = MockServer::start().await;
+        let filtered =
+            "data: {\"error\":{\"code\":\"content_filter\",\"message\":\"filtered\"}}\n\n";
+        let ok = concat!(
+            "data: {\"id\":\"c1\",\"object\":\"chat.completion.chunk\",\"created\":1700000000,\"model\":\"gpt-4o-mini\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"Hi\"},\"finish_reason\":null}]}\n\n",
+            "data: [DONE]\n\n"
+        );
+        for (model, body) in [("gpt-4o", filtered), ("gpt-4o-mini", ok)] {
+            mount_for_model(
+                &server,
+                CHAT_PATH,
+                model,
+                ResponseTemplate::new(200)
+                    .set_body_string(body)
+                    .insert_header("content-type", "text/event-stream"),
+                1,
+            )
+            .await;
+        }
+
+        let client = client_without_retries(&server);
+        let served = complete_stream(&client, &chat_request(), &policy())
+            .await
+            .unwrap();
+        assert_eq!(served.model, "gpt-4o-mini");
+        assert_eq!(served.skipped[0].trigger, FallbackTrigger::ContentFilter);
+
+        let chunks: Vec<_> = served.value.try_collect().await.unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].choices[0].delta.content.as_deref(), Some("Hi"));
+    }
+
+    #[tokio::test]
+    async fn test_create_response_falls_back_on_server_error() {
+        let server = MockServer::start().await;
+        mount_for_model(
+            &server,
+            "/openai/v1/responses",
+            "gpt-4o",
+            ResponseTemplate::new(500).set_body_string("boom"),
+            1,
+        )
+        .await;
+        mount_for_model(
+            &server,
+            "/openai/v1/responses",
+            "gpt-4o-mini",
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "resp_1",
+                "object": "response",
+                "created_at": TEST_TIMESTAMP,
+                "status": "completed",
+                "model": "gpt-4o-mini",
+                "output": []
+            })),
+            1,
+        )
+        .await;
+
+        let client = client_without_retries(&server);
+        let request = CreateResponseRequest::builder()
+            .model("gpt-4o")
+            .input("Hi")
+            .build();
+        let served = create_response(&client, &request, &policy()).await.unwrap();
+
+        assert_eq!(served.model, "gpt-4o-mini");
+        assert_eq!(served.value.id, "resp_1");
+        assert_eq!(served.skipped[0].trigger, FallbackTrigger::ServerError);
+    }
+
+    #[tokio::test]
+    async fn test_run_applies_policy_to_any_call() {
+        let policy = FallbackPolicy::builder().models(["a", "b", "c"]).build();
+        let served = policy
+            .run(|model| async move {
+                if model == "c" {
+                    Ok(model.len())
+                } else {
+                    Err(FoundryError::http(503, "down"))
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(served.model, "c");
+        assert_eq!(served.value, 1);
+        assert_eq!(served.skipped.len(), 2);
+    }
+}
@@ -0,0 +1,1129 @@
+//! Fine-tuning types and functions for Azure AI Foundry Models.
+//!
+//! Fine-tuning trains a customized model from chat-formatted JSONL examples.
+//! This module validates training data locally, uploads it through the files
+//! endpoint with the `fine-tune` purpose, and manages fine-tuning jobs:
+//! create, get, list, cancel, and poll until finished. Job events and
+//! checkpoints are returned as streams that fetch further pages on demand.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_models::fine_tuning::{self, FineTuningJobRequest};
+//! # use futures::TryStreamExt;
+//! # use std::time::Duration;
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let data = std::fs::read("training.jsonl")?;
+//! let file = fine_tuning::upload_training_file(client, "training.jsonl", data).await?;
+//!
+//! let request = FineTuningJobRequest::builder()
+//!     .model("gpt-4o-mini-2024-07-18")
+//!     .training_file(&file.id)
+//!     .suffix("support-bot")
+//!     .build();
+//! let job = fine_tuning::create(client, &request).await?;
+//!
+//! let job = fine_tuning::poll_until_complete(client, &job.id, Duration::from_secs(30), None).await?;
+//! println!("Fine-tuned model: {:?}", job.fine_tuned_model);
+//!
+//! let events: Vec<_> = fine_tuning::list_events(client, &job.id).try_collect().await?;
+//! for event in events {
+//!     println!("{}", event.message);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use azure_ai_foundry_core::client::FoundryClient;
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::chat::training;
+
+pub use crate::chat::training::TrainingExample;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Minimum number of examples the service accepts in a training file.
+pub const MIN_TRAINING_EXAMPLES: usize = 10;
+
+/// Maximum size of a training or validation file (512 MB).
+pub const MAX_TRAINING_FILE_SIZE: usize = 512 * 1024 * 1024;
+
+/// Maximum length of a fine-tuned model name suffix.
+pub const MAX_SUFFIX_LENGTH: usize = 64;
+
+/// Number of items requested per page when streaming events and checkpoints.
+const PAGE_SIZE: u32 = 100;
+
+// ---------------------------------------------------------------------------
+// Training data
+// ---------------------------------------------------------------------------
+
+/// Summary of a training file that passed local validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrainingDataSummary {
+    /// Number of examples in the file.
+    pub examples: usize,
+    /// Total number of messages across all examples.
+    pub messages: usize,
+}
+
+/// Validate chat-formatted JSONL training data without contacting the service.
+///
+/// Runs [`training::validate_jsonl`] and fails on the first issue it
+/// reports. The file must also hold at least [`MIN_TRAINING_EXAMPLES`]
+/// examples. Use [`training::validate_jsonl`] directly to see every issue
+/// and token estimates.
+///
+/// # Errors
+///
+/// Returns a validation error describing the first offending line.
+pub fn validate_training_data(data: &[u8]) -> FoundryResult<TrainingDataSummary> {
+    let report = training::validate_jsonl(data);
+    if let Some(issue) = report.issues.first() {
+        return Err(FoundryError::validation(issue.to_string()));
+    }
+
+    let summary = TrainingDataSummary {
+        examples: report.examples.len(),
+        messages: report.examples.iter().map(|e| e.messages).sum(),
+    };
+    if summary.examples < MIN_TRAINING_EXAMPLES {
+        return Err(FoundryError::validation(format!(
+            "training data needs at least {} examples, found {}",
+            MIN_TRAINING_EXAMPLES, summary.examples
+        )));
+    }
+    Ok(summary)
+}
+
+// ---------------------------------------------------------------------------
+// Request types
+// ---------------------------------------------------------------------------
+
+/// A hyperparameter that is either chosen by the service or set explicitly.
+///
+/// Serialized as `"auto"` or as the value itself.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hyperparameter<T> {
+    /// Let the service choose the value.
+    Auto,
+    /// Use this value.
+    Value(T),
+}
+
+impl<T: Serialize> Serialize for Hyperparameter<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Auto => serializer.serialize_str("auto"),
+            Self::Value(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Hyperparameter<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr<T> {
+            Value(T),
+            Auto(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Value(value) => Ok(Self::Value(value)),
+            Repr::Auto(s) if s == "auto" => Ok(Self::Auto),
+            Repr::Auto(s) => Err(serde::de::Error::custom(format!(
+                "invalid hyperparameter value: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Training hyperparameters.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Hyperparameters {
+    /// Number of passes over the training data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n_epochs: Option<Hyperparameter<u32>>,
+
+    /// Number of examples per batch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_size: Option<Hyperparameter<u32>>,
+
+    /// Scaling factor for the learning rate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub learning_rate_multiplier: Option<Hyperparameter<f64>>,
+}
+
+/// A request to create a fine-tuning job.
+#[derive(Debug, Clone, Serialize)]
+pub struct FineTuningJobRequest {
+    /// The base model to fine-tune.
+    pub model: String,
+
+    /// ID of the uploaded training file.
+    pub training_file: String,
+
+    /// ID of the uploaded validation file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_file: Option<String>,
+
+    /// Suffix added to the fine-tuned model name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+
+    /// Seed for reproducible training.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i64>,
+
+    /// Training hyperparameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hyperparameters: Option<Hyperparameters>,
+}
+
+impl FineTuningJobRequest {
+    /// Create a new builder.
+    pub fn builder() -> FineTuningJobRequestBuilder {
+        FineTuningJobRequestBuilder {
+            model: None,
+            training_file: None,
+            validation_file: None,
+            suffix: None,
+            seed: None,
+            hyperparameters: None,
+        }
+    }
+}
+
+/// Builder for [`FineTuningJobRequest`].
+#[derive(Debug)]
+pub struct FineTuningJobRequestBuilder {
+    model: Option<String>,
+    training_file: Option<String>,
+    validation_file: Option<String>,
+    suffix: Option<String>,
+    seed: Option<i64>,
+    hyperparameters: Option<Hyperparameters>,
+}
+
+impl FineTuningJobRequestBuilder {
+    /// Set the base model to fine-tune.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Set the ID of the uploaded training file.
+    pub fn training_file(mut self, file_id: impl Into<String>) -> Self {
+        self.training_file = Some(file_id.into());
+        self
+    }
+
+    /// Set the ID of the uploaded validation file.
+    pub fn validation_file(mut self, file_id: impl Into<String>) -> Self {
+        self.validation_file = Some(file_id.into());
+        self
+    }
+
+    /// Set the suffix added to the fine-tuned model name (up to 64 characters).
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Set the training seed.
+    pub fn seed(mut self, seed: i64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Set the training hyperparameters.
+    pub fn hyperparameters(mut self, hyperparameters: Hyperparameters) -> Self {
+        self.hyperparameters = Some(hyperparameters);
+        self
+    }
+
+    /// Build the request, returning an error if required fields are missing or invalid.
+    pub fn try_build(self) -> FoundryResult<FineTuningJobRequest> {
+        let model = self
+            .model
+            .ok_or_else(|| FoundryError::Builder("model is required".into()))?;
+        let training_file = self
+            .training_file
+            .ok_or_else(|| FoundryError::Builder("training_file is required".into()))?;
+        if training_file.trim().is_empty() {
+            return Err(FoundryError::Builder(
+                "training_file cannot be empty".into(),
+            ));
+        }
+
+        if let Some(ref suffix) = self.suffix {
+            if suffix.trim().is_empty() || suffix.chars().count() > MAX_SUFFIX_LENGTH {
+                return Err(FoundryError::Builder(format!(
+                    "suffix must be between 1 and {} characters",
+                    MAX_SUFFIX_LENGTH
+                )));
+            }
+        }
+
+        if let Some(ref hp) = self.hyperparameters {
+            if matches!(hp.n_epochs, Some(Hyperparameter::Value(0))) {
+                return Err(FoundryError::Builder("n_epochs must be at least 1".into()));
+            }
+            if matches!(hp.batch_size, Some(Hyperparameter::Value(0))) {
+                return Err(FoundryError::Builder(
+                    "batch_size must be at least 1".into(),
+                ));
+            }
+            if let Some(Hyperparameter::Value(lr)) = hp.learning_rate_multiplier {
+                if !lr.is_finite() || lr <= 0.0 {
+                    return Err(FoundryError::Builder(
+                        "learning_rate_multiplier must be a positive number".into(),
+                    ));
+                }
+            }
+        }
+
+        Ok(FineTuningJobRequest {
+            model,
+            training_file,
+            validation_file: self.validation_file,
+            suffix: self.suffix,
+            seed: self.seed,
+            hyperparameters: self.hyperparameters,
+        })
+    }
+
+    /// Build the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `model` or `training_file` is not set, or a value is invalid.
+    /// Use [`try_build`](Self::try_build) for fallible construction.
+    pub fn build(self) -> FineTuningJobRequest {
+        self.try_build().expect("builder validation failed")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Response types
+// ---------------------------------------------------------------------------
+
+/// A file uploaded for fine-tuning.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrainingFile {
+    /// Unique identifier for the file.
+    pub id: String,
+    /// File size in bytes.
+    pub bytes: u64,
+    /// Unix timestamp when the file was created.
+    pub created_at: u64,
+    /// Original filename.
+    pub filename: String,
+    /// The purpose of the file (`fine-tune`).
+    pub purpose: String,
+    /// Processing status of the file.
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// Status of a fine-tuning job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FineTuningJobStatus {
+    /// The training and validation files are being checked.
+    ValidatingFiles,
+    /// Waiting for training capacity.
+    Queued,
+    /// The job is waiting to start.
+    Pending,
+    /// Training is in progress.
+    Running,
+    /// Training finished and the model is available.
+    Succeeded,
+    /// Training failed; see [`FineTuningJob::error`].
+    Failed,
+    /// The job was cancelled.
+    Cancelled,
+    /// A status not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+impl FineTuningJobStatus {
+    /// Whether the job has stopped changing.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// Why a fine-tuning job failed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FineTuningJobError {
+    /// Error code.
+    #[serde(default)]
+    pub code: Option<String>,
+    /// Human-readable message.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// The parameter that caused the failure.
+    #[serde(default)]
+    pub param: Option<String>,
+}
+
+/// A fine-tuning job.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FineTuningJob {
+    /// Unique identifier for the job.
+    pub id: String,
+    /// The base model being fine-tuned.
+    pub model: String,
+    /// Current status.
+    pub status: FineTuningJobStatus,
+    /// Unix timestamp when the job was created.
+    pub created_at: u64,
+    /// Unix timestamp when the job finished.
+    #[serde(default)]
+    pub finished_at: Option<u64>,
+    /// Estimated Unix timestamp when the job will finish.
+    #[serde(default)]
+    pub estimated_finish: Option<u64>,
+    /// Name of the resulting model, once training succeeded.
+    #[serde(default)]
+    pub fine_tuned_model: Option<String>,
+    /// ID of the training file.
+    pub training_file: String,
+    /// ID of the validation file.
+    #[serde(default)]
+    pub validation_file: Option<String>,
+    /// IDs of result files with training metrics.
+    #[serde(default)]
+    pub result_files: Vec<String>,
+    /// Number of billable tokens processed.
+    #[serde(default)]
+    pub trained_tokens: Option<u64>,
+    /// Hyperparameters used for the job.
+    #[serde(default)]
+    pub hyperparameters: Option<Hyperparameters>,
+    /// Suffix of the fine-tuned model name.
+    #[serde(default)]
+    pub suffix: Option<String>,
+    /// Training seed.
+    #[serde(default)]
+    pub seed: Option<i64>,
+    /// Why the job failed.
+    #[serde(default)]
+    pub error: Option<FineTuningJobError>,
+}
+
+/// A page of fine-tuning jobs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FineTuningJobList {
+    /// The jobs on this page.
+    pub data: Vec<FineTuningJob>,
+    /// Whether more jobs are available; pass the last job ID as `after`.
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// Severity of a fine-tuning job event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FineTuningEventLevel {
+    /// Informational.
+    Info,
+    /// Warning.
+    Warn,
+    /// Error.
+    Error,
+    /// A level not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A progress or status event of a fine-tuning job.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FineTuningJobEvent {
+    /// Unique identifier for the event.
+    pub id: String,
+    /// Unix timestamp when the event was created.
+    pub created_at: u64,
+    /// Severity.
+    pub level: FineTuningEventLevel,
+    /// Human-readable message.
+    pub message: String,
+    /// Event type (`message` or `metrics`).
+    #[serde(rename = "type", default)]
+    pub event_type: Option<String>,
+    /// Structured data, such as training metrics.
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+}
+
+/// Training metrics recorded at a checkpoint.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CheckpointMetrics {
+    /// Training step.
+    #[serde(default)]
+    pub step: Option<f64>,
+    /// Training loss.
+    #[serde(default)]
+    pub train_loss: Option<f64>,
+    /// Training mean token accuracy.
+    #[serde(default)]
+    pub train_mean_token_accuracy: Option<f64>,
+    /// Validation loss.
+    #[serde(default)]
+    pub valid_loss: Option<f64>,
+    /// Validation mean token accuracy.
+    #[serde(default)]
+    pub valid_mean_token_accuracy: Option<f64>,
+    /// Loss over the full validation set.
+    #[serde(default)]
+    pub full_valid_loss: Option<f64>,
+    /// Mean token accuracy over the full validation set.
+    #[serde(default)]
+    pub full_valid_mean_token_accuracy: Option<f64>,
+}
+
+/// A model checkpoint saved during fine-tuning.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FineTuningCheckpoint {
+    /// Unique identifier for the checkpoint.
+    pub id: String,
+    /// Unix timestamp when the checkpoint was created.
+    pub created_at: u64,
+    /// Name of the model at this checkpoint, usable for inference.
+    pub fine_tuned_model_checkpoint: String,
+    /// Training step of the checkpoint.
+    pub step_number: u64,
+    /// Metrics at this checkpoint.
+    #[serde(default)]
+    pub metrics: CheckpointMetrics,
+    /// ID of the job that created the checkpoint.
+    pub fine_tuning_job_id: String,
+}
+
+/// A page of a cursor-paginated list.
+#[derive(Deserialize)]
+struct Page<T> {
+    data: Vec<T>,
+    #[serde(default)]
+    has_more: bool,
+}
+
+// ---------------------------------------------------------------------------
+// API functions
+// ---------------------------------------------------------------------------
+
+/// Validate and upload a training or validation file.
+///
+/// The data is checked with [`validate_training_data`] before it is sent.
+///
+/// # Errors
+///
+/// Returns a validation error if the filename is empty, the data exceeds
+/// [`MAX_TRAINING_FILE_SIZE`], or the data fails local validation.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::fine_tuning::upload_training_file` with
+/// field `filename`.
+#[tracing::instrument(
+    name = "foundry::fine_tuning::upload_training_file",
+    skip(client, data),
+    fields(filename = %filename)
+)]
+pub async fn upload_training_file(
+    client: &FoundryClient,
+    filename: &str,
+    data: impl Into<bytes::Bytes>,
+) -> FoundryResult<TrainingFile> {
+    let data: bytes::Bytes = data.into();
+
+    if filename.trim().is_empty() {
+        return Err(FoundryError::validation_field(
+            "filename",
+            "filename cannot be empty",
+        ));
+    }
+    if data.len() > MAX_TRAINING_FILE_SIZE {
+        return Err(FoundryError::validation_field(
+            "data",
+            format!("file data exceeds the 512 MB limit ({} bytes)", data.len()),
+        ));
+    }
+    let summary = validate_training_data(&data)?;
+    tracing::debug!(
+        size_bytes = data.len(),
+        examples = summary.examples,
+        "uploading training file"
+    );
+
+    let filename = filename.to_string();
+    let response = client
+        .post_multipart("/openai/v1/files", move || {
+            let part = reqwest::multipart::Part::stream_with_length(
+                reqwest::Body::from(data.clone()),
+                data.len() as u64,
+            )
+            .file_name(filename.clone());
+            reqwest::multipart::Form::new()
+                .part("file", part)
+                .text("purpose", "fine-tune")
+        })
+        .await?;
+
+    let file = response.json::<TrainingFile>().await?;
+    tracing::debug!(file_id = %file.id, "training file uploaded");
+    Ok(file)
+}
+
+/// Create a fine-tuning job.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::fine_tuning::create` with fields `model` and
+/// `training_file`.
+#[tracing::instrument(
+    name = "foundry::fine_tuning::create",
+    skip(client, request),
+    fields(model = %request.model, training_file = %request.training_file)
+)]
+pub async fn create(
+    client: &FoundryClient,
+    request: &FineTuningJobRequest,
+) -> FoundryResult<FineTuningJob> {
+    tracing::debug!("creating fine-tuning job");
+    let response = client.post("/openai/v1/fine_tuning/jobs", request).await?;
+    let job = response.json::<FineTuningJob>().await?;
+    tracing::debug!(job_id = %job.id, "fine-tuning job created");
+    Ok(job)
+}
+
+/// Get a fine-tuning job by ID.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::fine_tuning::get` with field `job_id`.
+#[tracing::instrument(name = "foundry::fine_tuning::get", skip(client), fields(job_id = %job_id))]
+pub async fn get(client: &FoundryClient, job_id: &str) -> FoundryResult<FineTuningJob> {
+    tracing::debug!("getting fine-tuning job");
+    FoundryClient::validate_resource_id(job_id)?;
+    let response = client
+        .get(&format!("/openai/v1/fine_tuning/jobs/{}", job_id))
+        .await?;
+    let job = response.json::<FineTuningJob>().await?;
+    Ok(job)
+}
+
+/// List fine-tuning jobs, newest first.
+///
+/// Pass the ID of the last job on the previous page as `after` to get the
+/// next page.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::fine_tuning::list`.
+#[tracing::instrument(name = "foundry::fine_tuning::list", skip(client))]
+pub async fn list(
+    client: &FoundryClient,
+    after: Option<&str>,
+    limit: Option<u32>,
+) -> FoundryResult<FineTuningJobList> {
+    tracing::debug!("listing fine-tuning jobs");
+    let path = page_path("/openai/v1/fine_tuning/jobs", after, limit)?;
+    let response = client.get(&path).await?;
+    let list = response.json::<FineTuningJobList>().await?;
+    Ok(list)
+}
+
+/// Cancel a fine-tuning job.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::fine_tuning::cancel` with field `job_id`.
+#[tracing::instrument(name = "foundry::fine_tuning::cancel", skip(client), fields(job_id = %job_id))]
+pub async fn cancel(client: &FoundryClient, job_id: &str) -> FoundryResult<FineTuningJob> {
+    tracing::debug!("cancelling fine-tuning job");
+    FoundryClient::validate_resource_id(job_id)?;
+    let response = client
+        .post(
+            &format!("/openai/v1/fine_tuning/jobs/{}/cancel", job_id),
+            &serde_json::json!({}),
+        )
+        .await?;
+    let job = response.json::<FineTuningJob>().await?;
+    Ok(job)
+}
+
+/// Stream the events of a fine-tuning job, newest first.
+///
+/// Further pages are requested as the stream is consumed.
+pub fn list_events<'a>(
+    client: &'a FoundryClient,
+    job_id: &str,
+) -> impl Stream<Item = FoundryResult<FineTuningJobEvent>> + 'a {
+    paginate(client, job_id, "events", |event: &FineTuningJobEvent| {
+        event.id.clone()
+    })
+}
+
+/// Stream the checkpoints of a fine-tuning job, newest first.
+///
+/// Further pages are requested as the stream is consumed.
+pub fn list_checkpoints<'a>(
+    client: &'a FoundryClient,
+    job_id: &str,
+) -> impl Stream<Item = FoundryResult<FineTuningCheckpoint>> + 'a {
+    paginate(
+        client,
+        job_id,
+        "checkpoints",
+        |checkpoint: &FineTuningCheckpoint| checkpoint.id.clone(),
+    )
+}
+
+/// Poll a fine-tuning job until it succeeds, fails or is cancelled.
+///
+/// A status this SDK does not know ([`FineTuningJobStatus::Unknown`], such
+/// as Azure's `paused`) also ends polling, returning the job as is, since it
+/// may not change without intervention.
+///
+/// # Warning
+///
+/// Training can take hours. When `max_attempts` is `None` this polls until
+/// the job finishes; use a long `poll_interval`.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::fine_tuning::poll_until_complete` with field `job_id`.
+#[tracing::instrument(
+    name = "foundry::fine_tuning::poll_until_complete",
+    skip(client),
+    fields(job_id = %job_id)
+)]
+pub async fn poll_until_complete(
+    client: &FoundryClient,
+    job_id: &str,
+    poll_interval: std::time::Duration,
+    max_attempts: Option<u32>,
+) -> FoundryResult<FineTuningJob> {
+    let mut attempts: u32 = 0;
+    loop {
+        let job = get(client, job_id).await?;
+        if job.status.is_terminal() {
+            tracing::debug!(status = ?job.status, "fine-tuning job reached terminal state");
+            return Ok(job);
+        }
+        if job.status == FineTuningJobStatus::Unknown {
+            tracing::warn!("fine-tuning job has an unknown status, stopping polling");
+            return Ok(job);
+        }
+
+        attempts += 1;
+        if let Some(max) = max_attempts {
+            if attempts >= max {
+                return Err(FoundryError::validation(format!(
+                    "fine-tuning job did not complete after {} poll attempts",
+                    max
+                )));
+            }
+        }
+        tracing::trace!(status = ?job.status, attempt = attempts, "fine-tuning job still in progress");
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Append `after` and `limit` query parameters to a list path.
+fn page_path(base: &str, after: Option<&str>, limit: Option<u32>) -> FoundryResult<String> {
+    let mut query = Vec::new();
+    if let Some(after) = after {
+        FoundryClient::validate_resource_id(after)?;
+        query.push(format!("after={}", after));
+    }
+    if let Some(limit) = limit {
+        query.push(format!("limit={}", limit));
+    }
+    Ok(if query.is_empty() {
+        base.to_string()
+    } else {
+        format!("{}?{}", base, query.join("&"))
+    })
+}
+
+/// Stream every item of a job sub-collection, following the `after` cursor.
+fn paginate<'a, T>(
+    client: &'a FoundryClient,
+    job_id: &str,
+    collection: &'static str,
+    cursor: fn(&T) -> String,
+) -> impl Stream<Item = FoundryResult<T>> + 'a
+where
+    T: DeserializeOwned + 'a,
+{
+    let base = format!("/openai/v1/fine_tuning/jobs/{}/{}", job_id, collection);
+    let job_id = job_id.to_string();
+
+    // State: `Some(after)` while pages remain, `None` once the last page was read.
+    let pages = stream::try_unfold(Some(None::<String>), move |state| {
+        let base = base.clone();
+        let job_id = job_id.clone();
+        async move {
+            let Some(after) = state else {
+                return Ok(None);
+            };
+            FoundryClient::validate_resource_id(&job_id)?;
+            let path = page_path(&base, after.as_deref(), Some(PAGE_SIZE))?;
+            tracing::debug!(path = %path, "fetching fine-tuning page");
+
+            let page = client.get(&path).await?.json::<Page<T>>().await?;
+            let next = match page.data.last() {
+                Some(last) if page.has_more => Some(Some(cursor(last))),
+                _ => None,
+            };
+            Ok::<_, FoundryError>(Some((page.data, next)))
+        }
+    });
+
+    pages
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{setup_mock_client, TEST_TIMESTAMP};
+    use futures::TryStreamExt;
+    use wiremock::matchers::{body_json, body_string_contains, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const EXAMPLE: &str = r#"{"messages":[{"role":"system","content":"Be brief."},{"role":"user","content":"Hi"},{"role":"assistant","content":"Hello!"}]}"#;
+
+    fn training_jsonl(examples: usize) -> String {
+        std::iter::repeat_n(EXAMPLE, examples)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn job_json(status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": "ftjob-abc",
+            "object": "fine_tuning.job",
+            "model": "gpt-4o-mini",
+            "status": status,
+            "created_at": TEST_TIMESTAMP,
+            "training_file": "file-train",
+            "fine_tuned_model": if status == "succeeded" { serde_json::json!("gpt-4o-mini.ft-abc") } else { serde_json::Value::Null },
+            "hyperparameters": {"n_epochs": "auto", "batch_size": 4, "learning_rate_multiplier": 0.5},
+            "result_files": []
+        })
+    }
+
+    #[test]
+    fn test_validate_training_data_counts_examples() {
+        let summary = validate_training_data(training_jsonl(10).as_bytes()).unwrap();
+        assert_eq!(summary.examples, 10);
+        assert_eq!(summary.messages, 30);
+    }
+
+    #[test]
+    fn test_validate_training_data_reports_bad_line() {
+        let mut data = training_jsonl(10);
+        data.push_str("\n{\"messages\":[{\"role\":\"user\",\"content\":\"Hi\"}]}");
+        let err = validate_training_data(data.as_bytes()).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("line 11: example has no assistant message"));
+
+        let mut data = training_jsonl(2);
+        data.push_str("\n{\"messages\":[{\"role\":\"robot\"}]}");
+        let err = validate_training_data(data.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("line 3"));
+    }
+
+    #[test]
+    fn test_validate_training_data_requires_minimum_examples() {
+        let err = validate_training_data(training_jsonl(3).as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("at least 10 examples, found 3"));
+    }
+
+    #[test]
+    fn test_hyperparameter_serde_round_trip() {
+        let hp = Hyperparameters {
+            n_epochs: Some(Hyperparameter::Auto),
+            batch_size: Some(Hyperparameter::Value(8)),
+            learning_rate_multiplier: None,
+        };
+        let json = serde_json::to_value(&hp).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"n_epochs": "auto", "batch_size": 8})
+        );
+        let back: Hyperparameters = serde_json::from_value(json).unwrap();
+        assert_eq!(back, hp);
+
+        let bad = serde_json::from_value::<Hyperparameter<u32>>(serde_json::json!("many"));
+        assert!(bad.is_err());
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        let err = FineTuningJobRequest::builder()
+            .model("gpt-4o-mini")
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("training_file is required"));
+
+        let err = FineTuningJobRequest::builder()
+            .model("gpt-4o-mini")
+            .training_file("file-train")
+            .suffix("x".repeat(65))
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("suffix"));
+
+        let err = FineTuningJobRequest::builder()
+            .model("gpt-4o-mini")
+            .training_file("file-train")
+            .hyperparameters(Hyperparameters {
+                n_epochs: Some(Hyperparameter::Value(0)),
+                ..Default::default()
+            })
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("n_epochs"));
+    }
+
+    #[tokio::test]
+    async fn test_upload_training_file_validates_locally() {
+        let server = MockServer::start().await;
+        let client = setup_mock_client(&server).await;
+
+        let err = upload_training_file(&client, "train.jsonl", training_jsonl(2))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("at least 10 examples"));
+    }
+
+    #[tokio::test]
+    async fn test_upload_training_file_and_create_job() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/files"))
+            .and(body_string_contains("fine-tune"))
+            .and(body_string_contains("train.jsonl"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "file-train", "object": "file", "bytes": 1000,
+                "created_at": TEST_TIMESTAMP, "filename": "train.jsonl",
+                "purpose": "fine-tune", "status": "processed"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/fine_tuning/jobs"))
+            .and(body_json(serde_json::json!({
+                "model": "gpt-4o-mini",
+                "training_file": "file-train",
+                "suffix": "support",
+                "hyperparameters": {"n_epochs": 3}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(job_json("validating_files")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let file = upload_training_file(&client, "train.jsonl", training_jsonl(10))
+            .await
+            .expect("should upload");
+        assert_eq!(file.purpose, "fine-tune");
+
+        let request = FineTuningJobRequest::builder()
+            .model("gpt-4o-mini")
+            .training_file(&file.id)
+            .suffix("support")
+            .hyperparameters(Hyperparameters {
+                n_epochs: Some(Hyperparameter::Value(3)),
+                ..Default::default()
+            })
+            .build();
+        let job = create(&client, &request).await.expect("should create");
+        assert_eq!(job.status, FineTuningJobStatus::ValidatingFiles);
+        let hp = job.hyperparameters.unwrap();
+        assert_eq!(hp.n_epochs, Some(Hyperparameter::Auto));
+        assert_eq!(hp.batch_size, Some(Hyperparameter::Value(4)));
+    }
+
+    #[tokio::test]
+    async fn test_list_and_cancel() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/fine_tuning/jobs"))
+            .and(query_param("limit", "5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list", "data": [job_json("running")], "has_more": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/fine_tuning/jobs/ftjob-abc/cancel"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(job_json("cancelled")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let page = list(&client, None, Some(5)).await.expect("should list");
+        assert!(page.has_more);
+        assert_eq!(page.data[0].status, FineTuningJobStatus::Running);
+
+        let job = cancel(&client, "ftjob-abc").await.expect("should cancel");
+        assert!(job.status.is_terminal());
+    }
+
+    #[tokio::test]
+    async fn test_list_events_follows_pages() {
+        let server = MockServer::start().await;
+
+        let event = |id: &str| {
+            serde_json::json!({
+                "id": id, "object": "fine_tuning.job.event", "created_at": TEST_TIMESTAMP,
+                "level": "info", "message": format!("event {}", id), "type": "message"
+            })
+        };
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/fine_tuning/jobs/ftjob-abc/events"))
+            .and(query_param("after", "ev-2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list", "data": [event("ev-3")], "has_more": false
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/fine_tuning/jobs/ftjob-abc/events"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list", "data": [event("ev-1"), event("ev-2")], "has_more": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let events: Vec<_> = list_events(&client, "ftjob-abc")
+            .try_collect()
+            .await
+            .expect("should stream events");
+        let ids: Vec<_> = events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["ev-1", "ev-2", "ev-3"]);
+        assert_eq!(events[0].level, FineTuningEventLevel::Info);
+    }
+
+    #[tokio::test]
+    async fn test_list_checkpoints_single_page() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/fine_tuning/jobs/ftjob-abc/checkpoints"))
+            .and(query_param("limit", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "data": [{
+                    "id": "ftckpt-1", "object": "fine_tuning.job.checkpoint",
+                    "created_at": TEST_TIMESTAMP,
+                    "fine_tuned_model_checkpoint": "gpt-4o-mini.ft-abc:ckpt-step-10",
+                    "step_number": 10,
+                    "metrics": {"step": 10.0, "train_loss": 0.42},
+                    "fine_tuning_job_id": "ftjob-abc"
+                }],
+                "has_more": false
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let checkpoints: Vec<_> = list_checkpoints(&client, "ftjob-abc")
+            .try_collect()
+            .await
+            .expect("should stream checkpoints");
+        assert_eq!(checkpoints.len(), 1);
+        assert_eq!(checkpoints[0].step_number, 10);
+        assert_eq!(checkpoints[0].metrics.train_loss, Some(0.42));
+    }
+
+    #[tokio::test]
+    async fn test_list_events_rejects_invalid_job_id() {
+        let server = MockServer::start().await;
+        let client = setup_mock_client(&server).await;
+
+        let result: FoundryResult<Vec<_>> = list_events(&client, "../jobs").try_collect().await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_complete_max_attempts() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/fine_tuning/jobs/ftjob-abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(job_json("running")))
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let err = poll_until_complete(
+            &client,
+            "ftjob-abc",
+            std::time::Duration::from_millis(1),
+            Some(2),
+        )
+        .await
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("did not complete after 2 poll attempts"));
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_complete_stops_on_unknown_status() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/fine_tuning/jobs/ftjob-abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(job_json("paused")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let job = poll_until_complete(
+            &client,
+            "ftjob-abc",
+            std::time::Duration::from_millis(1),
+            None,
+        )
+        .await
+        .expect("should stop");
+        assert_eq!(job.status, FineTuningJobStatus::Unknown);
+    }
+}
@@ -1,6 +1,7 @@
 //! Image generation and editing types and functions for Azure AI Foundry Models.
 //!
-//! This module provides image generation and image editing APIs.
+//! This module provides image generation, editing and variation APIs, streaming
+//! of partial images, and helpers to decode and save the results.
 //!
 //! # Image Generation Example
 //!
@@ -40,10 +41,43 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Saving Results
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_models::images::*;
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let request = ImageGenerationRequest::builder()
+//!     .model("gpt-image-1")
+//!     .prompt("A paper crane")
+//!     .background(ImageBackground::Transparent)
+//!     .output_format(ImageOutputFormat::Webp)
+//!     .build();
+//!
+//! let response = generate(client, &request).await?;
+//! // Writes crane_0.webp, crane_1.webp, ...
+//! let paths = response.save_all(client, "output", "crane").await?;
+//! println!("Saved {:?}", paths);
+//! # Ok(())
+//! # }
+//! ```
 
 use azure_ai_foundry_core::client::FoundryClient;
 use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use futures::Stream;
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Maximum number of input images for a single edit request.
+pub const MAX_EDIT_IMAGES: usize = 16;
+
+/// Maximum number of partial images streamed before the final image.
+pub const MAX_PARTIAL_IMAGES: u32 = 3;
 
 // ---------------------------------------------------------------------------
 // Enums
@@ -157,6 +191,133 @@ pub enum ImageOutputFormat {
     Webp,
 }
 
+impl ImageOutputFormat {
+    /// Return the string representation used by the API.
+    ///
+    /// This matches the serialized form used in multipart form fields.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+            Self::Webp => "webp",
+        }
+    }
+
+    /// Return the conventional file extension for this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Webp => "webp",
+        }
+    }
+}
+
+/// Background handling for gpt-image-1 output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageBackground {
+    /// Transparent background (PNG or WebP output only).
+    Transparent,
+    /// Opaque background.
+    Opaque,
+    /// Let the model decide.
+    Auto,
+}
+
+impl ImageBackground {
+    /// Return the string representation used by the API.
+    ///
+    /// This matches the serialized form used in multipart form fields.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Transparent => "transparent",
+            Self::Opaque => "opaque",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+/// Content moderation level for gpt-image-1 generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageModeration {
+    /// Default moderation.
+    Auto,
+    /// Less restrictive moderation.
+    Low,
+}
+
+/// An input image file for edit and variation requests.
+#[derive(Clone)]
+pub struct ImageFile {
+    /// The image data. Stored as `bytes::Bytes` for O(1) cloning (reference-counted).
+    pub data: bytes::Bytes,
+    /// The filename sent with the image.
+    pub filename: String,
+}
+
+impl ImageFile {
+    /// Create an image file from data and a filename.
+    pub fn new(data: impl Into<bytes::Bytes>, filename: impl Into<String>) -> Self {
+        Self {
+            data: data.into(),
+            filename: filename.into(),
+        }
+    }
+
+    /// Build a multipart part for this file.
+    fn to_part(&self) -> reqwest::multipart::Part {
+        reqwest::multipart::Part::stream_with_length(
+            reqwest::Body::from(self.data.clone()),
+            self.data.len() as u64,
+        )
+        .file_name(self.filename.clone())
+    }
+}
+
+impl std::fmt::Debug for ImageFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImageFile")
+            .field("data", &format!("<{} bytes>", self.data.len()))
+            .field("filename", &self.filename)
+            .finish()
+    }
+}
+
+/// Validate `output_compression` against the output format.
+fn validate_output_compression(
+    compression: Option<u8>,
+    format: Option<ImageOutputFormat>,
+) -> FoundryResult<()> {
+    if let Some(compression) = compression {
+        if compression > 100 {
+            return Err(FoundryError::Builder(
+                "output_compression must be between 0 and 100".into(),
+            ));
+        }
+        if format.is_none_or(|f| f == ImageOutputFormat::Png) {
+            return Err(FoundryError::Builder(
+                "output_compression requires jpeg or webp output_format".into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Validate that a transparent background is not combined with JPEG output.
+fn validate_background(
+    background: Option<ImageBackground>,
+    format: Option<ImageOutputFormat>,
+) -> FoundryResult<()> {
+    if background == Some(ImageBackground::Transparent) && format == Some(ImageOutputFormat::Jpeg) {
+        return Err(FoundryError::Builder(
+            "transparent background requires png or webp output_format".into(),
+        ));
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Image generation request
 // ---------------------------------------------------------------------------
@@ -188,6 +349,23 @@ pub struct ImageGenerationRequest {
     /// The output image format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_format: Option<ImageOutputFormat>,
+
+    /// Background handling (gpt-image-1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<ImageBackground>,
+
+    /// Content moderation level (gpt-image-1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<ImageModeration>,
+
+    /// Compression level (0-100) for JPEG and WebP output (gpt-image-1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_compression: Option<u8>,
+
+    /// Number of partial images (0-3) to stream before the final image.
+    /// Only sent by [`generate_stream`]; [`generate`] leaves it out.
+    #[serde(skip)]
+    pub partial_images: Option<u32>,
 }
 
 impl ImageGenerationRequest {
@@ -201,6 +379,10 @@ impl ImageGenerationRequest {
             quality: None,
             response_format: None,
             output_format: None,
+            background: None,
+            moderation: None,
+            output_compression: None,
+            partial_images: None,
         }
     }
 }
@@ -215,6 +397,10 @@ pub struct ImageGenerationRequestBuilder {
     quality: Option<ImageQuality>,
     response_format: Option<ImageResponseFormat>,
     output_format: Option<ImageOutputFormat>,
+    background: Option<ImageBackground>,
+    moderation: Option<ImageModeration>,
+    output_compression: Option<u8>,
+    partial_images: Option<u32>,
 }
 
 impl ImageGenerationRequestBuilder {
@@ -260,6 +446,30 @@ impl ImageGenerationRequestBuilder {
         self
     }
 
+    /// Set the background handling (gpt-image-1).
+    pub fn background(mut self, background: ImageBackground) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Set the content moderation level (gpt-image-1).
+    pub fn moderation(mut self, moderation: ImageModeration) -> Self {
+        self.moderation = Some(moderation);
+        self
+    }
+
+    /// Set the compression level (0-100) for JPEG and WebP output (gpt-image-1).
+    pub fn output_compression(mut self, compression: u8) -> Self {
+        self.output_compression = Some(compression);
+        self
+    }
+
+    /// Set the number of partial images (0-3) streamed by [`generate_stream`].
+    pub fn partial_images(mut self, count: u32) -> Self {
+        self.partial_images = Some(count);
+        self
+    }
+
     /// Build the request, returning an error if required fields are missing
     /// or parameter values are invalid.
     pub fn try_build(self) -> FoundryResult<ImageGenerationRequest> {
@@ -285,6 +495,18 @@ impl ImageGenerationRequestBuilder {
             }
         }
 
+        validate_output_compression(self.output_compression, self.output_format)?;
+        validate_background(self.background, self.output_format)?;
+
+        if let Some(partial) = self.partial_images {
+            if partial > MAX_PARTIAL_IMAGES {
+                return Err(FoundryError::Builder(format!(
+                    "partial_images must be between 0 and {}",
+                    MAX_PARTIAL_IMAGES
+                )));
+            }
+        }
+
         Ok(ImageGenerationRequest {
             model,
             prompt,
@@ -293,6 +515,10 @@ impl ImageGenerationRequestBuilder {
             quality: self.quality,
             response_format: self.response_format,
             output_format: self.output_format,
+            background: self.background,
+            moderation: self.moderation,
+            output_compression: self.output_compression,
+            partial_images: self.partial_images,
         })
     }
 
@@ -300,7 +526,8 @@ impl ImageGenerationRequestBuilder {
     ///
     /// # Panics
     ///
-    /// Panics if `model` or `prompt` is not set, or if `count` is out of range.
+    /// Panics if `model` or `prompt` is not set, or if `count`,
+    /// `output_compression` or `partial_images` is invalid.
     /// Use [`try_build`](Self::try_build) for fallible construction.
     pub fn build(self) -> ImageGenerationRequest {
         self.try_build().expect("builder validation failed")
@@ -334,6 +561,15 @@ pub struct ImageEditRequest {
     pub quality: Option<ImageQuality>,
     /// The format in which the images are returned.
     pub response_format: Option<ImageResponseFormat>,
+    /// Further input images after `image` (gpt-image-1 accepts up to
+    /// [`MAX_EDIT_IMAGES`] in total).
+    pub additional_images: Vec<ImageFile>,
+    /// Background handling (gpt-image-1).
+    pub background: Option<ImageBackground>,
+    /// The output image format (gpt-image-1).
+    pub output_format: Option<ImageOutputFormat>,
+    /// Compression level (0-100) for JPEG and WebP output (gpt-image-1).
+    pub output_compression: Option<u8>,
 }
 
 impl ImageEditRequest {
@@ -350,6 +586,10 @@ impl ImageEditRequest {
             size: None,
             quality: None,
             response_format: None,
+            additional_images: Vec::new(),
+            background: None,
+            output_format: None,
+            output_compression: None,
         }
     }
 }
@@ -366,6 +606,10 @@ pub struct ImageEditRequestBuilder {
     size: Option<ImageSize>,
     quality: Option<ImageQuality>,
     response_format: Option<ImageResponseFormat>,
+    additional_images: Vec<ImageFile>,
+    background: Option<ImageBackground>,
+    output_format: Option<ImageOutputFormat>,
+    output_compression: Option<u8>,
 }
 
 impl std::fmt::Debug for ImageEditRequestBuilder {
@@ -387,6 +631,10 @@ impl std::fmt::Debug for ImageEditRequestBuilder {
             .field("size", &self.size)
             .field("quality", &self.quality)
             .field("response_format", &self.response_format)
+            .field("additional_images", &self.additional_images)
+            .field("background", &self.background)
+            .field("output_format", &self.output_format)
+            .field("output_compression", &self.output_compression)
             .finish()
     }
 }
@@ -405,6 +653,17 @@ impl ImageEditRequestBuilder {
         self
     }
 
+    /// Add another input image (gpt-image-1).
+    ///
+    /// If no image has been set yet, this sets the first image.
+    pub fn add_image(mut self, data: impl Into<bytes::Bytes>, filename: impl Into<String>) -> Self {
+        if self.image.is_none() {
+            return self.image(data, filename);
+        }
+        self.additional_images.push(ImageFile::new(data, filename));
+        self
+    }
+
     /// Set the text prompt describing the edit.
     pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
         self.prompt = Some(prompt.into());
@@ -442,6 +701,24 @@ impl ImageEditRequestBuilder {
         self
     }
 
+    /// Set the background handling (gpt-image-1).
+    pub fn background(mut self, background: ImageBackground) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Set the output image format (gpt-image-1).
+    pub fn output_format(mut self, format: ImageOutputFormat) -> Self {
+        self.output_format = Some(format);
+        self
+    }
+
+    /// Set the compression level (0-100) for JPEG and WebP output (gpt-image-1).
+    pub fn output_compression(mut self, compression: u8) -> Self {
+        self.output_compression = Some(compression);
+        self
+    }
+
     /// Build the request, returning an error if required fields are missing
     /// or parameter values are invalid.
     pub fn try_build(self) -> FoundryResult<ImageEditRequest> {
@@ -483,6 +760,25 @@ impl ImageEditRequestBuilder {
             }
         }
 
+        if 1 + self.additional_images.len() > MAX_EDIT_IMAGES {
+            return Err(FoundryError::Builder(format!(
+                "at most {} input images are allowed",
+                MAX_EDIT_IMAGES
+            )));
+        }
+        if self
+            .additional_images
+            .iter()
+            .any(|f| f.data.is_empty() || f.filename.trim().is_empty())
+        {
+            return Err(FoundryError::Builder(
+                "additional images need data and a filename".into(),
+            ));
+        }
+
+        validate_output_compression(self.output_compression, self.output_format)?;
+        validate_background(self.background, self.output_format)?;
+
         Ok(ImageEditRequest {
             model,
             image,
@@ -494,6 +790,10 @@ impl ImageEditRequestBuilder {
             size: self.size,
             quality: self.quality,
             response_format: self.response_format,
+            additional_images: self.additional_images,
+            background: self.background,
+            output_format: self.output_format,
+            output_compression: self.output_compression,
         })
     }
 
@@ -509,6 +809,129 @@ impl ImageEditRequestBuilder {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Image variation request
+// ---------------------------------------------------------------------------
+
+/// A request to create variations of an existing image (dall-e-2).
+#[derive(Debug, Clone)]
+pub struct ImageVariationRequest {
+    /// The model to use.
+    pub model: String,
+    /// The source image (square PNG, less than 4 MB).
+    pub image: ImageFile,
+    /// The number of variations to generate (1-10).
+    pub count: Option<u32>,
+    /// The size of the generated images.
+    pub size: Option<ImageSize>,
+    /// The format in which the images are returned.
+    pub response_format: Option<ImageResponseFormat>,
+}
+
+impl ImageVariationRequest {
+    /// Create a new builder.
+    pub fn builder() -> ImageVariationRequestBuilder {
+        ImageVariationRequestBuilder {
+            model: None,
+            image: None,
+            count: None,
+            size: None,
+            response_format: None,
+        }
+    }
+}
+
+/// Builder for [`ImageVariationRequest`].
+#[derive(Debug)]
+pub struct ImageVariationRequestBuilder {
+    model: Option<String>,
+    image: Option<ImageFile>,
+    count: Option<u32>,
+    size: Option<ImageSize>,
+    response_format: Option<ImageResponseFormat>,
+}
+
+impl ImageVariationRequestBuilder {
+    /// Set the model ID.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Set the source image data and filename.
+    pub fn image(mut self, data: impl Into<bytes::Bytes>, filename: impl Into<String>) -> Self {
+        self.image = Some(ImageFile::new(data, filename));
+        self
+    }
+
+    /// Set the number of variations to generate (1-10).
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Set the image size.
+    pub fn size(mut self, size: ImageSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the response format.
+    pub fn response_format(mut self, format: ImageResponseFormat) -> Self {
+        self.response_format = Some(format);
+        self
+    }
+
+    /// Build the request, returning an error if required fields are missing
+    /// or parameter values are invalid.
+    pub fn try_build(self) -> FoundryResult<ImageVariationRequest> {
+        let model = self
+            .model
+            .ok_or_else(|| FoundryError::Builder("model is required".into()))?;
+        if model.trim().is_empty() {
+            return Err(FoundryError::Builder("model cannot be empty".into()));
+        }
+
+        let image = self
+            .image
+            .ok_or_else(|| FoundryError::Builder("image is required".into()))?;
+        if image.data.is_empty() {
+            return Err(FoundryError::Builder("image data cannot be empty".into()));
+        }
+        if image.filename.trim().is_empty() {
+            return Err(FoundryError::Builder(
+                "image filename cannot be empty".into(),
+            ));
+        }
+
+        if let Some(count) = self.count {
+            if !(1..=10).contains(&count) {
+                return Err(FoundryError::Builder(
+                    "count must be between 1 and 10".into(),
+                ));
+            }
+        }
+
+        Ok(ImageVariationRequest {
+            model,
+            image,
+            count: self.count,
+            size: self.size,
+            response_format: self.response_format,
+        })
+    }
+
+    /// Build the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `model` or `image` is not set, or if `count` is out of range.
+    /// Use [`try_build`](Self::try_build) for fallible construction.
+    pub fn build(self) -> ImageVariationRequest {
+        self.try_build().expect("builder validation failed")
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Response types
 // ---------------------------------------------------------------------------
@@ -520,6 +943,31 @@ pub struct ImageResponse {
     pub created: u64,
     /// The generated or edited images.
     pub data: Vec<ImageData>,
+    /// The output format of the images, when reported (gpt-image-1).
+    #[serde(default)]
+    pub output_format: Option<ImageOutputFormat>,
+}
+
+impl ImageResponse {
+    /// Save every image to `dir` as `{stem}_{index}.{ext}`.
+    ///
+    /// The extension comes from [`output_format`](Self::output_format),
+    /// defaulting to PNG. Images returned as URLs are downloaded with
+    /// `client` (see [`ImageData::fetch`]). Returns the written paths in order.
+    pub async fn save_all(
+        &self,
+        client: &FoundryClient,
+        dir: impl AsRef<Path>,
+        stem: &str,
+    ) -> FoundryResult<Vec<PathBuf>> {
+        let format = self.output_format.unwrap_or(ImageOutputFormat::Png);
+        let mut paths = Vec::with_capacity(self.data.len());
+        for (i, image) in self.data.iter().enumerate() {
+            let path = dir.as_ref().join(format!("{stem}_{i}"));
+            paths.push(image.save(client, path, format).await?);
+        }
+        Ok(paths)
+    }
 }
 
 /// A single image in the response.
@@ -533,6 +981,131 @@ pub struct ImageData {
     pub revised_prompt: Option<String>,
 }
 
+impl ImageData {
+    /// Decode the `b64_json` image data into bytes.
+    ///
+    /// Returns a validation error if the image was returned as a URL or the
+    /// data is not valid base64.
+    pub fn decode(&self) -> FoundryResult<bytes::Bytes> {
+        let encoded = self.b64_json.as_deref().ok_or_else(|| {
+            FoundryError::validation("image has no b64_json data; use fetch() for URL results")
+        })?;
+        decode_image(encoded)
+    }
+
+    /// Get the image bytes, decoding `b64_json` or downloading `url`.
+    ///
+    /// URLs point to pre-signed storage, so they are fetched with
+    /// [`FoundryClient::download`]: without credentials, but with the
+    /// client's timeouts and retry policy.
+    pub async fn fetch(&self, client: &FoundryClient) -> FoundryResult<bytes::Bytes> {
+        if self.b64_json.is_some() {
+            return self.decode();
+        }
+        let url = self
+            .url
+            .as_deref()
+            .ok_or_else(|| FoundryError::validation("image has neither b64_json nor url"))?;
+
+        tracing::debug!("downloading image");
+        client.download(url).await
+    }
+
+    /// Save the image to `path`, appending the extension for `format`.
+    ///
+    /// Any existing extension is kept (`out/cat.v2` becomes `out/cat.v2.png`);
+    /// a path that already ends in the right extension, in any case and with
+    /// `jpg` and `jpeg` treated alike, is used as is. Returns the path that
+    /// was written.
+    pub async fn save(
+        &self,
+        client: &FoundryClient,
+        path: impl AsRef<Path>,
+        format: ImageOutputFormat,
+    ) -> FoundryResult<PathBuf> {
+        let path = with_appended_extension(path.as_ref(), format.extension());
+        let bytes = self.fetch(client).await?;
+        tokio::fs::write(&path, &bytes).await?;
+        Ok(path)
+    }
+}
+
+/// Append `ext` to `path` unless it already ends in that extension.
+///
+/// Extensions compare case-insensitively, and `jpg` and `jpeg` are the same.
+fn with_appended_extension(path: &Path, ext: &str) -> PathBuf {
+    let normalize = |e: &str| {
+        let e = e.to_ascii_lowercase();
+        if e == "jpeg" {
+            "jpg".to_string()
+        } else {
+            e
+        }
+    };
+    let current = path.extension().and_then(|e| e.to_str());
+    if current.is_some_and(|e| normalize(e) == normalize(ext)) {
+        return path.to_path_buf();
+    }
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(ext);
+    PathBuf::from(name)
+}
+
+/// Decode base64 image data.
+fn decode_image(encoded: &str) -> FoundryResult<bytes::Bytes> {
+    use base64::Engine;
+
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map(bytes::Bytes::from)
+        .map_err(|e| FoundryError::validation(format!("invalid base64 image data: {}", e)))
+}
+
+/// An event from a streaming image generation.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
+pub enum ImageStreamEvent {
+    /// An intermediate, lower-quality rendering of the image.
+    #[serde(rename = "image_generation.partial_image")]
+    PartialImage(StreamedImage),
+    /// The final image.
+    #[serde(rename = "image_generation.completed")]
+    Completed(StreamedImage),
+}
+
+impl ImageStreamEvent {
+    /// The image carried by this event.
+    pub fn image(&self) -> &StreamedImage {
+        match self {
+            Self::PartialImage(image) | Self::Completed(image) => image,
+        }
+    }
+}
+
+/// An image delivered in an [`ImageStreamEvent`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct StreamedImage {
+    /// The base64-encoded image data.
+    pub b64_json: String,
+    /// Index of a partial image (0-based); absent for the final image.
+    #[serde(default)]
+    pub partial_image_index: Option<u32>,
+    /// The output format of the image.
+    #[serde(default)]
+    pub output_format: Option<ImageOutputFormat>,
+    /// Unix timestamp when the image was created.
+    #[serde(default)]
+    pub created_at: Option<u64>,
+}
+
+impl StreamedImage {
+    /// Decode the image data into bytes.
+    pub fn decode(&self) -> FoundryResult<bytes::Bytes> {
+        decode_image(&self.b64_json)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // API functions
 // ---------------------------------------------------------------------------
@@ -612,8 +1185,8 @@ pub async fn edit(
 ) -> FoundryResult<ImageResponse> {
     tracing::debug!("sending image edit request");
 
-    let image_data = request.image.clone();
-    let image_filename = request.image_filename.clone();
+    let image = ImageFile::new(request.image.clone(), request.image_filename.clone());
+    let additional_images = request.additional_images.clone();
     let model = request.model.clone();
     let prompt = request.prompt.clone();
     let mask_data = request.mask.clone();
@@ -622,17 +1195,22 @@ pub async fn edit(
     let size = request.size;
     let quality = request.quality;
     let response_format = request.response_format;
+    let background = request.background;
+    let output_format = request.output_format;
+    let output_compression = request.output_compression;
 
     let response = client
         .post_multipart("/openai/v1/images/edits", move || {
-            let img_len = image_data.len() as u64;
-            let image_part = reqwest::multipart::Part::stream_with_length(
-                reqwest::Body::from(image_data.clone()),
-                img_len,
-            )
-            .file_name(image_filename.clone());
-            let mut form = reqwest::multipart::Form::new()
-                .part("image", image_part)
+            let mut form = if additional_images.is_empty() {
+                reqwest::multipart::Form::new().part("image", image.to_part())
+            } else {
+                std::iter::once(&image)
+                    .chain(&additional_images)
+                    .fold(reqwest::multipart::Form::new(), |form, file| {
+                        form.part("image[]", file.to_part())
+                    })
+            };
+            form = form
                 .text("model", model.clone())
                 .text("prompt", prompt.clone());
 
@@ -658,6 +1236,73 @@ pub async fn edit(
             if let Some(fmt) = response_format {
                 form = form.text("response_format", fmt.as_str());
             }
+            if let Some(background) = background {
+                form = form.text("background", background.as_str());
+            }
+            if let Some(fmt) = output_format {
+                form = form.text("output_format", fmt.as_str());
+            }
+            if let Some(compression) = output_compression {
+                form = form.text("output_compression", compression.to_string());
+            }
+
+            form
+        })
+        .await?;
+
+    let body = response.json::<ImageResponse>().await?;
+    Ok(body)
+}
+
+/// Create variations of an existing image.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::images::*;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let request = ImageVariationRequest::builder()
+///     .model("dall-e-2")
+///     .image(std::fs::read("logo.png").unwrap(), "logo.png")
+///     .count(3)
+///     .build();
+///
+/// let response = create_variation(client, &request).await?;
+/// println!("Created {} variations", response.data.len());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::images::create_variation` with field `model`.
+#[tracing::instrument(
+    name = "foundry::images::create_variation",
+    skip(client, request),
+    fields(model = %request.model)
+)]
+pub async fn create_variation(
+    client: &FoundryClient,
+    request: &ImageVariationRequest,
+) -> FoundryResult<ImageResponse> {
+    tracing::debug!("sending image variation request");
+
+    let response = client
+        .post_multipart("/openai/v1/images/variations", || {
+            let mut form = reqwest::multipart::Form::new()
+                .part("image", request.image.to_part())
+                .text("model", request.model.clone());
+
+            if let Some(n) = request.count {
+                form = form.text("n", n.to_string());
+            }
+            if let Some(size) = request.size {
+                form = form.text("size", size.as_str());
+            }
+            if let Some(fmt) = request.response_format {
+                form = form.text("response_format", fmt.as_str());
+            }
 
             form
         })
@@ -667,6 +1312,108 @@ pub async fn edit(
     Ok(body)
 }
 
+/// Wrapper that adds `stream: true` and the stream-only `partial_images` to
+/// an image generation request.
+#[derive(Serialize)]
+struct StreamingImageRequest<'a> {
+    #[serde(flatten)]
+    request: &'a ImageGenerationRequest,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    partial_images: Option<u32>,
+}
+
+/// Generate an image, streaming partial renderings as they are produced.
+///
+/// Set [`partial_images`](ImageGenerationRequestBuilder::partial_images) to
+/// receive up to three [`ImageStreamEvent::PartialImage`] events before the
+/// final [`ImageStreamEvent::Completed`] event (gpt-image-1).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::images::*;
+/// # use futures::StreamExt;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let request = ImageGenerationRequest::builder()
+///     .model("gpt-image-1")
+///     .prompt("A lighthouse at dawn")
+///     .partial_images(2)
+///     .build();
+///
+/// let mut stream = std::pin::pin!(generate_stream(client, &request).await?);
+/// while let Some(event) = stream.next().await {
+///     let bytes = event?.image().decode()?;
+///     println!("received {} bytes", bytes.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::images::generate_stream` with field `model`.
+#[tracing::instrument(
+    name = "foundry::images::generate_stream",
+    skip(client, request),
+    fields(model = %request.model)
+)]
+pub async fn generate_stream(
+    client: &FoundryClient,
+    request: &ImageGenerationRequest,
+) -> FoundryResult<impl Stream<Item = FoundryResult<ImageStreamEvent>>> {
+    tracing::debug!("initiating streaming image generation");
+
+    let response = client
+        .post_stream(
+            "/openai/v1/images/generations",
+            &StreamingImageRequest {
+                request,
+                stream: true,
+                partial_images: request.partial_images,
+            },
+        )
+        .await?;
+
+    Ok(crate::chat::parse_sse_stream(
+        response,
+        parse_image_event,
+        client.stream_idle_timeout(),
+    ))
+}
+
+/// Parse an image stream event, skipping events other than partial and final images.
+fn parse_image_event(
+    event: &azure_ai_foundry_core::sse::SseEvent,
+) -> Option<FoundryResult<ImageStreamEvent>> {
+    if event.is_done() || event.data.trim().is_empty() {
+        return None;
+    }
+
+    let value: serde_json::Value = match serde_json::from_str(&event.data) {
+        Ok(value) => value,
+        Err(e) => {
+            return Some(Err(FoundryError::stream_with_source(
+                "failed to parse image event",
+                e,
+            )))
+        }
+    };
+    let event_type = value.get("type").and_then(|t| t.as_str()).unwrap_or("");
+    if !matches!(
+        event_type,
+        "image_generation.partial_image" | "image_generation.completed"
+    ) {
+        return None;
+    }
+
+    Some(
+        serde_json::from_value(value)
+            .map_err(|e| FoundryError::stream_with_source("failed to parse image event", e)),
+    )
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1401,4 +2148,343 @@ mod tests {
             .build();
         assert_eq!(request.image.len(), 3);
     }
+
+    // --- gpt-image-1 parameters, variations, streaming and saving ---
+
+    #[test]
+    fn test_generation_request_gpt_image_params() {
+        let request = ImageGenerationRequest::builder()
+            .model("gpt-image-1")
+            .prompt("A cat")
+            .background(ImageBackground::Transparent)
+            .moderation(ImageModeration::Low)
+            .output_format(ImageOutputFormat::Webp)
+            .output_compression(80)
+            .partial_images(2)
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["background"], "transparent");
+        assert_eq!(json["moderation"], "low");
+        assert_eq!(json["output_format"], "webp");
+        assert_eq!(json["output_compression"], 80);
+        assert!(
+            json.get("partial_images").is_none(),
+            "partial_images is only sent when streaming"
+        );
+    }
+
+    #[test]
+    fn test_generation_request_validates_gpt_image_params() {
+        let builder = || {
+            ImageGenerationRequest::builder()
+                .model("gpt-image-1")
+                .prompt("A cat")
+        };
+
+        let err = builder().output_compression(50).try_build().unwrap_err();
+        assert!(err.to_string().contains("requires jpeg or webp"));
+
+        let err = builder()
+            .output_format(ImageOutputFormat::Jpeg)
+            .output_compression(101)
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("between 0 and 100"));
+
+        let err = builder()
+            .output_format(ImageOutputFormat::Jpeg)
+            .background(ImageBackground::Transparent)
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("transparent background"));
+
+        let err = builder().partial_images(4).try_build().unwrap_err();
+        assert!(err.to_string().contains("partial_images"));
+    }
+
+    #[test]
+    fn test_output_format_extension() {
+        assert_eq!(ImageOutputFormat::Jpeg.as_str(), "jpeg");
+        assert_eq!(ImageOutputFormat::Jpeg.extension(), "jpg");
+        assert_eq!(ImageOutputFormat::Webp.extension(), "webp");
+    }
+
+    #[test]
+    fn test_edit_request_add_image_and_limit() {
+        let request = ImageEditRequest::builder()
+            .model("gpt-image-1")
+            .add_image(vec![1u8], "a.png")
+            .add_image(vec![2u8], "b.png")
+            .prompt("Combine")
+            .build();
+        assert_eq!(request.image_filename, "a.png");
+        assert_eq!(request.additional_images.len(), 1);
+        assert_eq!(request.additional_images[0].filename, "b.png");
+
+        let builder = (0..=MAX_EDIT_IMAGES).fold(
+            ImageEditRequest::builder().model("gpt-image-1").prompt("x"),
+            |b, i| b.add_image(vec![1u8], format!("{i}.png")),
+        );
+        let err = builder.try_build().unwrap_err();
+        assert!(err.to_string().contains("at most 16 input images"));
+    }
+
+    #[tokio::test]
+    async fn test_edit_sends_multiple_images() {
+        use wiremock::matchers::body_string_contains;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/images/edits"))
+            .and(body_string_contains("name=\"image[]\"; filename=\"b.png\""))
+            .and(body_string_contains("name=\"output_format\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "created": TEST_TIMESTAMP,
+                "data": [{"b64_json": "AQID"}],
+                "output_format": "png"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = ImageEditRequest::builder()
+            .model("gpt-image-1")
+            .image(vec![1u8], "a.png")
+            .add_image(vec![2u8], "b.png")
+            .prompt("Combine")
+            .output_format(ImageOutputFormat::Png)
+            .build();
+
+        let response = edit(&client, &request).await.expect("should succeed");
+        assert_eq!(response.output_format, Some(ImageOutputFormat::Png));
+        assert_eq!(response.data[0].decode().unwrap().as_ref(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_variation_request_validation() {
+        let err = ImageVariationRequest::builder()
+            .model("dall-e-2")
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("image is required"));
+
+        let err = ImageVariationRequest::builder()
+            .model("dall-e-2")
+            .image(vec![1u8], "a.png")
+            .count(11)
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("count must be between 1 and 10"));
+    }
+
+    #[tokio::test]
+    async fn test_create_variation_success() {
+        use wiremock::matchers::body_string_contains;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/images/variations"))
+            .and(body_string_contains("name=\"n\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "created": TEST_TIMESTAMP,
+                "data": [{"url": "https://example.com/1.png"}, {"url": "https://example.com/2.png"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = ImageVariationRequest::builder()
+            .model("dall-e-2")
+            .image(vec![0u8; 10], "logo.png")
+            .count(2)
+            .size(ImageSize::S512x512)
+            .build();
+
+        let response = create_variation(&client, &request)
+            .await
+            .expect("should succeed");
+        assert_eq!(response.data.len(), 2);
+    }
+
+    #[test]
+    fn test_image_data_decode_errors() {
+        let url_only = ImageData {
+            url: Some("https://example.com/a.png".into()),
+            b64_json: None,
+            revised_prompt: None,
+        };
+        assert!(url_only
+            .decode()
+            .unwrap_err()
+            .to_string()
+            .contains("fetch()"));
+
+        let invalid = ImageData {
+            url: None,
+            b64_json: Some("***".into()),
+            revised_prompt: None,
+        };
+        assert!(invalid
+            .decode()
+            .unwrap_err()
+            .to_string()
+            .contains("invalid base64"));
+    }
+
+    #[tokio::test]
+    async fn test_save_appends_extension() {
+        let server = MockServer::start().await;
+        let client = setup_mock_client(&server).await;
+        let image = ImageData {
+            url: None,
+            b64_json: Some("AQID".into()),
+            revised_prompt: None,
+        };
+
+        let dir = std::env::temp_dir().join(format!("foundry-save-ext-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = image
+            .save(&client, dir.join("cat.v2"), ImageOutputFormat::Png)
+            .await
+            .expect("should save");
+        assert_eq!(path, dir.join("cat.v2.png"));
+
+        let path = image
+            .save(&client, dir.join("cat.png"), ImageOutputFormat::Png)
+            .await
+            .expect("should save");
+        assert_eq!(path, dir.join("cat.png"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_appended_extension_ignores_case_and_jpeg_spelling() {
+        let cases = [
+            ("cat.jpeg", "jpg", "cat.jpeg"),
+            ("cat.JPG", "jpg", "cat.JPG"),
+            ("CAT.PNG", "png", "CAT.PNG"),
+            ("cat.png", "jpg", "cat.png.jpg"),
+            ("cat", "webp", "cat.webp"),
+        ];
+        for (path, ext, expected) in cases {
+            assert_eq!(
+                with_appended_extension(Path::new(path), ext),
+                PathBuf::from(expected),
+                "{path} + {ext}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_image_event_skips_edit_events() {
+        let event = azure_ai_foundry_core::sse::SseEvent {
+            event: "image_edit.completed".into(),
+            data: r#"{"type":"image_edit.completed","b64_json":"AQ=="}"#.into(),
+            id: None,
+            retry: None,
+        };
+        assert!(parse_image_event(&event).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_save_all_downloads_urls_with_extension() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/blob/0"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![9u8, 8, 7]))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = ImageResponse {
+            created: TEST_TIMESTAMP,
+            data: vec![
+                ImageData {
+                    url: Some(format!("{}/blob/0", server.uri())),
+                    b64_json: None,
+                    revised_prompt: None,
+                },
+                ImageData {
+                    url: None,
+                    b64_json: Some("AQID".into()),
+                    revised_prompt: None,
+                },
+            ],
+            output_format: Some(ImageOutputFormat::Jpeg),
+        };
+
+        let dir = std::env::temp_dir().join(format!("foundry-images-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let client = setup_mock_client(&server).await;
+        let paths = response
+            .save_all(&client, &dir, "out")
+            .await
+            .expect("should save");
+        assert_eq!(paths, vec![dir.join("out_0.jpg"), dir.join("out_1.jpg")]);
+        assert_eq!(std::fs::read(&paths[0]).unwrap(), vec![9, 8, 7]);
+        assert_eq!(std::fs::read(&paths[1]).unwrap(), vec![1, 2, 3]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_generate_stream_yields_partial_and_final_images() {
+        use futures::TryStreamExt;
+        use wiremock::matchers::body_partial_json;
+
+        let server = MockServer::start().await;
+        let body = concat!(
+            "event: image_generation.partial_image\n",
+            "data: {\"type\":\"image_generation.partial_image\",\"b64_json\":\"AQ==\",\"partial_image_index\":0}\n\n",
+            "event: image_generation.completed\n",
+            "data: {\"type\":\"image_generation.completed\",\"b64_json\":\"AQID\",\"output_format\":\"png\",\"usage\":{\"total_tokens\":10}}\n\n",
+        );
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/images/generations"))
+            .and(body_partial_json(
+                serde_json::json!({"stream": true, "partial_images": 1}),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_string(body),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = ImageGenerationRequest::builder()
+            .model("gpt-image-1")
+            .prompt("A lighthouse")
+            .partial_images(1)
+            .build();
+
+        let events: Vec<ImageStreamEvent> = generate_stream(&client, &request)
+            .await
+            .expect("should start")
+            .try_collect()
+            .await
+            .expect("stream should succeed");
+
+        assert_eq!(events.len(), 2);
+        assert!(
+            matches!(&events[0], ImageStreamEvent::PartialImage(img) if img.partial_image_index == Some(0))
+        );
+        let ImageStreamEvent::Completed(image) = &events[1] else {
+            panic!("expected completed event");
+        };
+        assert_eq!(image.output_format, Some(ImageOutputFormat::Png));
+        assert_eq!(image.decode().unwrap().as_ref(), &[1, 2, 3]);
+    }
 }
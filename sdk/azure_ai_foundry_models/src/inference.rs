@@ -0,0 +1,800 @@
+//! Azure AI Model Inference API for non-OpenAI models.
+//!
+//! Foundry resources serve models such as Llama, Mistral, Phi, DeepSeek and
+//! Cohere through the model inference routes under `/models`, next to the
+//! OpenAI-compatible `/openai/v1` routes used by [`chat`] and
+//! [`embeddings`](crate::embeddings). The functions here send the same
+//! request types to those routes, plus image embeddings and model
+//! discovery.
+//!
+//! Parameters a model supports beyond the common schema go in
+//! [`InferenceOptions::extra_body`]; [`ExtraParameters`] tells the service
+//! whether to pass them through to the model, drop them, or reject them.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_models::chat::{ChatCompletionRequest, Message};
+//! # use azure_ai_foundry_models::inference::{self, ExtraParameters, InferenceOptions};
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let info = inference::get_model_info(client).await?;
+//! println!("{} by {} ({:?})", info.model_name, info.model_provider_name, info.family());
+//!
+//! let request = ChatCompletionRequest::builder()
+//!     .model("Mistral-Large-2411")
+//!     .message(Message::user("Bonjour !"))
+//!     .build();
+//! let options = InferenceOptions {
+//!     extra_parameters: Some(ExtraParameters::PassThrough),
+//!     ..Default::default()
+//! }
+//! .with_parameter("safe_prompt", true);
+//!
+//! let response = inference::complete(client, &request, &options).await?;
+//! println!("{:?}", response.choices[0].message.content);
+//! # Ok(())
+//! # }
+//! ```
+
+use azure_ai_foundry_core::client::FoundryClient;
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use base64::Engine;
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::chat::{self, ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse};
+use crate::embeddings::{EmbeddingRequest, EmbeddingResponse, EncodingFormat};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// API version query parameter for the model inference routes.
+pub const INFERENCE_API_VERSION: &str = "api-version=2024-05-01-preview";
+
+/// Name of the header that controls handling of extra parameters.
+const EXTRA_PARAMETERS_HEADER: &str = "extra-parameters";
+
+// ---------------------------------------------------------------------------
+// Options
+// ---------------------------------------------------------------------------
+
+/// How the service handles request parameters outside the common schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtraParameters {
+    /// Forward extra parameters to the model.
+    PassThrough,
+    /// Remove extra parameters before calling the model.
+    Drop,
+    /// Reject requests with extra parameters (the service default).
+    Error,
+}
+
+impl ExtraParameters {
+    /// Return the header value used by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PassThrough => "pass-through",
+            Self::Drop => "drop",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl std::fmt::Display for ExtraParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Options for model inference requests.
+#[derive(Debug, Clone, Default)]
+pub struct InferenceOptions {
+    /// Value of the `extra-parameters` header; omitted when `None`.
+    pub extra_parameters: Option<ExtraParameters>,
+
+    /// Model-specific parameters merged into the request body.
+    pub extra_body: serde_json::Map<String, serde_json::Value>,
+}
+
+impl InferenceOptions {
+    /// Add a model-specific parameter to [`extra_body`](Self::extra_body).
+    pub fn with_parameter(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.extra_body.insert(name.into(), value.into());
+        self
+    }
+
+    /// Check that extra body parameters will not be rejected outright.
+    fn validate(&self) -> FoundryResult<()> {
+        if !self.extra_body.is_empty()
+            && matches!(self.extra_parameters, None | Some(ExtraParameters::Error))
+        {
+            return Err(FoundryError::validation_field(
+                "extra_parameters",
+                "extra body parameters require ExtraParameters::PassThrough or ExtraParameters::Drop",
+            ));
+        }
+        Ok(())
+    }
+
+    fn headers(&self) -> Vec<(&'static str, &'static str)> {
+        self.extra_parameters
+            .map(|p| (EXTRA_PARAMETERS_HEADER, p.as_str()))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// A request body with model-specific parameters merged in.
+#[derive(Serialize)]
+struct InferenceBody<'a, R> {
+    #[serde(flatten)]
+    request: &'a R,
+    #[serde(flatten)]
+    extra: &'a serde_json::Map<String, serde_json::Value>,
+}
+
+// ---------------------------------------------------------------------------
+// Image embeddings
+// ---------------------------------------------------------------------------
+
+/// An image, optionally paired with text, to embed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImageEmbeddingInput {
+    /// The image as a `data:` URL with base64 content.
+    pub image: String,
+
+    /// Text to embed together with the image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+impl ImageEmbeddingInput {
+    /// Create an input from raw image bytes and their MIME type (e.g. `image/png`).
+    pub fn from_bytes(data: &[u8], mime_type: &str) -> Self {
+        Self {
+            image: format!(
+                "data:{};base64,{}",
+                mime_type,
+                base64::engine::general_purpose::STANDARD.encode(data)
+            ),
+            text: None,
+        }
+    }
+
+    /// Create an input from an existing `data:` URL.
+    pub fn from_data_url(url: impl Into<String>) -> Self {
+        Self {
+            image: url.into(),
+            text: None,
+        }
+    }
+
+    /// Pair the image with text.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+}
+
+/// An image embedding request.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageEmbeddingRequest {
+    pub model: String,
+    pub input: Vec<ImageEmbeddingInput>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding_format: Option<EncodingFormat>,
+}
+
+impl ImageEmbeddingRequest {
+    /// Create a new builder.
+    pub fn builder() -> ImageEmbeddingRequestBuilder {
+        ImageEmbeddingRequestBuilder {
+            model: None,
+            input: Vec::new(),
+            dimensions: None,
+            encoding_format: None,
+        }
+    }
+}
+
+/// Builder for [`ImageEmbeddingRequest`].
+#[derive(Debug)]
+pub struct ImageEmbeddingRequestBuilder {
+    model: Option<String>,
+    input: Vec<ImageEmbeddingInput>,
+    dimensions: Option<u32>,
+    encoding_format: Option<EncodingFormat>,
+}
+
+impl ImageEmbeddingRequestBuilder {
+    /// Set the model.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Add an image to embed.
+    pub fn input(mut self, input: ImageEmbeddingInput) -> Self {
+        self.input.push(input);
+        self
+    }
+
+    /// Add several images to embed.
+    pub fn inputs(mut self, inputs: impl IntoIterator<Item = ImageEmbeddingInput>) -> Self {
+        self.input.extend(inputs);
+        self
+    }
+
+    /// Set the number of dimensions of the output embeddings.
+    pub fn dimensions(mut self, dimensions: u32) -> Self {
+        self.dimensions = Some(dimensions);
+        self
+    }
+
+    /// Set the encoding format of the output embeddings.
+    pub fn encoding_format(mut self, format: EncodingFormat) -> Self {
+        self.encoding_format = Some(format);
+        self
+    }
+
+    /// Build the request, returning an error if required fields are missing or invalid.
+    pub fn try_build(self) -> FoundryResult<ImageEmbeddingRequest> {
+        let model = self
+            .model
+            .ok_or_else(|| FoundryError::Builder("model is required".into()))?;
+        if self.input.is_empty() {
+            return Err(FoundryError::Builder(
+                "at least one input is required".into(),
+            ));
+        }
+        if let Some(input) = self.input.iter().find(|i| !i.image.starts_with("data:")) {
+            return Err(FoundryError::Builder(format!(
+                "image must be a data: URL, got '{}'",
+                input.image.chars().take(32).collect::<String>()
+            )));
+        }
+        if self.dimensions == Some(0) {
+            return Err(FoundryError::Builder(
+                "dimensions must be at least 1".into(),
+            ));
+        }
+
+        Ok(ImageEmbeddingRequest {
+            model,
+            input: self.input,
+            dimensions: self.dimensions,
+            encoding_format: self.encoding_format,
+        })
+    }
+
+    /// Build the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `model` is not set, no input was added, or a value is invalid.
+    /// Use [`try_build`](Self::try_build) for fallible construction.
+    pub fn build(self) -> ImageEmbeddingRequest {
+        self.try_build().expect("builder validation failed")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Model info
+// ---------------------------------------------------------------------------
+
+/// The kind of task a model performs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelType {
+    /// Chat completions.
+    #[serde(alias = "chat-completion", alias = "chat-completions")]
+    ChatCompletion,
+    /// Text embeddings.
+    Embeddings,
+    /// Image embeddings.
+    #[serde(alias = "image-embeddings")]
+    ImageEmbeddings,
+    /// Text generation.
+    #[serde(alias = "text-generation")]
+    TextGeneration,
+    /// Image generation.
+    #[serde(alias = "image-generation")]
+    ImageGeneration,
+    /// Audio generation.
+    #[serde(alias = "audio-generation")]
+    AudioGeneration,
+    /// A type not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The family a model belongs to, derived from its name and provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModelFamily {
+    /// OpenAI GPT and o-series models.
+    OpenAi,
+    /// Meta Llama.
+    Llama,
+    /// Mistral AI.
+    Mistral,
+    /// Microsoft Phi.
+    Phi,
+    /// DeepSeek.
+    DeepSeek,
+    /// Cohere Command and Embed.
+    Cohere,
+    /// Any other family.
+    Other,
+}
+
+/// Information about the model behind an inference endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModelInfo {
+    /// Model name, e.g. `Phi-4`.
+    pub model_name: String,
+    /// The task the model performs.
+    pub model_type: ModelType,
+    /// Provider name, e.g. `Microsoft`.
+    pub model_provider_name: String,
+}
+
+impl ModelInfo {
+    /// The model family, derived from the provider and model name.
+    pub fn family(&self) -> ModelFamily {
+        let provider = self.model_provider_name.to_ascii_lowercase();
+        let name = self.model_name.to_ascii_lowercase();
+        let either = |needles: &[&str]| {
+            needles
+                .iter()
+                .any(|n| provider.contains(n) || name.contains(n))
+        };
+
+        if either(&["deepseek"]) {
+            ModelFamily::DeepSeek
+        } else if either(&["llama", "meta"]) {
+            ModelFamily::Llama
+        } else if either(&["mistral", "codestral", "ministral"]) {
+            ModelFamily::Mistral
+        } else if either(&["cohere"]) || name.starts_with("command") {
+            ModelFamily::Cohere
+        } else if name.starts_with("phi") {
+            ModelFamily::Phi
+        } else if either(&["openai"])
+            || ["gpt", "o1", "o3", "o4"]
+                .iter()
+                .any(|p| name.starts_with(p))
+        {
+            ModelFamily::OpenAi
+        } else {
+            ModelFamily::Other
+        }
+    }
+
+    /// Whether the model serves chat completions.
+    pub fn supports_chat(&self) -> bool {
+        self.model_type == ModelType::ChatCompletion
+    }
+
+    /// Whether the model serves text embeddings.
+    pub fn supports_embeddings(&self) -> bool {
+        self.model_type == ModelType::Embeddings
+    }
+
+    /// Whether the model serves image embeddings.
+    pub fn supports_image_embeddings(&self) -> bool {
+        self.model_type == ModelType::ImageEmbeddings
+    }
+}
+
+// ---------------------------------------------------------------------------
+// API functions
+// ---------------------------------------------------------------------------
+
+/// Send a chat completion request to the model inference route.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::inference::complete` with field `model`.
+#[tracing::instrument(
+    name = "foundry::inference::complete",
+    skip(client, request, options),
+    fields(model = %request.model)
+)]
+pub async fn complete(
+    client: &FoundryClient,
+    request: &ChatCompletionRequest,
+    options: &InferenceOptions,
+) -> FoundryResult<ChatCompletionResponse> {
+    tracing::debug!("sending model inference chat completion request");
+    options.validate()?;
+
+    let body = InferenceBody {
+        request,
+        extra: &options.extra_body,
+    };
+    let response = client
+        .post_with_headers(
+            &format!("/models/chat/completions?{}", INFERENCE_API_VERSION),
+            &body,
+            &options.headers(),
+        )
+        .await?;
+    let response = response.json::<ChatCompletionResponse>().await?;
+    Ok(response)
+}
+
+/// Send a streaming chat completion request to the model inference route.
+///
+/// Interruptions end the stream with [`FoundryError::StreamInterrupted`]
+/// holding the content received so far; unlike
+/// [`chat::complete_stream`], the stream is not resumed.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::inference::complete_stream` with field `model`.
+#[tracing::instrument(
+    name = "foundry::inference::complete_stream",
+    skip(client, request, options),
+    fields(model = %request.model)
+)]
+pub async fn complete_stream(
+    client: &FoundryClient,
+    request: &ChatCompletionRequest,
+    options: &InferenceOptions,
+) -> FoundryResult<impl Stream<Item = FoundryResult<ChatCompletionChunk>>> {
+    tracing::debug!("initiating model inference streaming chat completion");
+    options.validate()?;
+
+    let streaming = chat::StreamingRequest::new(request);
+    let body = InferenceBody {
+        request: &streaming,
+        extra: &options.extra_body,
+    };
+    let response = client
+        .post_stream_with_headers(
+            &format!("/models/chat/completions?{}", INFERENCE_API_VERSION),
+            &body,
+            &options.headers(),
+        )
+        .await?;
+
+    let chunks = chat::open_chunk_stream(response, client.stream_idle_timeout());
+    Ok(chat::track_interruptions(chunks, None))
+}
+
+/// Send a text embedding request to the model inference route.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::inference::embed` with field `model`.
+#[tracing::instrument(
+    name = "foundry::inference::embed",
+    skip(client, request, options),
+    fields(model = %request.model)
+)]
+pub async fn embed(
+    client: &FoundryClient,
+    request: &EmbeddingRequest,
+    options: &InferenceOptions,
+) -> FoundryResult<EmbeddingResponse> {
+    tracing::debug!("sending model inference embedding request");
+    options.validate()?;
+
+    let body = InferenceBody {
+        request,
+        extra: &options.extra_body,
+    };
+    let response = client
+        .post_with_headers(
+            &format!("/models/embeddings?{}", INFERENCE_API_VERSION),
+            &body,
+            &options.headers(),
+        )
+        .await?;
+    let response = response.json::<EmbeddingResponse>().await?;
+    Ok(response)
+}
+
+/// Send an image embedding request to the model inference route.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::inference::embed_images` with fields `model`
+/// and `input_count`.
+#[tracing::instrument(
+    name = "foundry::inference::embed_images",
+    skip(client, request, options),
+    fields(model = %request.model, input_count = request.input.len())
+)]
+pub async fn embed_images(
+    client: &FoundryClient,
+    request: &ImageEmbeddingRequest,
+    options: &InferenceOptions,
+) -> FoundryResult<EmbeddingResponse> {
+    tracing::debug!("sending image embedding request");
+    options.validate()?;
+
+    let body = InferenceBody {
+        request,
+        extra: &options.extra_body,
+    };
+    let response = client
+        .post_with_headers(
+            &format!("/models/images/embeddings?{}", INFERENCE_API_VERSION),
+            &body,
+            &options.headers(),
+        )
+        .await?;
+    let response = response.json::<EmbeddingResponse>().await?;
+    Ok(response)
+}
+
+/// Get the name, type and provider of the model behind the inference endpoint.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::inference::get_model_info`.
+#[tracing::instrument(name = "foundry::inference::get_model_info", skip(client))]
+pub async fn get_model_info(client: &FoundryClient) -> FoundryResult<ModelInfo> {
+    tracing::debug!("getting model info");
+    let response = client
+        .get(&format!("/models/info?{}", INFERENCE_API_VERSION))
+        .await?;
+    let info = response.json::<ModelInfo>().await?;
+    Ok(info)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat::Message;
+    use crate::test_utils::{setup_mock_client, TEST_TIMESTAMP};
+    use futures::StreamExt;
+    use wiremock::matchers::{body_json, body_partial_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn chat_request() -> ChatCompletionRequest {
+        ChatCompletionRequest::builder()
+            .model("Mistral-Large-2411")
+            .message(Message::user("Hi"))
+            .build()
+    }
+
+    fn embedding_response() -> serde_json::Value {
+        serde_json::json!({
+            "object": "list",
+            "model": "Cohere-embed-v3-english",
+            "data": [{"object": "embedding", "index": 0, "embedding": [0.25, 0.5]}],
+            "usage": {"prompt_tokens": 4, "total_tokens": 4}
+        })
+    }
+
+    #[test]
+    fn test_options_require_pass_through_for_extra_body() {
+        let options = InferenceOptions::default().with_parameter("safe_prompt", true);
+        let err = options.validate().unwrap_err();
+        assert!(err.to_string().contains("PassThrough"));
+
+        let options = InferenceOptions {
+            extra_parameters: Some(ExtraParameters::Drop),
+            ..options
+        };
+        assert!(options.validate().is_ok());
+        assert_eq!(options.headers(), [("extra-parameters", "drop")]);
+        assert!(InferenceOptions::default().headers().is_empty());
+    }
+
+    #[test]
+    fn test_image_embedding_input_data_url() {
+        let input = ImageEmbeddingInput::from_bytes(b"png", "image/png").with_text("a logo");
+        assert_eq!(input.image, "data:image/png;base64,cG5n");
+        assert_eq!(input.text.as_deref(), Some("a logo"));
+
+        let err = ImageEmbeddingRequest::builder()
+            .model("Cohere-embed-v3-english")
+            .input(ImageEmbeddingInput::from_data_url(
+                "https://example.com/a.png",
+            ))
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("data: URL"));
+
+        let err = ImageEmbeddingRequest::builder()
+            .model("Cohere-embed-v3-english")
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("at least one input"));
+    }
+
+    #[test]
+    fn test_model_family() {
+        let info = |name: &str, provider: &str| ModelInfo {
+            model_name: name.into(),
+            model_type: ModelType::ChatCompletion,
+            model_provider_name: provider.into(),
+        };
+        assert_eq!(
+            info("Llama-3.3-70B-Instruct", "Meta").family(),
+            ModelFamily::Llama
+        );
+        assert_eq!(
+            info("Mistral-Large-2411", "Mistral AI").family(),
+            ModelFamily::Mistral
+        );
+        assert_eq!(info("Phi-4", "Microsoft").family(), ModelFamily::Phi);
+        assert_eq!(
+            info("DeepSeek-R1", "DeepSeek").family(),
+            ModelFamily::DeepSeek
+        );
+        assert_eq!(
+            info("Cohere-command-r-plus", "Cohere").family(),
+            ModelFamily::Cohere
+        );
+        assert_eq!(info("gpt-4o", "OpenAI").family(), ModelFamily::OpenAi);
+        assert_eq!(info("jais-30b-chat", "Core42").family(), ModelFamily::Other);
+    }
+
+    #[tokio::test]
+    async fn test_complete_sends_header_and_extra_body() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/models/chat/completions"))
+            .and(query_param("api-version", "2024-05-01-preview"))
+            .and(header("extra-parameters", "pass-through"))
+            .and(body_partial_json(serde_json::json!({
+                "model": "Mistral-Large-2411",
+                "safe_prompt": true
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "cmpl-1",
+                "object": "chat.completion",
+                "created": TEST_TIMESTAMP,
+                "model": "Mistral-Large-2411",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "Salut"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let options = InferenceOptions {
+            extra_parameters: Some(ExtraParameters::PassThrough),
+            ..Default::default()
+        }
+        .with_parameter("safe_prompt", true);
+
+        let response = complete(&client, &chat_request(), &options)
+            .await
+            .expect("should succeed");
+        assert_eq!(
+            response.choices[0].message.content.as_deref(),
+            Some("Salut")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_stream_uses_inference_route() {
+        let server = MockServer::start().await;
+
+        let sse = concat!(
+            "data: {\"id\":\"c\",\"object\":\"chat.completion.chunk\",\"created\":1700000000,\"model\":\"m\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hel\"},\"finish_reason\":null}]}\n\n",
+            "data: {\"id\":\"c\",\"object\":\"chat.completion.chunk\",\"created\":1700000000,\"model\":\"m\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"lo\"},\"finish_reason\":\"stop\"}]}\n\n",
+            "data: [DONE]\n\n"
+        );
+        Mock::given(method("POST"))
+            .and(path("/models/chat/completions"))
+            .and(body_partial_json(serde_json::json!({"stream": true})))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_string(sse),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let stream = complete_stream(&client, &chat_request(), &InferenceOptions::default())
+            .await
+            .expect("should start stream");
+        let chunks: Vec<_> = stream.collect().await;
+        let text: String = chunks
+            .into_iter()
+            .map(|c| {
+                c.unwrap().choices[0]
+                    .delta
+                    .content
+                    .clone()
+                    .unwrap_or_default()
+            })
+            .collect();
+        assert_eq!(text, "Hello");
+    }
+
+    #[tokio::test]
+    async fn test_embed_and_embed_images() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/models/embeddings"))
+            .and(body_partial_json(serde_json::json!({"input": "hello"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(embedding_response()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/models/images/embeddings"))
+            .and(body_json(serde_json::json!({
+                "model": "Cohere-embed-v3-english",
+                "input": [{"image": "data:image/png;base64,cG5n", "text": "logo"}],
+                "dimensions": 2
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(embedding_response()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = EmbeddingRequest::builder()
+            .model("Cohere-embed-v3-english")
+            .input("hello")
+            .build();
+        let response = embed(&client, &request, &InferenceOptions::default())
+            .await
+            .expect("should embed text");
+        assert_eq!(response.data[0].embedding, vec![0.25, 0.5]);
+
+        let request = ImageEmbeddingRequest::builder()
+            .model("Cohere-embed-v3-english")
+            .input(ImageEmbeddingInput::from_bytes(b"png", "image/png").with_text("logo"))
+            .dimensions(2)
+            .build();
+        let response = embed_images(&client, &request, &InferenceOptions::default())
+            .await
+            .expect("should embed images");
+        assert_eq!(response.data.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_model_info() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/models/info"))
+            .and(query_param("api-version", "2024-05-01-preview"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "model_name": "Phi-4",
+                "model_type": "chat-completion",
+                "model_provider_name": "Microsoft"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let info = get_model_info(&client).await.expect("should succeed");
+        assert_eq!(info.model_type, ModelType::ChatCompletion);
+        assert!(info.supports_chat());
+        assert!(!info.supports_embeddings());
+        assert_eq!(info.family(), ModelFamily::Phi);
+    }
+}
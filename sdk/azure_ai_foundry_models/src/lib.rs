@@ -1,10 +1,21 @@
 #![doc = include_str!("../README.md")]
 
 pub mod audio;
+pub mod batch;
+pub mod cache;
 pub mod chat;
 pub mod embeddings;
+pub mod fallback;
+pub mod fine_tuning;
 pub mod images;
+pub mod inference;
+pub mod model;
+#[cfg(feature = "realtime")]
+pub mod realtime;
+pub mod rerank;
 pub mod responses;
+#[cfg(feature = "tokenizer")]
+pub mod tokenizer;
 
 /// Test utilities shared across modules.
 #[cfg(test)]
@@ -0,0 +1,279 @@
+//! Provider-agnostic model traits.
+//!
+//! The free functions in [`chat`], [`embeddings`]
+//! and [`audio`] take a [`FoundryClient`] directly. The traits
+//! here put the same operations behind [`ChatModel`], [`EmbeddingModel`] and
+//! [`SpeechModel`], so application code can depend on the trait and be run
+//! against [`FoundryModel`] in production and the deterministic fakes in
+//! [`fake`] in unit tests. Middleware such as caching or fallbacks can wrap
+//! any implementation.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_core::error::FoundryResult;
+//! use azure_ai_foundry_models::chat::{ChatCompletionRequest, Message};
+//! use azure_ai_foundry_models::model::{ChatModel, FoundryModel};
+//!
+//! async fn summarize(model: &impl ChatModel, text: &str) -> FoundryResult<String> {
+//!     let request = ChatCompletionRequest::builder()
+//!         .model("default")
+//!         .message(Message::system("Summarize in one sentence."))
+//!         .message(Message::user(text))
+//!         .build();
+//!     let response = model.complete(&request).await?;
+//!     Ok(response.choices[0].message.content.clone().unwrap_or_default())
+//! }
+//!
+//! # async fn example(client: FoundryClient) -> FoundryResult<()> {
+//! let model = FoundryModel::new(client, "gpt-4o");
+//! println!("{}", summarize(&model, "...").await?);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod fake;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use azure_ai_foundry_core::client::FoundryClient;
+use azure_ai_foundry_core::error::FoundryResult;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+
+use crate::audio::{self, SpeechRequest, TranscriptionRequest, TranscriptionResponse};
+use crate::chat::{self, ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse};
+use crate::embeddings::{self, EmbeddingRequest, EmbeddingResponse};
+
+/// A boxed stream of chat completion chunks.
+pub type ChatStream = BoxStream<'static, FoundryResult<ChatCompletionChunk>>;
+
+// ---------------------------------------------------------------------------
+// Traits
+// ---------------------------------------------------------------------------
+
+/// A model that generates chat completions.
+#[async_trait]
+pub trait ChatModel: Send + Sync {
+    /// Generate a complete response.
+    async fn complete(
+        &self,
+        request: &ChatCompletionRequest,
+    ) -> FoundryResult<ChatCompletionResponse>;
+
+    /// Generate a response as a stream of chunks.
+    async fn complete_stream(&self, request: &ChatCompletionRequest) -> FoundryResult<ChatStream>;
+}
+
+/// A model that turns text into vector embeddings.
+#[async_trait]
+pub trait EmbeddingModel: Send + Sync {
+    /// Embed the inputs of `request`.
+    async fn embed(&self, request: &EmbeddingRequest) -> FoundryResult<EmbeddingResponse>;
+}
+
+/// A model that transcribes and synthesizes speech.
+#[async_trait]
+pub trait SpeechModel: Send + Sync {
+    /// Transcribe audio to text.
+    async fn transcribe(
+        &self,
+        request: &TranscriptionRequest,
+    ) -> FoundryResult<TranscriptionResponse>;
+
+    /// Synthesize speech, returning the encoded audio.
+    async fn speak(&self, request: &SpeechRequest) -> FoundryResult<bytes::Bytes>;
+}
+
+macro_rules! forward_impls {
+    ($($wrapper:ident),*) => {$(
+        #[async_trait]
+        impl<T: ChatModel + ?Sized> ChatModel for $wrapper<T> {
+            async fn complete(
+                &self,
+                request: &ChatCompletionRequest,
+            ) -> FoundryResult<ChatCompletionResponse> {
+                (**self).complete(request).await
+            }
+
+            async fn complete_stream(
+                &self,
+                request: &ChatCompletionRequest,
+            ) -> FoundryResult<ChatStream> {
+                (**self).complete_stream(request).await
+            }
+        }
+
+        #[async_trait]
+        impl<T: EmbeddingModel + ?Sized> EmbeddingModel for $wrapper<T> {
+            async fn embed(&self, request: &EmbeddingRequest) -> FoundryResult<EmbeddingResponse> {
+                (**self).embed(request).await
+            }
+        }
+
+        #[async_trait]
+        impl<T: SpeechModel + ?Sized> SpeechModel for $wrapper<T> {
+            async fn transcribe(
+                &self,
+                request: &TranscriptionRequest,
+            ) -> FoundryResult<TranscriptionResponse> {
+                (**self).transcribe(request).await
+            }
+
+            async fn speak(&self, request: &SpeechRequest) -> FoundryResult<bytes::Bytes> {
+                (**self).speak(request).await
+            }
+        }
+    )*};
+}
+
+forward_impls!(Arc, Box);
+
+// ---------------------------------------------------------------------------
+// Foundry implementation
+// ---------------------------------------------------------------------------
+
+/// A model deployment served by Azure AI Foundry.
+///
+/// Implements every model trait by calling the matching free function with
+/// the held client. The `model` of each request is replaced by the
+/// deployment name, so callers can build requests without knowing it.
+#[derive(Debug, Clone)]
+pub struct FoundryModel {
+    client: FoundryClient,
+    deployment: String,
+}
+
+impl FoundryModel {
+    /// Create a model that sends requests to `deployment`.
+    pub fn new(client: FoundryClient, deployment: impl Into<String>) -> Self {
+        Self {
+            client,
+            deployment: deployment.into(),
+        }
+    }
+
+    /// The underlying client.
+    pub fn client(&self) -> &FoundryClient {
+        &self.client
+    }
+
+    /// The deployment name requests are sent to.
+    pub fn deployment(&self) -> &str {
+        &self.deployment
+    }
+}
+
+/// Return `$request` with its `model` set to the deployment.
+macro_rules! for_deployment {
+    ($self:ident, $request:ident) => {{
+        let mut request = $request.clone();
+        request.model.clone_from(&$self.deployment);
+        request
+    }};
+}
+
+#[async_trait]
+impl ChatModel for FoundryModel {
+    async fn complete(
+        &self,
+        request: &ChatCompletionRequest,
+    ) -> FoundryResult<ChatCompletionResponse> {
+        chat::complete(&self.client, &for_deployment!(self, request)).await
+    }
+
+    async fn complete_stream(&self, request: &ChatCompletionRequest) -> FoundryResult<ChatStream> {
+        let stream = chat::complete_stream(&self.client, &for_deployment!(self, request)).await?;
+        Ok(stream.boxed())
+    }
+}
+
+#[async_trait]
+impl EmbeddingModel for FoundryModel {
+    async fn embed(&self, request: &EmbeddingRequest) -> FoundryResult<EmbeddingResponse> {
+        embeddings::embed(&self.client, &for_deployment!(self, request)).await
+    }
+}
+
+#[async_trait]
+impl SpeechModel for FoundryModel {
+    async fn transcribe(
+        &self,
+        request: &TranscriptionRequest,
+    ) -> FoundryResult<TranscriptionResponse> {
+        audio::transcribe(&self.client, &for_deployment!(self, request)).await
+    }
+
+    async fn speak(&self, request: &SpeechRequest) -> FoundryResult<bytes::Bytes> {
+        audio::speak(&self.client, &for_deployment!(self, request)).await
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat::Message;
+    use crate::test_utils::{setup_mock_client, TEST_TIMESTAMP};
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn chat_request() -> ChatCompletionRequest {
+        ChatCompletionRequest::builder()
+            .model("placeholder")
+            .message(Message::user("Hi"))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_foundry_model_sends_to_deployment() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/chat/completions"))
+            .and(body_partial_json(
+                serde_json::json!({"model": "gpt-4o-prod"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-1",
+                "object": "chat.completion",
+                "created": TEST_TIMESTAMP,
+                "model": "gpt-4o",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "Hello!"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let model = FoundryModel::new(client, "gpt-4o-prod");
+        assert_eq!(model.deployment(), "gpt-4o-prod");
+
+        let response = model.complete(&chat_request()).await.unwrap();
+        assert_eq!(
+            response.choices[0].message.content.as_deref(),
+            Some("Hello!")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_traits_are_object_safe_and_forward_through_wrappers() {
+        let fake = Arc::new(fake::FakeChatModel::new().reply("from the fake"));
+        let boxed: Box<dyn ChatModel> = Box::new(Arc::clone(&fake));
+
+        let response = boxed.complete(&chat_request()).await.unwrap();
+        assert_eq!(
+            response.choices[0].message.content.as_deref(),
+            Some("from the fake")
+        );
+        assert_eq!(fake.call_count(), 1);
+    }
+}
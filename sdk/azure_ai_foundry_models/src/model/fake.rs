@@ -0,0 +1,542 @@
+//! Deterministic in-memory models for unit tests.
+//!
+//! Each fake answers without any network access, records the requests it
+//! receives, and can be told to fail so error paths can be exercised:
+//!
+//! - [`FakeChatModel`] replies with scripted text, or echoes the last user message.
+//! - [`FakeEmbeddingModel`] derives a stable unit vector from each input text.
+//! - [`FakeSpeechModel`] "speaks" text as its UTF-8 bytes and "transcribes"
+//!   bytes back to text, so the two round-trip.
+//!
+//! # Example
+//!
+//! ```rust
+//! # async fn example() -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! use azure_ai_foundry_models::chat::{ChatCompletionRequest, Message};
+//! use azure_ai_foundry_models::model::fake::FakeChatModel;
+//! use azure_ai_foundry_models::model::ChatModel;
+//!
+//! let model = FakeChatModel::new().reply("Paris").fail(503, "overloaded");
+//! let request = ChatCompletionRequest::builder()
+//!     .model("any")
+//!     .message(Message::user("Capital of France?"))
+//!     .build();
+//!
+//! let response = model.complete(&request).await?;
+//! assert_eq!(response.choices[0].message.content.as_deref(), Some("Paris"));
+//! assert!(model.complete(&request).await.is_err());
+//! assert_eq!(model.call_count(), 2);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use azure_ai_foundry_core::models::Usage;
+use futures::StreamExt;
+
+use super::{ChatModel, ChatStream, EmbeddingModel, SpeechModel};
+use crate::audio::{SpeechRequest, TranscriptionRequest, TranscriptionResponse};
+use crate::chat::training::approx_tokens;
+use crate::chat::{
+    ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse, Choice, ChunkChoice, Delta,
+    Message, Role,
+};
+use crate::embeddings::{
+    normalize, EmbeddingData, EmbeddingInput, EmbeddingRequest, EmbeddingResponse,
+};
+
+/// Default number of dimensions of [`FakeEmbeddingModel`] vectors.
+pub const DEFAULT_FAKE_DIMENSIONS: usize = 8;
+
+/// Lock a mutex, ignoring poisoning from a panicked test thread.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn usage(prompt_tokens: usize, completion_tokens: Option<usize>) -> Usage {
+    let prompt_tokens = prompt_tokens as u32;
+    let completion_tokens = completion_tokens.map(|t| t as u32);
+    Usage {
+        prompt_tokens,
+        completion_tokens,
+        total_tokens: prompt_tokens + completion_tokens.unwrap_or(0),
+    }
+}
+
+/// A queued failure: HTTP status and message.
+type Failure = (u16, String);
+
+// ---------------------------------------------------------------------------
+// Chat
+// ---------------------------------------------------------------------------
+
+/// A scripted answer of [`FakeChatModel`].
+#[derive(Debug)]
+enum FakeReply {
+    Text(String),
+    Error(Failure),
+}
+
+/// A chat model that answers from a script.
+///
+/// Queued replies and failures are used in order, one per call. Once the
+/// queue is empty, the model echoes the content of the last user message.
+/// Streaming splits the reply into one chunk per word.
+#[derive(Debug, Default)]
+pub struct FakeChatModel {
+    replies: Mutex<VecDeque<FakeReply>>,
+    requests: Mutex<Vec<ChatCompletionRequest>>,
+}
+
+impl FakeChatModel {
+    /// Create a model that echoes the last user message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a reply.
+    pub fn reply(self, text: impl Into<String>) -> Self {
+        lock(&self.replies).push_back(FakeReply::Text(text.into()));
+        self
+    }
+
+    /// Queue a failure returned as [`FoundryError::Http`] with `status`.
+    pub fn fail(self, status: u16, message: impl Into<String>) -> Self {
+        lock(&self.replies).push_back(FakeReply::Error((status, message.into())));
+        self
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<ChatCompletionRequest> {
+        lock(&self.requests).clone()
+    }
+
+    /// Number of calls received so far.
+    pub fn call_count(&self) -> usize {
+        lock(&self.requests).len()
+    }
+
+    /// Record the request and pick its reply.
+    fn next_reply(&self, request: &ChatCompletionRequest) -> FoundryResult<(usize, String)> {
+        let call = {
+            let mut requests = lock(&self.requests);
+            requests.push(request.clone());
+            requests.len()
+        };
+        match lock(&self.replies).pop_front() {
+            Some(FakeReply::Text(text)) => Ok((call, text)),
+            Some(FakeReply::Error((status, message))) => Err(FoundryError::http(status, message)),
+            None => {
+                let echo = request
+                    .messages
+                    .iter()
+                    .rev()
+                    .find(|m| m.role == Role::User)
+                    .and_then(|m| m.content.clone())
+                    .unwrap_or_default();
+                Ok((call, echo))
+            }
+        }
+    }
+}
+
+fn prompt_tokens(request: &ChatCompletionRequest) -> usize {
+    request
+        .messages
+        .iter()
+        .map(|m| m.content.as_deref().map_or(0, approx_tokens))
+        .sum()
+}
+
+#[async_trait]
+impl ChatModel for FakeChatModel {
+    async fn complete(
+        &self,
+        request: &ChatCompletionRequest,
+    ) -> FoundryResult<ChatCompletionResponse> {
+        let (call, text) = self.next_reply(request)?;
+        let completion_tokens = approx_tokens(&text);
+        Ok(ChatCompletionResponse {
+            id: format!("fake-{}", call),
+            object: "chat.completion".into(),
+            created: 0,
+            model: request.model.clone(),
+            choices: vec![Choice {
+                index: 0,
+                message: Message::assistant(text),
+                finish_reason: Some("stop".into()),
+            }],
+            usage: Some(usage(prompt_tokens(request), Some(completion_tokens))),
+        })
+    }
+
+    async fn complete_stream(&self, request: &ChatCompletionRequest) -> FoundryResult<ChatStream> {
+        let (call, text) = self.next_reply(request)?;
+        let chunk =
+            |delta: Delta, finish_reason: Option<&str>, usage: Option<Usage>| ChatCompletionChunk {
+                id: format!("fake-{}", call),
+                object: "chat.completion.chunk".into(),
+                created: 0,
+                model: request.model.clone(),
+                choices: vec![ChunkChoice {
+                    index: 0,
+                    delta,
+                    finish_reason: finish_reason.map(Into::into),
+                }],
+                usage,
+            };
+
+        let mut chunks = vec![chunk(
+            Delta {
+                role: Some(Role::Assistant),
+                content: None,
+                tool_calls: None,
+                context: None,
+            },
+            None,
+            None,
+        )];
+        chunks.extend(text.split_inclusive(' ').map(|word| {
+            chunk(
+                Delta {
+                    role: None,
+                    content: Some(word.into()),
+                    tool_calls: None,
+                    context: None,
+                },
+                None,
+                None,
+            )
+        }));
+        chunks.push(chunk(
+            Delta {
+                role: None,
+                content: None,
+                tool_calls: None,
+                context: None,
+            },
+            Some("stop"),
+            Some(usage(prompt_tokens(request), Some(approx_tokens(&text)))),
+        ));
+
+        Ok(futures::stream::iter(chunks.into_iter().map(Ok)).boxed())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Embeddings
+// ---------------------------------------------------------------------------
+
+/// An embedding model that derives vectors from a hash of each input.
+///
+/// The same text always gets the same unit-length vector, and different
+/// texts get unrelated ones. The request's `dimensions`, if set, overrides
+/// the model's default.
+#[derive(Debug)]
+pub struct FakeEmbeddingModel {
+    dimensions: usize,
+    failures: Mutex<VecDeque<Failure>>,
+    requests: Mutex<Vec<EmbeddingRequest>>,
+}
+
+impl Default for FakeEmbeddingModel {
+    fn default() -> Self {
+        Self::new(DEFAULT_FAKE_DIMENSIONS)
+    }
+}
+
+impl FakeEmbeddingModel {
+    /// Create a model producing vectors of `dimensions` components.
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions,
+            failures: Mutex::new(VecDeque::new()),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Queue a failure returned as [`FoundryError::Http`] with `status`.
+    pub fn fail(self, status: u16, message: impl Into<String>) -> Self {
+        lock(&self.failures).push_back((status, message.into()));
+        self
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<EmbeddingRequest> {
+        lock(&self.requests).clone()
+    }
+
+    /// Number of calls received so far.
+    pub fn call_count(&self) -> usize {
+        lock(&self.requests).len()
+    }
+
+    /// The vector this model returns for `text` at its default dimensions.
+    pub fn embedding_for(&self, text: &str) -> Vec<f32> {
+        hash_embedding(text, self.dimensions)
+    }
+}
+
+/// A unit vector seeded from the FNV-1a hash of `text`.
+fn hash_embedding(text: &str, dimensions: usize) -> Vec<f32> {
+    let mut state = text.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    let values: Vec<f32> = (0..dimensions)
+        .map(|_| {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 40) as f32 / (1_u64 << 23) as f32 - 1.0
+        })
+        .collect();
+    normalize(&values)
+}
+
+#[async_trait]
+impl EmbeddingModel for FakeEmbeddingModel {
+    async fn embed(&self, request: &EmbeddingRequest) -> FoundryResult<EmbeddingResponse> {
+        lock(&self.requests).push(request.clone());
+        if let Some((status, message)) = lock(&self.failures).pop_front() {
+            return Err(FoundryError::http(status, message));
+        }
+
+        let texts: Vec<&str> = match &request.input {
+            EmbeddingInput::Single(text) => vec![text],
+            EmbeddingInput::Multiple(texts) => texts.iter().map(String::as_str).collect(),
+        };
+        let dimensions = request.dimensions.map_or(self.dimensions, |d| d as usize);
+        let data = texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| EmbeddingData {
+                object: "embedding".into(),
+                index: i as u32,
+                embedding: hash_embedding(text, dimensions),
+            })
+            .collect();
+
+        Ok(EmbeddingResponse {
+            object: "list".into(),
+            model: request.model.clone(),
+            data,
+            usage: usage(texts.iter().map(|t| approx_tokens(t)).sum(), None),
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Speech
+// ---------------------------------------------------------------------------
+
+/// A speech model that maps text to bytes and back.
+///
+/// [`speak`](SpeechModel::speak) returns the input text as UTF-8 bytes, and
+/// [`transcribe`](SpeechModel::transcribe) decodes the audio bytes as UTF-8
+/// text, unless a fixed transcript is set with
+/// [`transcript`](Self::transcript).
+#[derive(Debug, Default)]
+pub struct FakeSpeechModel {
+    transcript: Option<String>,
+    failures: Mutex<VecDeque<Failure>>,
+    transcriptions: Mutex<Vec<TranscriptionRequest>>,
+    speeches: Mutex<Vec<SpeechRequest>>,
+}
+
+impl FakeSpeechModel {
+    /// Create a model that round-trips text through bytes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return `text` from every transcription.
+    pub fn transcript(mut self, text: impl Into<String>) -> Self {
+        self.transcript = Some(text.into());
+        self
+    }
+
+    /// Queue a failure returned as [`FoundryError::Http`] with `status`.
+    pub fn fail(self, status: u16, message: impl Into<String>) -> Self {
+        lock(&self.failures).push_back((status, message.into()));
+        self
+    }
+
+    /// The transcription requests received so far, in order.
+    pub fn transcription_requests(&self) -> Vec<TranscriptionRequest> {
+        lock(&self.transcriptions).clone()
+    }
+
+    /// The speech requests received so far, in order.
+    pub fn speech_requests(&self) -> Vec<SpeechRequest> {
+        lock(&self.speeches).clone()
+    }
+
+    /// Number of calls received so far, transcriptions and speech combined.
+    pub fn call_count(&self) -> usize {
+        lock(&self.transcriptions).len() + lock(&self.speeches).len()
+    }
+
+    fn next_failure(&self) -> FoundryResult<()> {
+        match lock(&self.failures).pop_front() {
+            Some((status, message)) => Err(FoundryError::http(status, message)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl SpeechModel for FakeSpeechModel {
+    async fn transcribe(
+        &self,
+        request: &TranscriptionRequest,
+    ) -> FoundryResult<TranscriptionResponse> {
+        lock(&self.transcriptions).push(request.clone());
+        self.next_failure()?;
+
+        let text = match &self.transcript {
+            Some(text) => text.clone(),
+            None => String::from_utf8_lossy(&request.data).into_owned(),
+        };
+        Ok(TranscriptionResponse {
+            text,
+            logprobs: None,
+        })
+    }
+
+    async fn speak(&self, request: &SpeechRequest) -> FoundryResult<bytes::Bytes> {
+        lock(&self.speeches).push(request.clone());
+        self.next_failure()?;
+
+        Ok(bytes::Bytes::from(request.input.clone()))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::Voice;
+    use futures::TryStreamExt;
+
+    fn chat_request(text: &str) -> ChatCompletionRequest {
+        ChatCompletionRequest::builder()
+            .model("fake")
+            .message(Message::system("Be brief."))
+            .message(Message::user(text))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_fake_chat_uses_script_then_echoes() {
+        let model = FakeChatModel::new()
+            .reply("scripted")
+            .fail(429, "slow down");
+
+        let first = model.complete(&chat_request("one")).await.unwrap();
+        assert_eq!(
+            first.choices[0].message.content.as_deref(),
+            Some("scripted")
+        );
+        assert_eq!(first.id, "fake-1");
+
+        let err = model.complete(&chat_request("two")).await.unwrap_err();
+        assert!(matches!(err, FoundryError::Http { status: 429, .. }));
+        assert!(err.is_retryable());
+
+        let third = model.complete(&chat_request("three")).await.unwrap();
+        assert_eq!(third.choices[0].message.content.as_deref(), Some("three"));
+
+        assert_eq!(model.call_count(), 3);
+        assert_eq!(
+            model.requests()[1].messages[1].content.as_deref(),
+            Some("two")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fake_chat_stream_splits_words() {
+        let model = FakeChatModel::new().reply("Hello there world");
+        let chunks: Vec<ChatCompletionChunk> = model
+            .complete_stream(&chat_request("hi"))
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(chunks.len(), 5);
+        assert_eq!(chunks[0].choices[0].delta.role, Some(Role::Assistant));
+        let text: String = chunks
+            .iter()
+            .filter_map(|c| c.choices[0].delta.content.as_deref())
+            .collect();
+        assert_eq!(text, "Hello there world");
+        assert_eq!(chunks[4].choices[0].finish_reason.as_deref(), Some("stop"));
+        assert!(chunks[4].usage.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_fake_embeddings_are_deterministic_unit_vectors() {
+        let model = FakeEmbeddingModel::new(16);
+        let request = EmbeddingRequest::builder()
+            .model("fake")
+            .inputs(["alpha", "beta", "alpha"])
+            .build();
+
+        let response = model.embed(&request).await.unwrap();
+        let vectors: Vec<&Vec<f32>> = response.data.iter().map(|d| &d.embedding).collect();
+
+        assert_eq!(vectors[0].len(), 16);
+        assert_eq!(vectors[0], vectors[2]);
+        assert_ne!(vectors[0], vectors[1]);
+        assert_eq!(*vectors[1], model.embedding_for("beta"));
+        let norm: f32 = vectors[0].iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-5);
+    }
+
+    #[tokio::test]
+    async fn test_fake_embeddings_honor_request_dimensions_and_failures() {
+        let model = FakeEmbeddingModel::default().fail(500, "boom");
+        let request = EmbeddingRequest::builder()
+            .model("fake")
+            .input("text")
+            .dimensions(4)
+            .build();
+
+        assert!(model.embed(&request).await.is_err());
+        let response = model.embed(&request).await.unwrap();
+        assert_eq!(response.data[0].embedding.len(), 4);
+        assert_eq!(model.call_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fake_speech_round_trips_text() {
+        let model = FakeSpeechModel::new();
+        let speech = SpeechRequest::builder()
+            .model("fake")
+            .input("Hello, world")
+            .voice(Voice::Alloy)
+            .build();
+        let audio = model.speak(&speech).await.unwrap();
+
+        let transcription = TranscriptionRequest::builder()
+            .model("fake")
+            .filename("speech.mp3")
+            .data(audio)
+            .build();
+        let response = model.transcribe(&transcription).await.unwrap();
+
+        assert_eq!(response.text, "Hello, world");
+        assert_eq!(model.speech_requests().len(), 1);
+        assert_eq!(model.transcription_requests().len(), 1);
+        assert_eq!(model.call_count(), 2);
+    }
+}
@@ -0,0 +1,1083 @@
+//! Realtime API over WebSocket for Azure AI Foundry Models.
+//!
+//! The Realtime API keeps a bidirectional WebSocket open to a realtime model
+//! (for example `gpt-4o-realtime-preview`). The client sends JSON
+//! [`ClientEvent`]s such as `session.update`, `input_audio_buffer.append` and
+//! `response.create`, and receives [`ServerEvent`]s such as
+//! `response.audio.delta` and function-call events.
+//!
+//! Connections authenticate with the same [`FoundryCredential`](azure_ai_foundry_core::auth::FoundryCredential)
+//! as the rest of the SDK, taken from the [`FoundryClient`].
+//!
+//! Available with the `realtime` feature, which adds a WebSocket client with
+//! native TLS.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_models::realtime::*;
+//! # use azure_ai_foundry_models::audio::Voice;
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let mut session = connect(client, "gpt-4o-realtime-preview").await?;
+//!
+//! session
+//!     .send(ClientEvent::SessionUpdate {
+//!         session: SessionConfig {
+//!             voice: Some(Voice::Alloy),
+//!             instructions: Some("You are a helpful assistant.".into()),
+//!             ..Default::default()
+//!         },
+//!     })
+//!     .await?;
+//! session.send(ClientEvent::append_audio(&[0u8; 3200])).await?;
+//! session.send(ClientEvent::InputAudioBufferCommit).await?;
+//! session.send(ClientEvent::create_response()).await?;
+//!
+//! while let Some(event) = session.next_event().await {
+//!     match event? {
+//!         ServerEvent::ResponseAudioDelta(delta) => {
+//!             let pcm = delta.decode_audio()?;
+//!             println!("{} bytes of audio", pcm.len());
+//!         }
+//!         ServerEvent::ResponseDone { .. } => break,
+//!         _ => {}
+//!     }
+//! }
+//! session.close().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use azure_ai_foundry_core::client::FoundryClient;
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::audio::Voice;
+use crate::chat::Role;
+
+/// API path for realtime sessions.
+const REALTIME_PATH: &str = "/openai/v1/realtime";
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+// ---------------------------------------------------------------------------
+// Session configuration
+// ---------------------------------------------------------------------------
+
+/// Output modality of a realtime response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Modality {
+    /// Text output.
+    Text,
+    /// Audio output.
+    Audio,
+}
+
+/// Audio encoding for realtime input and output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RealtimeAudioFormat {
+    /// 16-bit PCM, 24 kHz, mono, little-endian.
+    Pcm16,
+    /// G.711 μ-law.
+    #[serde(rename = "g711_ulaw")]
+    G711Ulaw,
+    /// G.711 A-law.
+    #[serde(rename = "g711_alaw")]
+    G711Alaw,
+}
+
+/// How the server detects the end of a user turn.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TurnDetection {
+    /// Voice activity detection based on audio level.
+    ServerVad {
+        /// Activation threshold (0.0 to 1.0).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        threshold: Option<f32>,
+        /// Audio kept before detected speech, in milliseconds.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        prefix_padding_ms: Option<u32>,
+        /// Silence that ends a turn, in milliseconds.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        silence_duration_ms: Option<u32>,
+        /// Whether a response is created automatically when a turn ends.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        create_response: Option<bool>,
+    },
+    /// Detection based on what the user said rather than on silence.
+    SemanticVad {
+        /// How quickly to respond: `low`, `medium`, `high` or `auto`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        eagerness: Option<String>,
+        /// Whether a response is created automatically when a turn ends.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        create_response: Option<bool>,
+    },
+}
+
+/// Settings for transcribing the user's input audio.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputAudioTranscription {
+    /// Transcription model, e.g. `whisper-1`.
+    pub model: String,
+}
+
+/// A function the model may call during a realtime session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "function")]
+pub struct RealtimeTool {
+    /// Function name.
+    pub name: String,
+    /// What the function does, shown to the model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// JSON Schema of the function arguments.
+    pub parameters: serde_json::Value,
+}
+
+impl RealtimeTool {
+    /// Create a function tool.
+    pub fn function(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        parameters: serde_json::Value,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: Some(description.into()),
+            parameters,
+        }
+    }
+}
+
+/// Realtime session settings, sent with `session.update` and returned in
+/// `session.created` / `session.updated`.
+///
+/// Unset fields are left unchanged by the server.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionConfig {
+    /// Session ID (set by the server).
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    /// Model serving the session (set by the server).
+    #[serde(skip_serializing)]
+    pub model: Option<String>,
+    /// Output modalities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modalities: Option<Vec<Modality>>,
+    /// System instructions for the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// Voice used for audio output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<Voice>,
+    /// Encoding of audio sent with `input_audio_buffer.append`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_audio_format: Option<RealtimeAudioFormat>,
+    /// Encoding of audio returned in `response.audio.delta`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_audio_format: Option<RealtimeAudioFormat>,
+    /// Transcription of input audio; `None` leaves it unchanged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_audio_transcription: Option<InputAudioTranscription>,
+    /// Turn detection; `None` leaves it unchanged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turn_detection: Option<TurnDetection>,
+    /// Functions the model may call.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<RealtimeTool>,
+    /// Tool choice: `auto`, `none`, `required`, or a function name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<String>,
+    /// Sampling temperature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+}
+
+/// Per-response overrides sent with `response.create`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ResponseConfig {
+    /// Output modalities for this response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modalities: Option<Vec<Modality>>,
+    /// Instructions for this response only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// Voice for this response only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<Voice>,
+    /// Tool choice for this response only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Conversation items
+// ---------------------------------------------------------------------------
+
+/// A content part of a realtime message item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ItemContent {
+    /// Text sent by the client.
+    InputText {
+        /// The text.
+        text: String,
+    },
+    /// Audio sent by the client, base64-encoded.
+    InputAudio {
+        /// Base64 audio.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        audio: Option<String>,
+        /// Transcript, when input transcription is enabled.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        transcript: Option<String>,
+    },
+    /// Text produced by the model.
+    #[serde(alias = "output_text")]
+    Text {
+        /// The text.
+        text: String,
+    },
+    /// Audio produced by the model.
+    #[serde(alias = "output_audio")]
+    Audio {
+        /// Base64 audio, when included.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        audio: Option<String>,
+        /// Transcript of the audio.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        transcript: Option<String>,
+    },
+}
+
+/// An item in the realtime conversation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConversationItem {
+    /// A message from the user, the assistant or the system.
+    Message {
+        /// Item ID (assigned by the server if omitted).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// Author of the message.
+        role: Role,
+        /// Message content.
+        content: Vec<ItemContent>,
+    },
+    /// A function call made by the model.
+    FunctionCall {
+        /// Item ID.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// ID used to return the function output.
+        call_id: String,
+        /// Function name.
+        name: String,
+        /// JSON-encoded arguments.
+        arguments: String,
+    },
+    /// The result of a function call, sent by the client.
+    FunctionCallOutput {
+        /// Item ID.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        /// The `call_id` of the function call.
+        call_id: String,
+        /// The function result, usually JSON.
+        output: String,
+    },
+}
+
+impl ConversationItem {
+    /// Create a user message with a single text part.
+    pub fn user_text(text: impl Into<String>) -> Self {
+        Self::Message {
+            id: None,
+            role: Role::User,
+            content: vec![ItemContent::InputText { text: text.into() }],
+        }
+    }
+
+    /// Create a function call result.
+    pub fn function_output(call_id: impl Into<String>, output: impl Into<String>) -> Self {
+        Self::FunctionCallOutput {
+            id: None,
+            call_id: call_id.into(),
+            output: output.into(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Client events
+// ---------------------------------------------------------------------------
+
+/// An event sent from the client to the realtime service.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum ClientEvent {
+    /// Update the session configuration.
+    #[serde(rename = "session.update")]
+    SessionUpdate {
+        /// Fields to change.
+        session: SessionConfig,
+    },
+    /// Append base64-encoded audio to the input buffer.
+    #[serde(rename = "input_audio_buffer.append")]
+    InputAudioBufferAppend {
+        /// Base64 audio in the session's input format.
+        audio: String,
+    },
+    /// Commit the input buffer as a user message.
+    #[serde(rename = "input_audio_buffer.commit")]
+    InputAudioBufferCommit,
+    /// Discard the input buffer.
+    #[serde(rename = "input_audio_buffer.clear")]
+    InputAudioBufferClear,
+    /// Add an item to the conversation.
+    #[serde(rename = "conversation.item.create")]
+    ConversationItemCreate {
+        /// The item to add.
+        item: ConversationItem,
+    },
+    /// Ask the model to respond.
+    #[serde(rename = "response.create")]
+    ResponseCreate {
+        /// Per-response overrides.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        response: Option<ResponseConfig>,
+    },
+    /// Cancel the response in progress.
+    #[serde(rename = "response.cancel")]
+    ResponseCancel,
+}
+
+impl ClientEvent {
+    /// Create an `input_audio_buffer.append` event from raw audio bytes.
+    pub fn append_audio(audio: &[u8]) -> Self {
+        use base64::Engine;
+
+        Self::InputAudioBufferAppend {
+            audio: base64::engine::general_purpose::STANDARD.encode(audio),
+        }
+    }
+
+    /// Create a `response.create` event using the session defaults.
+    pub fn create_response() -> Self {
+        Self::ResponseCreate { response: None }
+    }
+
+    /// Create a `conversation.item.create` event returning a function result.
+    pub fn function_output(call_id: impl Into<String>, output: impl Into<String>) -> Self {
+        Self::ConversationItemCreate {
+            item: ConversationItem::function_output(call_id, output),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Server events
+// ---------------------------------------------------------------------------
+
+/// An error reported by the realtime service.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RealtimeError {
+    /// Error category.
+    #[serde(rename = "type", default)]
+    pub error_type: Option<String>,
+    /// Error code.
+    #[serde(default)]
+    pub code: Option<String>,
+    /// Human-readable message.
+    pub message: String,
+    /// The parameter the error relates to.
+    #[serde(default)]
+    pub param: Option<String>,
+    /// ID of the client event that caused the error.
+    #[serde(default)]
+    pub event_id: Option<String>,
+}
+
+impl From<RealtimeError> for FoundryError {
+    fn from(error: RealtimeError) -> Self {
+        FoundryError::Api {
+            code: error
+                .code
+                .or(error.error_type)
+                .unwrap_or_else(|| "unknown".into()),
+            message: error.message,
+        }
+    }
+}
+
+/// An incremental piece of text, audio or transcript in a response.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RealtimeDelta {
+    /// The response being generated.
+    pub response_id: String,
+    /// The item being generated.
+    pub item_id: String,
+    /// Index of the item in the response output.
+    pub output_index: u32,
+    /// Index of the content part in the item.
+    pub content_index: u32,
+    /// The delta: text, or base64 audio for audio deltas.
+    pub delta: String,
+}
+
+impl RealtimeDelta {
+    /// Decode a base64 audio delta into raw audio bytes.
+    pub fn decode_audio(&self) -> FoundryResult<Vec<u8>> {
+        use base64::Engine;
+
+        base64::engine::general_purpose::STANDARD
+            .decode(&self.delta)
+            .map_err(|e| FoundryError::stream_with_source("invalid base64 audio delta", e))
+    }
+}
+
+/// A realtime response, as reported in `response.created` and `response.done`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RealtimeResponse {
+    /// Response ID.
+    pub id: String,
+    /// Status: `in_progress`, `completed`, `cancelled`, `incomplete` or `failed`.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Items produced by the response.
+    #[serde(default)]
+    pub output: Vec<ConversationItem>,
+    /// Token usage, when the response is done.
+    #[serde(default)]
+    pub usage: Option<serde_json::Value>,
+}
+
+impl RealtimeResponse {
+    /// Function calls made in this response.
+    pub fn function_calls(&self) -> impl Iterator<Item = &ConversationItem> {
+        self.output
+            .iter()
+            .filter(|item| matches!(item, ConversationItem::FunctionCall { .. }))
+    }
+}
+
+/// An event received from the realtime service.
+///
+/// Events this SDK does not model are returned as [`ServerEvent::Other`]
+/// with their raw JSON.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type")]
+pub enum ServerEvent {
+    /// The service reported an error. The session stays open.
+    #[serde(rename = "error")]
+    Error {
+        /// Error details.
+        error: RealtimeError,
+    },
+    /// The session was created.
+    #[serde(rename = "session.created")]
+    SessionCreated {
+        /// Initial session settings.
+        session: SessionConfig,
+    },
+    /// The session was updated.
+    #[serde(rename = "session.updated")]
+    SessionUpdated {
+        /// Current session settings.
+        session: SessionConfig,
+    },
+    /// Speech was detected in the input buffer.
+    #[serde(rename = "input_audio_buffer.speech_started")]
+    SpeechStarted {
+        /// Milliseconds into the buffer where speech started.
+        audio_start_ms: u64,
+        /// The user message item that will be created.
+        item_id: String,
+    },
+    /// The end of speech was detected in the input buffer.
+    #[serde(rename = "input_audio_buffer.speech_stopped")]
+    SpeechStopped {
+        /// Milliseconds into the buffer where speech ended.
+        audio_end_ms: u64,
+        /// The user message item that will be created.
+        item_id: String,
+    },
+    /// The input buffer was committed.
+    #[serde(rename = "input_audio_buffer.committed")]
+    InputAudioBufferCommitted {
+        /// The user message item created from the buffer.
+        item_id: String,
+    },
+    /// An item was added to the conversation.
+    #[serde(rename = "conversation.item.created")]
+    ConversationItemCreated {
+        /// The new item.
+        item: ConversationItem,
+    },
+    /// Transcription of user audio finished.
+    #[serde(rename = "conversation.item.input_audio_transcription.completed")]
+    InputAudioTranscriptionCompleted {
+        /// The user message item.
+        item_id: String,
+        /// The transcript.
+        transcript: String,
+    },
+    /// A response started.
+    #[serde(rename = "response.created")]
+    ResponseCreated {
+        /// The new response.
+        response: RealtimeResponse,
+    },
+    /// A response finished.
+    #[serde(rename = "response.done")]
+    ResponseDone {
+        /// The finished response with all output items.
+        response: RealtimeResponse,
+    },
+    /// A piece of text output.
+    #[serde(rename = "response.text.delta", alias = "response.output_text.delta")]
+    ResponseTextDelta(RealtimeDelta),
+    /// A piece of audio output (base64).
+    #[serde(rename = "response.audio.delta", alias = "response.output_audio.delta")]
+    ResponseAudioDelta(RealtimeDelta),
+    /// A piece of the transcript of audio output.
+    #[serde(
+        rename = "response.audio_transcript.delta",
+        alias = "response.output_audio_transcript.delta"
+    )]
+    ResponseAudioTranscriptDelta(RealtimeDelta),
+    /// A piece of function call arguments.
+    #[serde(rename = "response.function_call_arguments.delta")]
+    FunctionCallArgumentsDelta {
+        /// The function call ID.
+        call_id: String,
+        /// The arguments fragment.
+        delta: String,
+    },
+    /// Function call arguments are complete.
+    #[serde(rename = "response.function_call_arguments.done")]
+    FunctionCallArgumentsDone {
+        /// The function call ID.
+        call_id: String,
+        /// Function name, when sent by the service.
+        #[serde(default)]
+        name: Option<String>,
+        /// JSON-encoded arguments.
+        arguments: String,
+    },
+    /// Any other event.
+    #[serde(skip)]
+    Other {
+        /// The event `type`.
+        event_type: String,
+        /// The full event JSON.
+        data: serde_json::Value,
+    },
+}
+
+impl ServerEvent {
+    /// Parse a server event from its JSON text.
+    ///
+    /// Events without a `type`, or whose `type` is not modelled, become
+    /// [`ServerEvent::Other`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a stream error if `text` is not JSON, or if an event of a
+    /// modelled type does not match its expected shape.
+    pub fn from_json(text: &str) -> FoundryResult<Self> {
+        let data: serde_json::Value = serde_json::from_str(text)
+            .map_err(|e| FoundryError::stream_with_source("failed to parse realtime event", e))?;
+
+        let Some(event_type) = data.get("type").and_then(|t| t.as_str()) else {
+            return Ok(ServerEvent::Other {
+                event_type: String::new(),
+                data,
+            });
+        };
+        match ServerEvent::deserialize(&data) {
+            Ok(event) => Ok(event),
+            // serde reports a tag that matches no variant as an unknown variant.
+            Err(e) if e.to_string().starts_with("unknown variant") => Ok(ServerEvent::Other {
+                event_type: event_type.to_string(),
+                data,
+            }),
+            Err(e) => Err(FoundryError::stream_with_source(
+                format!("failed to parse realtime `{}` event", event_type),
+                e,
+            )),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Session
+// ---------------------------------------------------------------------------
+
+/// An open realtime session.
+///
+/// Use [`send`](Self::send) and [`next_event`](Self::next_event) from one
+/// task, or [`split`](Self::split) the session to send and receive from
+/// separate tasks.
+#[derive(Debug)]
+pub struct RealtimeSession {
+    sender: RealtimeSender,
+    receiver: RealtimeReceiver,
+}
+
+/// The sending half of a [`RealtimeSession`].
+#[derive(Debug)]
+pub struct RealtimeSender {
+    sink: SplitSink<Socket, Message>,
+}
+
+/// The receiving half of a [`RealtimeSession`].
+#[derive(Debug)]
+pub struct RealtimeReceiver {
+    stream: SplitStream<Socket>,
+}
+
+impl RealtimeSession {
+    /// Send an event to the service.
+    pub async fn send(&mut self, event: ClientEvent) -> FoundryResult<()> {
+        self.sender.send(event).await
+    }
+
+    /// Wait for the next event from the service.
+    ///
+    /// Returns `None` once the service closes the connection.
+    pub async fn next_event(&mut self) -> Option<FoundryResult<ServerEvent>> {
+        self.receiver.next_event().await
+    }
+
+    /// Split the session into halves that can be used from separate tasks.
+    pub fn split(self) -> (RealtimeSender, RealtimeReceiver) {
+        (self.sender, self.receiver)
+    }
+
+    /// Close the connection.
+    pub async fn close(self) -> FoundryResult<()> {
+        self.sender.close().await
+    }
+}
+
+impl RealtimeSender {
+    /// Send an event to the service.
+    pub async fn send(&mut self, event: ClientEvent) -> FoundryResult<()> {
+        let text = serde_json::to_string(&event)?;
+        self.sink
+            .send(Message::text(text))
+            .await
+            .map_err(websocket_error)
+    }
+
+    /// Close the connection.
+    pub async fn close(mut self) -> FoundryResult<()> {
+        match self.sink.close().await {
+            Ok(()) | Err(tungstenite::Error::ConnectionClosed) => Ok(()),
+            Err(e) => Err(websocket_error(e)),
+        }
+    }
+}
+
+impl RealtimeReceiver {
+    /// Wait for the next event from the service.
+    ///
+    /// Returns `None` once the service closes the connection.
+    pub async fn next_event(&mut self) -> Option<FoundryResult<ServerEvent>> {
+        loop {
+            let message = match self.stream.next().await? {
+                Ok(message) => message,
+                Err(tungstenite::Error::ConnectionClosed) => return None,
+                Err(e) => return Some(Err(websocket_error(e))),
+            };
+            match message {
+                Message::Text(text) => return Some(ServerEvent::from_json(text.as_str())),
+                Message::Close(_) => return None,
+                // Pings are answered by the WebSocket layer; binary frames are not used.
+                _ => continue,
+            }
+        }
+    }
+}
+
+/// Map a WebSocket error, surfacing handshake rejections as HTTP errors.
+///
+/// The rejection body is not included, as it may echo request headers.
+fn websocket_error(error: tungstenite::Error) -> FoundryError {
+    match error {
+        tungstenite::Error::Http(response) => {
+            let status = response.status();
+            FoundryError::http(
+                status.as_u16(),
+                format!(
+                    "realtime connection rejected: {}",
+                    status.canonical_reason().unwrap_or("unknown status")
+                ),
+            )
+        }
+        e => FoundryError::stream_with_source("realtime connection error", e),
+    }
+}
+
+/// Open a realtime session with a model deployment.
+///
+/// The connection uses the client's endpoint (with `https` switched to
+/// `wss`), its API version, and its credential for the `Authorization`
+/// header.
+///
+/// # Errors
+///
+/// Returns a validation error if `model` is empty, an HTTP error if the
+/// service rejects the handshake, or a stream error if the connection fails.
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::realtime::connect` with field `model`.
+#[tracing::instrument(name = "foundry::realtime::connect", skip(client))]
+pub async fn connect(client: &FoundryClient, model: &str) -> FoundryResult<RealtimeSession> {
+    if model.trim().is_empty() {
+        return Err(FoundryError::validation_field(
+            "model",
+            "model cannot be empty",
+        ));
+    }
+
+    let mut url = client.url(REALTIME_PATH)?;
+    url.query_pairs_mut().append_pair("model", model);
+    let scheme = if url.scheme() == "http" { "ws" } else { "wss" };
+    url.set_scheme(scheme)
+        .map_err(|()| FoundryError::validation("endpoint cannot be used for WebSocket"))?;
+
+    let mut request = url
+        .as_str()
+        .into_client_request()
+        .map_err(websocket_error)?;
+    let auth = client.credential().resolve().await?;
+    let headers = request.headers_mut();
+    headers.insert(
+        "Authorization",
+        HeaderValue::from_str(&auth)
+            .map_err(|_| FoundryError::auth("credential is not a valid header value"))?,
+    );
+    if let Ok(version) = HeaderValue::from_str(client.api_version()) {
+        headers.insert("api-version", version);
+    }
+
+    tracing::debug!("opening realtime connection");
+    let (socket, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .map_err(websocket_error)?;
+    tracing::debug!("realtime connection established");
+
+    let (sink, stream) = socket.split();
+    Ok(RealtimeSession {
+        sender: RealtimeSender { sink },
+        receiver: RealtimeReceiver { stream },
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TEST_API_KEY;
+    use azure_ai_foundry_core::auth::FoundryCredential;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    /// Start a one-connection WebSocket stand-in. The handler receives the
+    /// accepted socket; the request URI and headers are checked by `check`.
+    async fn start_server<F, Fut>(
+        check: fn(&Request),
+        handler: F,
+    ) -> (FoundryClient, tokio::task::JoinHandle<()>)
+    where
+        F: FnOnce(WebSocketStream<TcpStream>) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = ()> + Send,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let task = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            // The handshake callback signature is fixed by tungstenite.
+            #[allow(clippy::result_large_err)]
+            let socket = tokio_tungstenite::accept_hdr_async(stream, |req: &Request, res| {
+                check(req);
+                Ok::<Response, _>(res)
+            })
+            .await
+            .unwrap();
+            handler(socket).await;
+        });
+
+        let client = FoundryClient::builder()
+            .endpoint(format!("http://{addr}"))
+            .credential(FoundryCredential::api_key(TEST_API_KEY))
+            .build()
+            .unwrap();
+        (client, task)
+    }
+
+    fn text(value: serde_json::Value) -> Message {
+        Message::text(value.to_string())
+    }
+
+    #[test]
+    fn test_client_event_serialization() {
+        let event = ClientEvent::SessionUpdate {
+            session: SessionConfig {
+                voice: Some(Voice::Alloy),
+                input_audio_format: Some(RealtimeAudioFormat::Pcm16),
+                turn_detection: Some(TurnDetection::ServerVad {
+                    threshold: Some(0.5),
+                    prefix_padding_ms: None,
+                    silence_duration_ms: Some(500),
+                    create_response: None,
+                }),
+                tools: vec![RealtimeTool::function(
+                    "get_weather",
+                    "Get the weather",
+                    serde_json::json!({"type": "object"}),
+                )],
+                ..Default::default()
+            },
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "session.update");
+        assert_eq!(json["session"]["voice"], "alloy");
+        assert_eq!(json["session"]["input_audio_format"], "pcm16");
+        assert_eq!(json["session"]["turn_detection"]["type"], "server_vad");
+        assert_eq!(
+            json["session"]["turn_detection"]["silence_duration_ms"],
+            500
+        );
+        assert_eq!(json["session"]["tools"][0]["type"], "function");
+        assert!(json["session"].get("id").is_none());
+        assert!(json["session"].get("instructions").is_none());
+
+        let json = serde_json::to_value(ClientEvent::append_audio(&[1, 2])).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "input_audio_buffer.append", "audio": "AQI="})
+        );
+
+        let json = serde_json::to_value(ClientEvent::InputAudioBufferCommit).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "input_audio_buffer.commit"})
+        );
+
+        let json = serde_json::to_value(ClientEvent::function_output("call_1", "{}")).unwrap();
+        assert_eq!(json["item"]["type"], "function_call_output");
+        assert_eq!(json["item"]["call_id"], "call_1");
+        assert!(json["item"].get("id").is_none());
+    }
+
+    #[test]
+    fn test_server_event_audio_delta() {
+        let event = ServerEvent::from_json(
+            r#"{"type":"response.audio.delta","event_id":"e1","response_id":"r1","item_id":"i1","output_index":0,"content_index":0,"delta":"AwQ="}"#,
+        )
+        .unwrap();
+        match event {
+            ServerEvent::ResponseAudioDelta(delta) => {
+                assert_eq!(delta.response_id, "r1");
+                assert_eq!(delta.decode_audio().unwrap(), vec![3, 4]);
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_server_event_function_call_in_response_done() {
+        let event = ServerEvent::from_json(
+            &serde_json::json!({
+                "type": "response.done",
+                "response": {
+                    "id": "r1",
+                    "status": "completed",
+                    "output": [{
+                        "type": "function_call",
+                        "id": "i1",
+                        "call_id": "call_1",
+                        "name": "get_weather",
+                        "arguments": "{\"city\":\"Paris\"}"
+                    }]
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let ServerEvent::ResponseDone { response } = event else {
+            panic!("expected response.done");
+        };
+        let calls: Vec<_> = response.function_calls().collect();
+        assert_eq!(calls.len(), 1);
+        assert!(matches!(
+            calls[0],
+            ConversationItem::FunctionCall { name, .. } if name == "get_weather"
+        ));
+    }
+
+    #[test]
+    fn test_server_event_unknown_is_other() {
+        let event =
+            ServerEvent::from_json(r#"{"type":"rate_limits.updated","rate_limits":[]}"#).unwrap();
+        match event {
+            ServerEvent::Other { event_type, data } => {
+                assert_eq!(event_type, "rate_limits.updated");
+                assert!(data["rate_limits"].is_array());
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+
+        assert!(ServerEvent::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_server_event_known_type_with_bad_payload_is_error() {
+        let err = ServerEvent::from_json(r#"{"type":"error","error":"rate limited"}"#)
+            .expect_err("malformed error event should not become Other");
+        assert!(matches!(err, FoundryError::Stream { .. }), "{err:?}");
+        assert!(err.to_string().contains("`error` event"), "{err}");
+    }
+
+    #[test]
+    fn test_realtime_error_into_foundry_error() {
+        let event = ServerEvent::from_json(
+            r#"{"type":"error","error":{"type":"invalid_request_error","code":"invalid_value","message":"bad voice"}}"#,
+        )
+        .unwrap();
+        let ServerEvent::Error { error } = event else {
+            panic!("expected error event");
+        };
+        let err = FoundryError::from(error);
+        assert!(matches!(err, FoundryError::Api { ref code, .. } if code == "invalid_value"));
+        assert!(err.to_string().contains("bad voice"));
+    }
+
+    #[tokio::test]
+    async fn test_connect_sends_auth_and_exchanges_events() {
+        let (client, server) = start_server(
+            |req| {
+                assert_eq!(req.uri().path(), "/openai/v1/realtime");
+                assert_eq!(req.uri().query(), Some("model=gpt-4o-realtime-preview"));
+                assert_eq!(
+                    req.headers()["Authorization"],
+                    format!("Bearer {TEST_API_KEY}").as_str()
+                );
+            },
+            |mut socket| async move {
+                socket
+                    .send(text(serde_json::json!({
+                        "type": "session.created",
+                        "session": {"id": "sess_1", "model": "gpt-4o-realtime-preview", "voice": "alloy"}
+                    })))
+                    .await
+                    .unwrap();
+
+                let received = socket.next().await.unwrap().unwrap();
+                let received: serde_json::Value =
+                    serde_json::from_str(received.to_text().unwrap()).unwrap();
+                assert_eq!(received["type"], "response.create");
+
+                socket
+                    .send(text(serde_json::json!({
+                        "type": "response.audio.delta",
+                        "response_id": "r1", "item_id": "i1",
+                        "output_index": 0, "content_index": 0, "delta": "AQI="
+                    })))
+                    .await
+                    .unwrap();
+                socket.close(None).await.unwrap();
+            },
+        )
+        .await;
+
+        let mut session = connect(&client, "gpt-4o-realtime-preview")
+            .await
+            .expect("should connect");
+
+        let event = session.next_event().await.unwrap().unwrap();
+        let ServerEvent::SessionCreated { session: info } = event else {
+            panic!("expected session.created, got {event:?}");
+        };
+        assert_eq!(info.id.as_deref(), Some("sess_1"));
+        assert_eq!(info.voice, Some(Voice::Alloy));
+
+        session.send(ClientEvent::create_response()).await.unwrap();
+
+        let event = session.next_event().await.unwrap().unwrap();
+        assert!(matches!(event, ServerEvent::ResponseAudioDelta(_)));
+        assert!(session.next_event().await.is_none());
+
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_split_session_halves() {
+        let (client, server) = start_server(
+            |_| {},
+            |mut socket| async move {
+                let received = socket.next().await.unwrap().unwrap();
+                assert!(received
+                    .to_text()
+                    .unwrap()
+                    .contains("input_audio_buffer.commit"));
+                socket
+                    .send(text(serde_json::json!({
+                        "type": "input_audio_buffer.committed",
+                        "item_id": "i1"
+                    })))
+                    .await
+                    .unwrap();
+                socket.close(None).await.unwrap();
+            },
+        )
+        .await;
+
+        let session = connect(&client, "gpt-4o-realtime-preview").await.unwrap();
+        let (mut sender, mut receiver) = session.split();
+
+        let reader = tokio::spawn(async move { receiver.next_event().await });
+        sender
+            .send(ClientEvent::InputAudioBufferCommit)
+            .await
+            .unwrap();
+
+        let event = reader.await.unwrap().unwrap().unwrap();
+        assert!(matches!(
+            event,
+            ServerEvent::InputAudioBufferCommitted { item_id } if item_id == "i1"
+        ));
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_empty_model() {
+        let client = FoundryClient::builder()
+            .endpoint("http://127.0.0.1:9")
+            .credential(FoundryCredential::api_key(TEST_API_KEY))
+            .build()
+            .unwrap();
+        let err = connect(&client, " ").await.unwrap_err();
+        assert!(matches!(err, FoundryError::Validation { .. }));
+    }
+}
@@ -0,0 +1,513 @@
+//! Rerank types and API calls for Azure AI Foundry Models.
+//!
+//! Reranking scores a list of documents by relevance to a query, typically
+//! to reorder chunks retrieved for RAG. This module targets Cohere Rerank
+//! models deployed in Foundry.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_models::rerank::{rerank, RerankRequest};
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let chunks = vec![
+//!     "Carson City is the capital of Nevada.".to_string(),
+//!     "Washington, D.C. is the capital of the United States.".to_string(),
+//!     "Capital punishment has existed in the United States since before it was a country.".to_string(),
+//! ];
+//!
+//! let request = RerankRequest::builder()
+//!     .model("Cohere-rerank-v3.5")
+//!     .query("What is the capital of the United States?")
+//!     .documents(chunks.iter().cloned())
+//!     .top_n(2)
+//!     .build();
+//!
+//! let response = rerank(client, &request).await?;
+//! for (chunk, score) in response.ranked(&chunks) {
+//!     println!("{:.3} {}", score, chunk);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use azure_ai_foundry_core::client::FoundryClient;
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use serde::{Deserialize, Serialize};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Maximum number of documents in one rerank request.
+pub const MAX_RERANK_DOCUMENTS: usize = 1000;
+
+/// API path for reranking.
+const RERANK_PATH: &str = "/providers/cohere/v2/rerank";
+
+// ---------------------------------------------------------------------------
+// Request types
+// ---------------------------------------------------------------------------
+
+/// A document to rerank: plain text or a structured record.
+///
+/// The v2 rerank API only accepts strings, so records are sent as YAML
+/// (`key: value` per line), the format Cohere recommends for structured data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RerankDocument {
+    /// A plain-text document.
+    Text(String),
+    /// A structured record of named fields.
+    Record(serde_json::Map<String, serde_json::Value>),
+}
+
+impl RerankDocument {
+    /// The document text: the string itself, or a record's `text` field.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            Self::Record(record) => record.get("text").and_then(|t| t.as_str()),
+        }
+    }
+}
+
+impl Serialize for RerankDocument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Text(text) => serializer.serialize_str(text),
+            Self::Record(record) => serializer.serialize_str(&record_to_yaml(record)),
+        }
+    }
+}
+
+/// Render a record as YAML, one `key: value` line per field.
+///
+/// Strings that YAML would misread are double-quoted, and nested values are
+/// written as JSON, which YAML reads as flow style.
+fn record_to_yaml(record: &serde_json::Map<String, serde_json::Value>) -> String {
+    let mut lines = Vec::with_capacity(record.len());
+    for (key, value) in record {
+        let key = yaml_scalar(key);
+        let value = match value {
+            serde_json::Value::String(text) => yaml_scalar(text),
+            other => other.to_string(),
+        };
+        lines.push(format!("{}: {}", key, value));
+    }
+    lines.join("\n")
+}
+
+/// A YAML scalar for `text`, quoted when it is not a safe plain scalar.
+fn yaml_scalar(text: &str) -> String {
+    let plain = !text.is_empty()
+        && text.trim() == text
+        && !text.contains([':', '#', '\n', '\r', '"', '\''])
+        && !text.starts_with(|c: char| "-?,[]{}&*!|>%@`".contains(c))
+        && !matches!(
+            text.to_ascii_lowercase().as_str(),
+            "null" | "~" | "true" | "false" | "yes" | "no" | "on" | "off"
+        )
+        && text.parse::<f64>().is_err();
+    if plain {
+        text.to_string()
+    } else {
+        serde_json::Value::from(text).to_string()
+    }
+}
+
+impl From<String> for RerankDocument {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for RerankDocument {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<serde_json::Map<String, serde_json::Value>> for RerankDocument {
+    fn from(record: serde_json::Map<String, serde_json::Value>) -> Self {
+        Self::Record(record)
+    }
+}
+
+/// A rerank request.
+#[derive(Debug, Clone, Serialize)]
+pub struct RerankRequest {
+    pub model: String,
+    pub query: String,
+    pub documents: Vec<RerankDocument>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_n: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens_per_doc: Option<u32>,
+}
+
+impl RerankRequest {
+    /// Create a new builder.
+    pub fn builder() -> RerankRequestBuilder {
+        RerankRequestBuilder {
+            model: None,
+            query: None,
+            documents: Vec::new(),
+            top_n: None,
+            max_tokens_per_doc: None,
+        }
+    }
+}
+
+/// Builder for [`RerankRequest`].
+#[derive(Debug)]
+pub struct RerankRequestBuilder {
+    model: Option<String>,
+    query: Option<String>,
+    documents: Vec<RerankDocument>,
+    top_n: Option<u32>,
+    max_tokens_per_doc: Option<u32>,
+}
+
+impl RerankRequestBuilder {
+    /// Set the model.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Set the query documents are scored against.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Add a document.
+    pub fn document(mut self, document: impl Into<RerankDocument>) -> Self {
+        self.documents.push(document.into());
+        self
+    }
+
+    /// Add several documents.
+    pub fn documents<I, D>(mut self, documents: I) -> Self
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<RerankDocument>,
+    {
+        self.documents.extend(documents.into_iter().map(Into::into));
+        self
+    }
+
+    /// Return only the `n` most relevant documents.
+    pub fn top_n(mut self, n: u32) -> Self {
+        self.top_n = Some(n);
+        self
+    }
+
+    /// Truncate each document to this many tokens before scoring.
+    pub fn max_tokens_per_doc(mut self, max: u32) -> Self {
+        self.max_tokens_per_doc = Some(max);
+        self
+    }
+
+    /// Build the request, returning an error if required fields are missing or invalid.
+    pub fn try_build(self) -> FoundryResult<RerankRequest> {
+        let model = self
+            .model
+            .ok_or_else(|| FoundryError::Builder("model is required".into()))?;
+        let query = self
+            .query
+            .ok_or_else(|| FoundryError::Builder("query is required".into()))?;
+        if query.trim().is_empty() {
+            return Err(FoundryError::Builder("query cannot be empty".into()));
+        }
+
+        if self.documents.is_empty() {
+            return Err(FoundryError::Builder(
+                "at least one document is required".into(),
+            ));
+        }
+        if self.documents.len() > MAX_RERANK_DOCUMENTS {
+            return Err(FoundryError::Builder(format!(
+                "at most {} documents are allowed, got {}",
+                MAX_RERANK_DOCUMENTS,
+                self.documents.len()
+            )));
+        }
+
+        if self.top_n == Some(0) {
+            return Err(FoundryError::Builder("top_n must be at least 1".into()));
+        }
+        if self.max_tokens_per_doc == Some(0) {
+            return Err(FoundryError::Builder(
+                "max_tokens_per_doc must be at least 1".into(),
+            ));
+        }
+
+        Ok(RerankRequest {
+            model,
+            query,
+            documents: self.documents,
+            top_n: self.top_n,
+            max_tokens_per_doc: self.max_tokens_per_doc,
+        })
+    }
+
+    /// Build the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `model`, `query` or documents are missing, or a value is invalid.
+    /// Use [`try_build`](Self::try_build) for fallible construction.
+    pub fn build(self) -> RerankRequest {
+        self.try_build().expect("builder validation failed")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Response types
+// ---------------------------------------------------------------------------
+
+/// A rerank response, with results ordered from most to least relevant.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RerankResponse {
+    /// Request identifier.
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Scored documents, most relevant first.
+    pub results: Vec<RerankResult>,
+    /// Billing information.
+    #[serde(default)]
+    pub meta: Option<RerankMeta>,
+}
+
+impl RerankResponse {
+    /// Pair each result with the caller's original document, most relevant first.
+    ///
+    /// Results whose index falls outside `documents` are skipped.
+    pub fn ranked<'a, T>(&self, documents: &'a [T]) -> Vec<(&'a T, f64)> {
+        self.results
+            .iter()
+            .filter_map(|r| Some((documents.get(r.index)?, r.relevance_score)))
+            .collect()
+    }
+}
+
+/// The score of one document.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RerankResult {
+    /// Position of the document in the request.
+    pub index: usize,
+    /// Relevance to the query, from 0 to 1.
+    pub relevance_score: f64,
+}
+
+/// Billing information for a rerank request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RerankMeta {
+    /// Units billed for the request.
+    #[serde(default)]
+    pub billed_units: Option<RerankBilledUnits>,
+}
+
+/// Units billed for a rerank request.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct RerankBilledUnits {
+    /// Number of search units.
+    #[serde(default)]
+    pub search_units: Option<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// API functions
+// ---------------------------------------------------------------------------
+
+/// Rerank documents by relevance to a query.
+///
+/// # Tracing
+///
+/// This function emits a span named `foundry::rerank::rerank` with the following fields:
+/// - `model`: The reranking model
+/// - `document_count`: Number of documents being scored
+/// - `search_units`: Billed search units (recorded after response)
+#[tracing::instrument(
+    name = "foundry::rerank::rerank",
+    skip(client, request),
+    fields(model = %request.model, document_count = request.documents.len(), search_units)
+)]
+pub async fn rerank(
+    client: &FoundryClient,
+    request: &RerankRequest,
+) -> FoundryResult<RerankResponse> {
+    tracing::debug!("sending rerank request");
+
+    let response = client.post(RERANK_PATH, request).await?;
+    let body = response.json::<RerankResponse>().await?;
+
+    if let Some(units) = body
+        .meta
+        .as_ref()
+        .and_then(|m| m.billed_units)
+        .and_then(|b| b.search_units)
+    {
+        tracing::Span::current().record("search_units", units);
+    }
+
+    Ok(body)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup_mock_client;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_builder_validation() {
+        let err = RerankRequest::builder()
+            .model("Cohere-rerank-v3.5")
+            .document("a")
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("query is required"));
+
+        let err = RerankRequest::builder()
+            .model("Cohere-rerank-v3.5")
+            .query("q")
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("at least one document"));
+
+        let err = RerankRequest::builder()
+            .model("Cohere-rerank-v3.5")
+            .query("q")
+            .document("a")
+            .top_n(0)
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("top_n"));
+
+        let err = RerankRequest::builder()
+            .model("Cohere-rerank-v3.5")
+            .query("q")
+            .documents(std::iter::repeat_n("doc", MAX_RERANK_DOCUMENTS + 1))
+            .try_build()
+            .unwrap_err();
+        assert!(err.to_string().contains("at most 1000 documents"));
+    }
+
+    #[test]
+    fn test_document_serialization() {
+        let mut record = serde_json::Map::new();
+        record.insert("title".into(), "Nevada".into());
+        record.insert("text".into(), "Carson City".into());
+
+        let request = RerankRequest::builder()
+            .model("Cohere-rerank-v3.5")
+            .query("capital")
+            .document("plain")
+            .document(record.clone())
+            .build();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["documents"][0], "plain");
+        assert_eq!(json["documents"][1], "text: Carson City\ntitle: Nevada");
+        assert!(json.get("top_n").is_none());
+
+        assert_eq!(RerankDocument::Record(record).text(), Some("Carson City"));
+    }
+
+    #[test]
+    fn test_record_yaml_quotes_ambiguous_values() {
+        let record = serde_json::json!({
+            "title": "Note: draft",
+            "year": 2024,
+            "tags": ["a", "b"],
+            "flag": "yes",
+            "count": "42",
+            "multi line": "one\ntwo"
+        });
+        let serde_json::Value::Object(record) = record else {
+            unreachable!()
+        };
+        assert_eq!(
+            record_to_yaml(&record),
+            "count: \"42\"\nflag: \"yes\"\nmulti line: \"one\\ntwo\"\ntags: [\"a\",\"b\"]\ntitle: \"Note: draft\"\nyear: 2024"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rerank_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/providers/cohere/v2/rerank"))
+            .and(body_json(serde_json::json!({
+                "model": "Cohere-rerank-v3.5",
+                "query": "capital of the US",
+                "documents": ["Carson City", "Washington, D.C.", "Capital punishment"],
+                "top_n": 2
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "rr-1",
+                "results": [
+                    {"index": 1, "relevance_score": 0.98},
+                    {"index": 0, "relevance_score": 0.12}
+                ],
+                "meta": {"billed_units": {"search_units": 1}}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let documents = ["Carson City", "Washington, D.C.", "Capital punishment"];
+        let request = RerankRequest::builder()
+            .model("Cohere-rerank-v3.5")
+            .query("capital of the US")
+            .documents(documents)
+            .top_n(2)
+            .build();
+
+        let response = rerank(&client, &request).await.expect("should succeed");
+        assert_eq!(response.results.len(), 2);
+        let ranked = response.ranked(&documents);
+        assert_eq!(ranked[0], (&"Washington, D.C.", 0.98));
+        assert_eq!(ranked[1].0, &"Carson City");
+        assert_eq!(
+            response.meta.unwrap().billed_units.unwrap().search_units,
+            Some(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rerank_api_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/providers/cohere/v2/rerank"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": {"code": "invalid_request", "message": "query too long"}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = RerankRequest::builder()
+            .model("Cohere-rerank-v3.5")
+            .query("q")
+            .document("a")
+            .build();
+
+        let err = rerank(&client, &request).await.unwrap_err();
+        assert!(err.to_string().contains("query too long"));
+    }
+}
@@ -22,6 +22,71 @@
 //! # }
 //! ```
 //!
+//! # Stream a Response
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_models::responses::*;
+//! # use futures::StreamExt;
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let request = CreateResponseRequest::builder()
+//!     .model("gpt-4o")
+//!     .input("What is Rust?")
+//!     .build();
+//!
+//! let mut stream = create_stream(client, &request).await?;
+//! while let Some(event) = stream.next().await {
+//!     if let ResponseStreamEvent::OutputTextDelta { delta, .. } = event? {
+//!         print!("{}", delta);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Function Calling
+//!
+//! ```rust,no_run
+//! # use azure_ai_foundry_core::client::FoundryClient;
+//! # use azure_ai_foundry_models::responses::*;
+//! # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let weather = ResponseTool::function(
+//!     "get_weather",
+//!     "Get the current weather for a city",
+//!     serde_json::json!({
+//!         "type": "object",
+//!         "properties": { "city": { "type": "string" } },
+//!         "required": ["city"]
+//!     }),
+//! );
+//!
+//! let request = CreateResponseRequest::builder()
+//!     .model("gpt-4o")
+//!     .input("What's the weather in Paris?")
+//!     .tool(weather.clone())
+//!     .build();
+//! let response = create(client, &request).await?;
+//!
+//! // Answer each function call and continue from the previous response.
+//! let mut follow_up = CreateResponseRequest::builder()
+//!     .model("gpt-4o")
+//!     .previous_response_id(&response.id)
+//!     .tool(weather);
+//! for call in response.function_calls() {
+//!     let call_id = call.call_id.as_deref().unwrap_or_default();
+//!     follow_up = follow_up.function_output(call_id, r#"{"temperature_c": 18}"#);
+//! }
+//! let final_response = create(client, &follow_up.build()).await?;
+//! println!("{:?}", final_response.output_text());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Multi-turn Conversations
+//!
+//! [`Conversation`] threads `previous_response_id` across turns and falls
+//! back to replaying the local history when a previous response is gone.
+//!
 //! # Get a Previous Response
 //!
 //! ```rust,no_run
@@ -50,10 +115,14 @@
 
 use azure_ai_foundry_core::client::FoundryClient;
 use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use azure_ai_foundry_core::sse::SseEvent;
+use futures::Stream;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
-use crate::chat::Role;
+use crate::chat::{parse_sse_stream, Role};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -78,6 +147,8 @@ pub enum ResponseInput {
     Text(String),
     /// A list of structured messages.
     Messages(Vec<ResponseMessage>),
+    /// A list of input items (messages and function call results).
+    Items(Vec<ResponseInputItem>),
 }
 
 impl Serialize for ResponseInput {
@@ -88,6 +159,7 @@ impl Serialize for ResponseInput {
         match self {
             Self::Text(s) => s.serialize(serializer),
             Self::Messages(msgs) => msgs.serialize(serializer),
+            Self::Items(items) => items.serialize(serializer),
         }
     }
 }
@@ -137,6 +209,223 @@ impl ResponseMessage {
 // Request types
 // ---------------------------------------------------------------------------
 
+/// An input item for a response request.
+///
+/// Used with [`ResponseInput::Items`] to mix messages with function call
+/// results, e.g. when submitting function outputs together with
+/// `previous_response_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseInputItem {
+    /// A conversation message.
+    Message(ResponseMessage),
+    /// A function call previously emitted by the model (for replaying history).
+    FunctionCall {
+        /// The call ID that links the call with its output.
+        call_id: String,
+        /// The name of the function.
+        name: String,
+        /// The JSON arguments string.
+        arguments: String,
+    },
+    /// The result of a function call.
+    FunctionCallOutput {
+        /// The call ID from the model's function call output item.
+        call_id: String,
+        /// The function result, usually a JSON string.
+        output: String,
+    },
+}
+
+impl ResponseInputItem {
+    /// Create a function call output item.
+    pub fn function_call_output(call_id: impl Into<String>, output: impl Into<String>) -> Self {
+        Self::FunctionCallOutput {
+            call_id: call_id.into(),
+            output: output.into(),
+        }
+    }
+}
+
+impl From<ResponseMessage> for ResponseInputItem {
+    fn from(message: ResponseMessage) -> Self {
+        Self::Message(message)
+    }
+}
+
+/// A tool the model may use while generating a response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseTool {
+    /// A user-defined function the model can call.
+    Function {
+        /// The name of the function.
+        name: String,
+        /// What the function does, used by the model to decide when to call it.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// JSON Schema for the function arguments.
+        parameters: serde_json::Value,
+        /// Whether to enforce strict schema adherence.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        strict: Option<bool>,
+    },
+    /// Search over files in vector stores.
+    FileSearch {
+        /// IDs of the vector stores to search.
+        vector_store_ids: Vec<String>,
+        /// Maximum number of results to return.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_num_results: Option<u32>,
+    },
+    /// Search the web.
+    #[serde(rename = "web_search_preview")]
+    WebSearch {
+        /// How much context to retrieve (`"low"`, `"medium"`, `"high"`).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        search_context_size: Option<String>,
+    },
+    /// Run Python code in a sandboxed container.
+    CodeInterpreter {
+        /// The container, either a container ID string or `{"type": "auto"}`.
+        container: serde_json::Value,
+    },
+}
+
+impl ResponseTool {
+    /// Create a function tool.
+    pub fn function(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        parameters: serde_json::Value,
+    ) -> Self {
+        Self::Function {
+            name: name.into(),
+            description: Some(description.into()),
+            parameters,
+            strict: None,
+        }
+    }
+
+    /// Create a file search tool over the given vector stores.
+    pub fn file_search(vector_store_ids: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::FileSearch {
+            vector_store_ids: vector_store_ids.into_iter().map(Into::into).collect(),
+            max_num_results: None,
+        }
+    }
+
+    /// Create a web search tool with default settings.
+    pub fn web_search() -> Self {
+        Self::WebSearch {
+            search_context_size: None,
+        }
+    }
+
+    /// Create a code interpreter tool with an automatically managed container.
+    pub fn code_interpreter() -> Self {
+        Self::CodeInterpreter {
+            container: serde_json::json!({ "type": "auto" }),
+        }
+    }
+}
+
+/// Controls which tool (if any) the model calls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponseToolChoice {
+    /// The model will not call any tool.
+    None,
+    /// The model decides whether to call tools.
+    Auto,
+    /// The model must call at least one tool.
+    Required,
+    /// The model must call the named function.
+    Function(String),
+}
+
+impl Serialize for ResponseToolChoice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_str("none"),
+            Self::Auto => serializer.serialize_str("auto"),
+            Self::Required => serializer.serialize_str("required"),
+            Self::Function(name) => {
+                serde_json::json!({ "type": "function", "name": name }).serialize(serializer)
+            }
+        }
+    }
+}
+
+/// How much effort reasoning models spend before answering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningEffort {
+    /// Minimal reasoning.
+    Minimal,
+    /// Low effort.
+    Low,
+    /// Medium effort (service default).
+    Medium,
+    /// High effort.
+    High,
+}
+
+/// Level of detail of the reasoning summary returned by reasoning models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningSummaryMode {
+    /// Let the service choose.
+    Auto,
+    /// A short summary.
+    Concise,
+    /// A detailed summary.
+    Detailed,
+}
+
+/// Reasoning configuration for reasoning models (o-series, gpt-5).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReasoningConfig {
+    /// Reasoning effort.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort: Option<ReasoningEffort>,
+    /// Whether and how to summarize the reasoning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ReasoningSummaryMode>,
+}
+
+/// The format of the text output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TextFormat {
+    /// Plain text (default).
+    Text,
+    /// Any valid JSON object.
+    JsonObject,
+    /// JSON that conforms to the given schema (structured outputs).
+    JsonSchema {
+        /// Name of the schema.
+        name: String,
+        /// The JSON Schema.
+        schema: serde_json::Value,
+        /// What the output is for.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// Whether to enforce strict schema adherence.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        strict: Option<bool>,
+    },
+}
+
+/// Text output configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextConfig {
+    /// The output format.
+    pub format: TextFormat,
+}
+
 /// A request to create a response.
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateResponseRequest {
@@ -172,6 +461,38 @@ pub struct CreateResponseRequest {
     /// The ID of a previous response to continue from.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_response_id: Option<String>,
+
+    /// System-level instructions for this response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// Tools the model may call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<ResponseTool>>,
+
+    /// Which tool the model should call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ResponseToolChoice>,
+
+    /// Reasoning configuration for reasoning models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning: Option<ReasoningConfig>,
+
+    /// Text output configuration (e.g. structured outputs).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<TextConfig>,
+
+    /// Whether the service stores the response for later retrieval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<bool>,
+
+    /// Key-value metadata attached to the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// Run the response asynchronously; poll with [`get`] until it finishes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<bool>,
 }
 
 impl CreateResponseRequest {
@@ -187,6 +508,14 @@ impl CreateResponseRequest {
             presence_penalty: None,
             stop: None,
             previous_response_id: None,
+            instructions: None,
+            tools: None,
+            tool_choice: None,
+            reasoning: None,
+            text: None,
+            store: None,
+            metadata: None,
+            background: None,
         }
     }
 }
@@ -203,6 +532,14 @@ pub struct CreateResponseRequestBuilder {
     presence_penalty: Option<f32>,
     stop: Option<Vec<String>>,
     previous_response_id: Option<String>,
+    instructions: Option<String>,
+    tools: Option<Vec<ResponseTool>>,
+    tool_choice: Option<ResponseToolChoice>,
+    reasoning: Option<ReasoningConfig>,
+    text: Option<TextConfig>,
+    store: Option<bool>,
+    metadata: Option<HashMap<String, String>>,
+    background: Option<bool>,
 }
 
 impl CreateResponseRequestBuilder {
@@ -269,6 +606,96 @@ impl CreateResponseRequestBuilder {
         self
     }
 
+    /// Set the input to a list of input items.
+    pub fn input_items(mut self, items: impl IntoIterator<Item = ResponseInputItem>) -> Self {
+        self.input = Some(ResponseInput::Items(items.into_iter().collect()));
+        self
+    }
+
+    /// Append the output of a function call to the input.
+    ///
+    /// Combine with [`previous_response_id`](Self::previous_response_id) to
+    /// submit function results for the calls requested by that response.
+    /// Existing text or message input is converted to input items first.
+    pub fn function_output(
+        mut self,
+        call_id: impl Into<String>,
+        output: impl Into<String>,
+    ) -> Self {
+        let mut items = match self.input.take() {
+            None => Vec::new(),
+            Some(ResponseInput::Text(text)) => {
+                vec![ResponseInputItem::Message(ResponseMessage::user(text))]
+            }
+            Some(ResponseInput::Messages(msgs)) => {
+                msgs.into_iter().map(ResponseInputItem::Message).collect()
+            }
+            Some(ResponseInput::Items(items)) => items,
+        };
+        items.push(ResponseInputItem::function_call_output(call_id, output));
+        self.input = Some(ResponseInput::Items(items));
+        self
+    }
+
+    /// Set system-level instructions.
+    pub fn instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = Some(instructions.into());
+        self
+    }
+
+    /// Add a tool the model may call.
+    pub fn tool(mut self, tool: ResponseTool) -> Self {
+        self.tools.get_or_insert_with(Vec::new).push(tool);
+        self
+    }
+
+    /// Add multiple tools the model may call.
+    pub fn tools(mut self, tools: impl IntoIterator<Item = ResponseTool>) -> Self {
+        self.tools.get_or_insert_with(Vec::new).extend(tools);
+        self
+    }
+
+    /// Set which tool the model should call.
+    pub fn tool_choice(mut self, choice: ResponseToolChoice) -> Self {
+        self.tool_choice = Some(choice);
+        self
+    }
+
+    /// Set the reasoning configuration.
+    pub fn reasoning(mut self, reasoning: ReasoningConfig) -> Self {
+        self.reasoning = Some(reasoning);
+        self
+    }
+
+    /// Set the text output format.
+    pub fn text_format(mut self, format: TextFormat) -> Self {
+        self.text = Some(TextConfig { format });
+        self
+    }
+
+    /// Set whether the response is stored by the service.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Add a metadata key-value pair.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Run the response in the background.
+    ///
+    /// The service returns immediately with status `queued` or
+    /// `in_progress`; poll with [`get`] or stop it with [`cancel`].
+    pub fn background(mut self, background: bool) -> Self {
+        self.background = Some(background);
+        self
+    }
+
     /// Build the request, returning an error if required fields are missing
     /// or parameter values are invalid.
     pub fn try_build(self) -> FoundryResult<CreateResponseRequest> {
@@ -323,6 +750,12 @@ impl CreateResponseRequestBuilder {
             }
         }
 
+        if self.background == Some(true) && self.store == Some(false) {
+            return Err(FoundryError::Builder(
+                "background responses require store to be enabled".into(),
+            ));
+        }
+
         Ok(CreateResponseRequest {
             model,
             input,
@@ -333,6 +766,14 @@ impl CreateResponseRequestBuilder {
             presence_penalty: self.presence_penalty,
             stop: self.stop,
             previous_response_id: self.previous_response_id,
+            instructions: self.instructions,
+            tools: self.tools,
+            tool_choice: self.tool_choice,
+            reasoning: self.reasoning,
+            text: self.text,
+            store: self.store,
+            metadata: self.metadata,
+            background: self.background,
         })
     }
 
@@ -364,6 +805,17 @@ pub enum ResponseStatus {
     InProgress,
     /// The response was cancelled.
     Cancelled,
+    /// The response ended before completion (e.g. `max_output_tokens` reached).
+    Incomplete,
+    /// A background response is waiting to start.
+    Queued,
+}
+
+impl ResponseStatus {
+    /// Returns `true` if the response will not change anymore.
+    pub fn is_terminal(self) -> bool {
+        !matches!(self, Self::InProgress | Self::Queued)
+    }
 }
 
 /// A response from the Responses API.
@@ -385,6 +837,21 @@ pub struct Response {
     pub usage: Option<ResponseUsage>,
     /// Metadata associated with the response.
     pub metadata: Option<HashMap<String, String>>,
+    /// The ID of the response this one continues from.
+    #[serde(default)]
+    pub previous_response_id: Option<String>,
+    /// The error that made the response fail, if any.
+    #[serde(default)]
+    pub error: Option<ResponseError>,
+}
+
+/// Error details of a failed response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResponseError {
+    /// Error code.
+    pub code: String,
+    /// Human-readable error message.
+    pub message: String,
 }
 
 impl Response {
@@ -406,6 +873,31 @@ impl Response {
         }
         None
     }
+
+    /// Iterate over the function calls the model requested.
+    ///
+    /// Answer them with
+    /// [`CreateResponseRequestBuilder::function_output`] and
+    /// `previous_response_id` set to this response's ID.
+    pub fn function_calls(&self) -> impl Iterator<Item = &ResponseOutput> {
+        self.output
+            .iter()
+            .filter(|item| item.output_type == ResponseOutputType::FunctionCall)
+    }
+
+    /// Concatenate the reasoning summary texts of all reasoning items.
+    ///
+    /// Returns `None` if the response has no reasoning summary.
+    pub fn reasoning_summary(&self) -> Option<String> {
+        let parts: Vec<&str> = self
+            .output
+            .iter()
+            .filter(|item| item.output_type == ResponseOutputType::Reasoning)
+            .flat_map(|item| item.summary.iter().flatten())
+            .map(|part| part.text.as_str())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join("\n\n"))
+    }
 }
 
 /// The type of a response output item.
@@ -414,12 +906,35 @@ impl Response {
 pub enum ResponseOutputType {
     /// A message output containing text or other content blocks.
     Message,
+    /// A call to a user-defined function.
+    FunctionCall,
+    /// The result of a function call (only in input item lists).
+    FunctionCallOutput,
+    /// Reasoning performed by a reasoning model.
+    Reasoning,
+    /// A file search tool call and its results.
+    FileSearchCall,
+    /// A web search tool call.
+    WebSearchCall,
+    /// A code interpreter tool call and its outputs.
+    CodeInterpreterCall,
     /// An unknown output type returned by the API (forward-compatibility).
     #[serde(other)]
     Other,
 }
 
 /// An output item in a response.
+///
+/// Which optional fields are set depends on [`output_type`](Self::output_type):
+///
+/// | Type | Fields |
+/// |------|--------|
+/// | `Message` | `role`, `content` |
+/// | `FunctionCall` | `call_id`, `name`, `arguments` |
+/// | `FunctionCallOutput` | `call_id`, `output` |
+/// | `Reasoning` | `summary` |
+/// | `FileSearchCall` | `queries`, `results` |
+/// | `CodeInterpreterCall` | `code`, `outputs` |
 #[derive(Debug, Clone, Deserialize)]
 pub struct ResponseOutput {
     /// Unique identifier for the output item.
@@ -431,6 +946,56 @@ pub struct ResponseOutput {
     pub role: Option<crate::chat::Role>,
     /// The content blocks of the output.
     pub content: Option<Vec<ResponseContent>>,
+    /// The item status (e.g. `"completed"`, `"in_progress"`, `"searching"`).
+    #[serde(default)]
+    pub status: Option<String>,
+    /// The call ID linking a function call with its output.
+    #[serde(default)]
+    pub call_id: Option<String>,
+    /// The name of the called function.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The JSON arguments of the function call.
+    #[serde(default)]
+    pub arguments: Option<String>,
+    /// The function result (function call output items).
+    #[serde(default)]
+    pub output: Option<String>,
+    /// Reasoning summary parts.
+    #[serde(default)]
+    pub summary: Option<Vec<ReasoningSummaryPart>>,
+    /// Queries issued by the file search tool.
+    #[serde(default)]
+    pub queries: Option<Vec<String>>,
+    /// Results returned by the file search tool.
+    #[serde(default)]
+    pub results: Option<Vec<FileSearchResult>>,
+    /// Code run by the code interpreter tool.
+    #[serde(default)]
+    pub code: Option<String>,
+    /// Outputs (logs, images) produced by the code interpreter tool.
+    #[serde(default)]
+    pub outputs: Option<Vec<serde_json::Value>>,
+}
+
+/// A part of a reasoning summary.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReasoningSummaryPart {
+    /// The summary text.
+    pub text: String,
+}
+
+/// A single file search result.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FileSearchResult {
+    /// ID of the matching file.
+    pub file_id: Option<String>,
+    /// Name of the matching file.
+    pub filename: Option<String>,
+    /// Relevance score.
+    pub score: Option<f64>,
+    /// The matching text.
+    pub text: Option<String>,
 }
 
 /// The type of a content block within a response output item.
@@ -470,6 +1035,45 @@ pub struct ResponseUsage {
     pub total_tokens: u32,
 }
 
+/// Sort order for [`list_input_items`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListOrder {
+    /// Oldest first.
+    Asc,
+    /// Newest first.
+    Desc,
+}
+
+impl ListOrder {
+    /// Return the string representation used by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Asc => "asc",
+            Self::Desc => "desc",
+        }
+    }
+}
+
+/// A page of input items returned by [`list_input_items`].
+///
+/// Input items share the shape of [`ResponseOutput`]; messages carry
+/// `input_text` content blocks, which deserialize as
+/// [`ResponseContentType::Other`] with `text` set.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResponseInputItemList {
+    /// Object type, always "list".
+    pub object: String,
+    /// The input items.
+    pub data: Vec<ResponseOutput>,
+    /// ID of the first item in the list.
+    pub first_id: Option<String>,
+    /// ID of the last item in the list.
+    pub last_id: Option<String>,
+    /// Whether there are more items to fetch.
+    pub has_more: bool,
+}
+
 /// Response from deleting a response.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ResponseDeletionResponse {
@@ -481,6 +1085,223 @@ pub struct ResponseDeletionResponse {
     pub deleted: bool,
 }
 
+// ---------------------------------------------------------------------------
+// Streaming types
+// ---------------------------------------------------------------------------
+
+/// A semantic event from a streaming Responses API call.
+///
+/// Each Server-Sent Event carries a JSON payload whose `type` field selects
+/// the variant. Event types not modelled here deserialize as
+/// [`Other`](Self::Other) so new service events do not break the stream.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
+pub enum ResponseStreamEvent {
+    /// The response was created (`response.created`).
+    #[serde(rename = "response.created")]
+    Created {
+        /// The response snapshot, usually with empty output.
+        response: Response,
+    },
+
+    /// Generation is in progress (`response.in_progress`).
+    #[serde(rename = "response.in_progress")]
+    InProgress {
+        /// The response snapshot.
+        response: Response,
+    },
+
+    /// The response finished successfully (`response.completed`).
+    #[serde(rename = "response.completed")]
+    Completed {
+        /// The final response, including output and usage.
+        response: Response,
+    },
+
+    /// The response failed (`response.failed`).
+    #[serde(rename = "response.failed")]
+    Failed {
+        /// The final response snapshot.
+        response: Response,
+    },
+
+    /// The response ended early, e.g. on `max_output_tokens` (`response.incomplete`).
+    #[serde(rename = "response.incomplete")]
+    Incomplete {
+        /// The final response snapshot.
+        response: Response,
+    },
+
+    /// A new output item was added (`response.output_item.added`).
+    #[serde(rename = "response.output_item.added")]
+    OutputItemAdded {
+        /// Index of the item in the response output.
+        output_index: u32,
+        /// The item, usually without content yet.
+        item: ResponseOutput,
+    },
+
+    /// An output item is complete (`response.output_item.done`).
+    #[serde(rename = "response.output_item.done")]
+    OutputItemDone {
+        /// Index of the item in the response output.
+        output_index: u32,
+        /// The complete item.
+        item: ResponseOutput,
+    },
+
+    /// A content part was added to an output item (`response.content_part.added`).
+    #[serde(rename = "response.content_part.added")]
+    ContentPartAdded {
+        /// ID of the output item.
+        item_id: String,
+        /// Index of the item in the response output.
+        output_index: u32,
+        /// Index of the part in the item's content.
+        content_index: u32,
+        /// The content part, usually empty.
+        part: ResponseContent,
+    },
+
+    /// A content part is complete (`response.content_part.done`).
+    #[serde(rename = "response.content_part.done")]
+    ContentPartDone {
+        /// ID of the output item.
+        item_id: String,
+        /// Index of the item in the response output.
+        output_index: u32,
+        /// Index of the part in the item's content.
+        content_index: u32,
+        /// The complete content part.
+        part: ResponseContent,
+    },
+
+    /// A fragment of output text (`response.output_text.delta`).
+    #[serde(rename = "response.output_text.delta")]
+    OutputTextDelta {
+        /// ID of the output item.
+        item_id: String,
+        /// Index of the item in the response output.
+        output_index: u32,
+        /// Index of the part in the item's content.
+        content_index: u32,
+        /// The text to append.
+        delta: String,
+    },
+
+    /// The output text of a content part is complete (`response.output_text.done`).
+    #[serde(rename = "response.output_text.done")]
+    OutputTextDone {
+        /// ID of the output item.
+        item_id: String,
+        /// Index of the item in the response output.
+        output_index: u32,
+        /// Index of the part in the item's content.
+        content_index: u32,
+        /// The full text.
+        text: String,
+    },
+
+    /// A fragment of function call arguments (`response.function_call_arguments.delta`).
+    #[serde(rename = "response.function_call_arguments.delta")]
+    FunctionCallArgumentsDelta {
+        /// ID of the function call output item.
+        item_id: String,
+        /// Index of the item in the response output.
+        output_index: u32,
+        /// The JSON fragment to append.
+        delta: String,
+    },
+
+    /// Function call arguments are complete (`response.function_call_arguments.done`).
+    #[serde(rename = "response.function_call_arguments.done")]
+    FunctionCallArgumentsDone {
+        /// ID of the function call output item.
+        item_id: String,
+        /// Index of the item in the response output.
+        output_index: u32,
+        /// The full JSON arguments string.
+        arguments: String,
+    },
+
+    /// An event type not modelled by this SDK (forward-compatibility).
+    #[serde(other)]
+    Other,
+}
+
+impl ResponseStreamEvent {
+    /// The final response carried by a terminal event.
+    ///
+    /// Returns `Some` for [`Completed`](Self::Completed),
+    /// [`Failed`](Self::Failed) and [`Incomplete`](Self::Incomplete).
+    pub fn final_response(&self) -> Option<&Response> {
+        match self {
+            Self::Completed { response }
+            | Self::Failed { response }
+            | Self::Incomplete { response } => Some(response),
+            _ => None,
+        }
+    }
+}
+
+/// A stream of [`ResponseStreamEvent`]s returned by [`create_stream`].
+///
+/// Events are forwarded unchanged. When a terminal event arrives, its
+/// [`Response`] is kept and available from [`response`](Self::response) or
+/// [`into_response`](Self::into_response).
+pub struct ResponseStream<S> {
+    inner: Pin<Box<S>>,
+    response: Option<Response>,
+}
+
+impl<S> ResponseStream<S>
+where
+    S: Stream<Item = FoundryResult<ResponseStreamEvent>>,
+{
+    fn new(stream: S) -> Self {
+        Self {
+            inner: Box::pin(stream),
+            response: None,
+        }
+    }
+
+    /// The final response, once a terminal event has been received.
+    pub fn response(&self) -> Option<&Response> {
+        self.response.as_ref()
+    }
+
+    /// Consume the stream wrapper and return the final response, if any.
+    pub fn into_response(self) -> Option<Response> {
+        self.response
+    }
+}
+
+impl<S> std::fmt::Debug for ResponseStream<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseStream")
+            .field("response", &self.response)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Stream for ResponseStream<S>
+where
+    S: Stream<Item = FoundryResult<ResponseStreamEvent>>,
+{
+    type Item = FoundryResult<ResponseStreamEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let poll = this.inner.as_mut().poll_next(cx);
+        if let Poll::Ready(Some(Ok(ref event))) = poll {
+            if let Some(response) = event.final_response() {
+                this.response = Some(response.clone());
+            }
+        }
+        poll
+    }
+}
+
 // ---------------------------------------------------------------------------
 // API functions
 // ---------------------------------------------------------------------------
@@ -523,6 +1344,98 @@ pub async fn create(
     Ok(body)
 }
 
+/// Create a response and stream its events as they are generated.
+///
+/// Sends the request with `stream: true` and returns a [`ResponseStream`]
+/// of typed [`ResponseStreamEvent`]s. SSE decoding (including the
+/// [`SSE_BUFFER_LIMIT`](azure_ai_foundry_core::sse::SSE_BUFFER_LIMIT) protection)
+/// is handled by [`azure_ai_foundry_core::sse`]. An in-stream `error` event is
+/// yielded as [`FoundryError::Api`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::responses::*;
+/// # use futures::StreamExt;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let request = CreateResponseRequest::builder()
+///     .model("gpt-4o")
+///     .input("Tell me a story")
+///     .build();
+///
+/// let mut stream = create_stream(client, &request).await?;
+/// while let Some(event) = stream.next().await {
+///     if let ResponseStreamEvent::OutputTextDelta { delta, .. } = event? {
+///         print!("{}", delta);
+///     }
+/// }
+///
+/// if let Some(response) = stream.response() {
+///     println!("\nTokens: {:?}", response.usage.as_ref().map(|u| u.total_tokens));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::responses::create_stream` with field `model`.
+#[tracing::instrument(
+    name = "foundry::responses::create_stream",
+    skip(client, request),
+    fields(model = %request.model)
+)]
+pub async fn create_stream(
+    client: &FoundryClient,
+    request: &CreateResponseRequest,
+) -> FoundryResult<ResponseStream<impl Stream<Item = FoundryResult<ResponseStreamEvent>>>> {
+    tracing::debug!("initiating streaming response");
+
+    let stream_request = StreamingRequest {
+        request,
+        stream: true,
+    };
+
+    let response = client
+        .post_stream("/openai/v1/responses", &stream_request)
+        .await?;
+
+    tracing::debug!("stream initiated");
+
+    Ok(ResponseStream::new(parse_sse_stream(
+        response,
+        parse_stream_event,
+        client.stream_idle_timeout(),
+    )))
+}
+
+/// Internal request type for streaming responses.
+///
+/// Borrows the [`CreateResponseRequest`] and adds `stream: true`.
+#[derive(Serialize)]
+struct StreamingRequest<'a> {
+    #[serde(flatten)]
+    request: &'a CreateResponseRequest,
+    stream: bool,
+}
+
+/// Parse a single SSE event into a stream event, returning None for events that should be skipped.
+///
+/// In-stream `error` events never reach this function: the core SSE decoder
+/// already yields them as [`FoundryError::Api`].
+fn parse_stream_event(event: &SseEvent) -> Option<FoundryResult<ResponseStreamEvent>> {
+    // The payload's `type` field duplicates `event:`, so only `data` is needed.
+    if event.is_done() || event.data.trim().is_empty() {
+        return None;
+    }
+
+    Some(
+        serde_json::from_str(&event.data)
+            .map_err(|e| FoundryError::stream_with_source("failed to parse event", e)),
+    )
+}
+
 /// Get a previously created response by ID.
 ///
 /// # Example
@@ -592,9 +1505,359 @@ pub async fn delete(
     Ok(body)
 }
 
-// ---------------------------------------------------------------------------
-// Tests
-// ---------------------------------------------------------------------------
+/// Cancel a background response.
+///
+/// Only responses created with
+/// [`background(true)`](CreateResponseRequestBuilder::background) can be
+/// cancelled.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::responses;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let response = responses::cancel(client, "resp_abc123").await?;
+/// println!("Status: {:?}", response.status);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::responses::cancel` with field `response_id`.
+#[tracing::instrument(
+    name = "foundry::responses::cancel",
+    skip(client),
+    fields(response_id = %response_id)
+)]
+pub async fn cancel(client: &FoundryClient, response_id: &str) -> FoundryResult<Response> {
+    tracing::debug!("cancelling response");
+    FoundryClient::validate_resource_id(response_id)?;
+
+    let path = format!("/openai/v1/responses/{}/cancel", response_id);
+    let response = client.post(&path, &serde_json::json!({})).await?;
+    let body = response.json::<Response>().await?;
+    Ok(body)
+}
+
+/// List the input items of a response.
+///
+/// Pass the previous page's [`ResponseInputItemList::last_id`] as `after` to
+/// get the next page. `limit` and `order` default to the service's page size
+/// and order when `None`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::responses;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let items = responses::list_input_items(client, "resp_abc123", None, Some(50), None).await?;
+/// for item in &items.data {
+///     println!("{:?}", item.output_type);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Tracing
+///
+/// Emits a span named `foundry::responses::list_input_items` with field `response_id`.
+#[tracing::instrument(
+    name = "foundry::responses::list_input_items",
+    skip(client),
+    fields(response_id = %response_id)
+)]
+pub async fn list_input_items(
+    client: &FoundryClient,
+    response_id: &str,
+    after: Option<&str>,
+    limit: Option<u32>,
+    order: Option<ListOrder>,
+) -> FoundryResult<ResponseInputItemList> {
+    tracing::debug!("listing response input items");
+    FoundryClient::validate_resource_id(response_id)?;
+
+    let mut query = Vec::new();
+    if let Some(after) = after {
+        FoundryClient::validate_resource_id(after)?;
+        query.push(format!("after={}", after));
+    }
+    if let Some(limit) = limit {
+        query.push(format!("limit={}", limit));
+    }
+    if let Some(order) = order {
+        query.push(format!("order={}", order.as_str()));
+    }
+    let mut path = format!("/openai/v1/responses/{}/input_items", response_id);
+    if !query.is_empty() {
+        path = format!("{}?{}", path, query.join("&"));
+    }
+    let response = client.get(&path).await?;
+    let list = response.json::<ResponseInputItemList>().await?;
+
+    tracing::debug!(count = list.data.len(), "input items listed");
+    Ok(list)
+}
+
+// ---------------------------------------------------------------------------
+// Conversation state
+// ---------------------------------------------------------------------------
+
+/// A multi-turn conversation on top of the Responses API.
+///
+/// Each call to [`send`](Self::send) chains onto the previous turn through
+/// `previous_response_id`, so only the new input is uploaded. If the service
+/// no longer has the previous response (deleted, expired, or created with
+/// `store: false`), the full history recorded locally is replayed instead
+/// and the chain continues from the new response.
+///
+/// The conversation is `Serialize`/`Deserialize`, so a session can be
+/// persisted and resumed after a restart. Use [`branch_at`](Self::branch_at)
+/// to explore an alternative continuation from an earlier turn.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use azure_ai_foundry_core::client::FoundryClient;
+/// # use azure_ai_foundry_models::responses::Conversation;
+/// # async fn example(client: &FoundryClient) -> azure_ai_foundry_core::error::FoundryResult<()> {
+/// let mut conversation = Conversation::new("gpt-4o").with_instructions("Be concise.");
+///
+/// let first = conversation.send(client, "What is Rust?").await?;
+/// println!("{:?}", first.output_text());
+///
+/// let second = conversation.send(client, "Who created it?").await?;
+/// println!("{:?}", second.output_text());
+///
+/// // Persist the session.
+/// let saved = serde_json::to_string(&conversation)?;
+/// let restored: Conversation = serde_json::from_str(&saved)?;
+///
+/// // Try a different follow-up to the first answer.
+/// let mut alternative = restored.branch_at(1)?;
+/// alternative.send(client, "How does it compare to Go?").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conversation {
+    model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    instructions: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tools: Vec<ResponseTool>,
+    #[serde(default)]
+    turns: Vec<ConversationTurn>,
+}
+
+/// One request/response exchange of a [`Conversation`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationTurn {
+    /// The input items sent in this turn.
+    pub input: Vec<ResponseInputItem>,
+    /// The ID of the response that answered this turn.
+    pub response_id: String,
+    /// The model output of this turn, as input items for replay.
+    ///
+    /// Contains assistant messages and function calls; reasoning and
+    /// built-in tool items are not replayable and are omitted.
+    pub output: Vec<ResponseInputItem>,
+}
+
+impl Conversation {
+    /// Start an empty conversation with the given model.
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            instructions: None,
+            tools: Vec::new(),
+            turns: Vec::new(),
+        }
+    }
+
+    /// Set instructions sent with every turn.
+    pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = Some(instructions.into());
+        self
+    }
+
+    /// Add a tool offered to the model on every turn.
+    pub fn with_tool(mut self, tool: ResponseTool) -> Self {
+        self.tools.push(tool);
+        self
+    }
+
+    /// The model used for new turns.
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// The recorded turns, oldest first.
+    pub fn turns(&self) -> &[ConversationTurn] {
+        &self.turns
+    }
+
+    /// The ID of the most recent response, if any turn was sent.
+    pub fn last_response_id(&self) -> Option<&str> {
+        self.turns.last().map(|turn| turn.response_id.as_str())
+    }
+
+    /// The full conversation history as input items, oldest first.
+    ///
+    /// This is what gets replayed when the previous response is gone.
+    pub fn history(&self) -> Vec<ResponseInputItem> {
+        self.turns
+            .iter()
+            .flat_map(|turn| turn.input.iter().chain(turn.output.iter()))
+            .cloned()
+            .collect()
+    }
+
+    /// Create a new conversation that keeps only the first `turns` turns.
+    ///
+    /// The original conversation is left untouched, so several branches can
+    /// continue independently from the same point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FoundryError::Validation`] if `turns` exceeds the number of
+    /// recorded turns.
+    pub fn branch_at(&self, turns: usize) -> FoundryResult<Self> {
+        if turns > self.turns.len() {
+            return Err(FoundryError::validation_field(
+                "turns",
+                format!(
+                    "cannot branch at turn {} of a conversation with {} turns",
+                    turns,
+                    self.turns.len()
+                ),
+            ));
+        }
+        Ok(Self {
+            turns: self.turns[..turns].to_vec(),
+            ..self.clone()
+        })
+    }
+
+    /// Send a user message and record the response.
+    pub async fn send(
+        &mut self,
+        client: &FoundryClient,
+        text: impl Into<String>,
+    ) -> FoundryResult<Response> {
+        self.send_items(client, vec![ResponseMessage::user(text).into()])
+            .await
+    }
+
+    /// Send arbitrary input items (e.g. function call outputs) and record the response.
+    ///
+    /// Chains onto the last response with `previous_response_id`. If the
+    /// service reports that response as missing, retries once with the full
+    /// local history instead.
+    #[tracing::instrument(
+        name = "foundry::responses::conversation::send",
+        skip(self, client, items),
+        fields(model = %self.model, turn = self.turns.len(), replayed)
+    )]
+    pub async fn send_items(
+        &mut self,
+        client: &FoundryClient,
+        items: Vec<ResponseInputItem>,
+    ) -> FoundryResult<Response> {
+        let span = tracing::Span::current();
+
+        let response = match self.last_response_id() {
+            Some(previous_id) => {
+                let request = self
+                    .request_builder(items.clone())
+                    .previous_response_id(previous_id)
+                    .try_build()?;
+                match create(client, &request).await {
+                    Err(err) if is_missing_previous_response(&err) => {
+                        tracing::debug!("previous response is gone, replaying history");
+                        span.record("replayed", true);
+                        let mut replay = self.history();
+                        replay.extend(items.iter().cloned());
+                        create(client, &self.request_builder(replay).try_build()?).await?
+                    }
+                    result => result?,
+                }
+            }
+            None => create(client, &self.request_builder(items.clone()).try_build()?).await?,
+        };
+
+        self.turns.push(ConversationTurn {
+            input: items,
+            response_id: response.id.clone(),
+            output: replayable_items(&response),
+        });
+
+        Ok(response)
+    }
+
+    fn request_builder(&self, items: Vec<ResponseInputItem>) -> CreateResponseRequestBuilder {
+        let mut builder = CreateResponseRequest::builder()
+            .model(&self.model)
+            .input_items(items);
+        if let Some(ref instructions) = self.instructions {
+            builder = builder.instructions(instructions);
+        }
+        if !self.tools.is_empty() {
+            builder = builder.tools(self.tools.iter().cloned());
+        }
+        builder
+    }
+}
+
+/// Returns `true` if the error means the `previous_response_id` is unknown.
+///
+/// Other 404s (a missing deployment or a bad endpoint) are not treated as
+/// an expired response, so they surface instead of triggering a replay.
+fn is_missing_previous_response(err: &FoundryError) -> bool {
+    let mentions_previous_response = |message: &str| {
+        let message = message.to_ascii_lowercase();
+        message.contains("previous response") && message.contains("not found")
+    };
+    match err {
+        FoundryError::Api { code, message } => {
+            code == "previous_response_not_found" || mentions_previous_response(message)
+        }
+        FoundryError::Http { message, .. } => mentions_previous_response(message),
+        _ => false,
+    }
+}
+
+/// Convert the replayable output items of a response into input items.
+fn replayable_items(response: &Response) -> Vec<ResponseInputItem> {
+    response
+        .output
+        .iter()
+        .filter_map(|item| match item.output_type {
+            ResponseOutputType::Message => {
+                let text: String = item
+                    .content
+                    .iter()
+                    .flatten()
+                    .filter(|c| c.content_type == ResponseContentType::OutputText)
+                    .filter_map(|c| c.text.as_deref())
+                    .collect();
+                Some(ResponseInputItem::Message(ResponseMessage::assistant(text)))
+            }
+            ResponseOutputType::FunctionCall => Some(ResponseInputItem::FunctionCall {
+                call_id: item.call_id.clone().unwrap_or_default(),
+                name: item.name.clone().unwrap_or_default(),
+                arguments: item.arguments.clone().unwrap_or_default(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
 
 #[cfg(test)]
 mod tests {
@@ -667,7 +1930,7 @@ mod tests {
                 assert_eq!(msgs[0].role, crate::chat::Role::System);
                 assert_eq!(msgs[1].role, crate::chat::Role::User);
             }
-            ResponseInput::Text(_) => panic!("Expected Messages, got Text"),
+            other => panic!("Expected Messages, got {:?}", other),
         }
     }
 
@@ -1242,7 +2505,7 @@ mod tests {
 
     #[test]
     fn test_response_output_type_deserializes_unknown() {
-        let json = r#"{"type": "function_call"}"#;
+        let json = r#"{"type": "image_generation_call"}"#;
         #[derive(Deserialize)]
         struct W {
             #[serde(rename = "type")]
@@ -1513,4 +2776,771 @@ mod tests {
             .try_build();
         assert!(result.is_ok());
     }
+
+    // --- Streaming ---
+
+    #[test]
+    fn test_stream_event_deserializes_output_text_delta() {
+        let event: ResponseStreamEvent = serde_json::from_value(serde_json::json!({
+            "type": "response.output_text.delta",
+            "item_id": "msg_001",
+            "output_index": 0,
+            "content_index": 0,
+            "delta": "Hel",
+            "sequence_number": 4
+        }))
+        .unwrap();
+
+        match event {
+            ResponseStreamEvent::OutputTextDelta { item_id, delta, .. } => {
+                assert_eq!(item_id, "msg_001");
+                assert_eq!(delta, "Hel");
+            }
+            other => panic!("Expected OutputTextDelta, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_stream_event_completed_exposes_final_response() {
+        let event: ResponseStreamEvent = serde_json::from_value(serde_json::json!({
+            "type": "response.completed",
+            "response": sample_response_json()
+        }))
+        .unwrap();
+
+        let response = event.final_response().expect("terminal event");
+        assert_eq!(response.id, "resp_abc123");
+        assert_eq!(response.output_text(), Some("Hello, how can I help?"));
+    }
+
+    #[test]
+    fn test_stream_event_unknown_type_is_other() {
+        let event: ResponseStreamEvent = serde_json::from_value(serde_json::json!({
+            "type": "response.reasoning_summary_text.delta",
+            "delta": "thinking"
+        }))
+        .unwrap();
+        assert!(matches!(event, ResponseStreamEvent::Other));
+        assert!(event.final_response().is_none());
+    }
+
+    #[test]
+    fn test_stream_event_function_call_arguments_delta() {
+        let event: ResponseStreamEvent = serde_json::from_value(serde_json::json!({
+            "type": "response.function_call_arguments.delta",
+            "item_id": "fc_1",
+            "output_index": 1,
+            "delta": "{\"city\":"
+        }))
+        .unwrap();
+        assert!(matches!(
+            event,
+            ResponseStreamEvent::FunctionCallArgumentsDelta {
+                output_index: 1,
+                ..
+            }
+        ));
+    }
+
+    /// Decode a single SSE line as a complete event and parse it as a stream event.
+    fn parse_event_line(line: &str) -> Option<FoundryResult<ResponseStreamEvent>> {
+        let mut decoder = azure_ai_foundry_core::sse::SseDecoder::new();
+        let event = decoder
+            .feed(format!("{line}\n\n").as_bytes())
+            .expect("should decode")
+            .pop()?;
+        match event.into_result() {
+            Ok(event) => parse_stream_event(&event),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    #[test]
+    fn test_parse_event_line_skips_non_data_lines() {
+        assert!(parse_event_line("event: response.output_text.delta").is_none());
+        assert!(parse_event_line(": keep-alive").is_none());
+        assert!(parse_event_line("").is_none());
+        assert!(parse_event_line("data: [DONE]").is_none());
+    }
+
+    #[test]
+    fn test_parse_event_line_maps_error_event_to_api_error() {
+        let result = parse_event_line(
+            r#"data: {"type":"error","code":"server_error","message":"boom","param":null}"#,
+        )
+        .expect("should yield");
+
+        match result {
+            Err(FoundryError::Api { code, message }) => {
+                assert_eq!(code, "server_error");
+                assert_eq!(message, "boom");
+            }
+            other => panic!("Expected Api error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_event_line_invalid_json() {
+        let result = parse_event_line("data: {not json}").expect("should yield");
+        assert!(matches!(result, Err(FoundryError::Stream { .. })));
+    }
+
+    #[test]
+    fn test_response_status_incomplete_deserializes() {
+        let status: ResponseStatus = serde_json::from_str("\"incomplete\"").unwrap();
+        assert_eq!(status, ResponseStatus::Incomplete);
+    }
+
+    #[tokio::test]
+    async fn test_create_stream_yields_events_and_final_response() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+
+        let mut created = sample_response_json();
+        created["status"] = "in_progress".into();
+        created["output"] = serde_json::json!([]);
+        created["usage"] = serde_json::Value::Null;
+
+        let events = [
+            serde_json::json!({"type": "response.created", "response": created}),
+            serde_json::json!({"type": "response.output_text.delta", "item_id": "msg_001", "output_index": 0, "content_index": 0, "delta": "Hello, "}),
+            serde_json::json!({"type": "response.output_text.delta", "item_id": "msg_001", "output_index": 0, "content_index": 0, "delta": "how can I help?"}),
+            serde_json::json!({"type": "response.completed", "response": sample_response_json()}),
+        ];
+        let mut sse_body = String::new();
+        for event in &events {
+            sse_body.push_str(&format!(
+                "event: {}\ndata: {}\n\n",
+                event["type"].as_str().unwrap(),
+                event
+            ));
+        }
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/responses"))
+            .and(header("Authorization", format!("Bearer {}", TEST_API_KEY)))
+            .and(body_json(serde_json::json!({
+                "model": "gpt-4o",
+                "input": "Hello",
+                "stream": true
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(sse_body)
+                    .insert_header("content-type", "text/event-stream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = CreateResponseRequest::builder()
+            .model("gpt-4o")
+            .input("Hello")
+            .build();
+
+        let mut stream = create_stream(&client, &request).await.expect("stream");
+        assert!(stream.response().is_none());
+
+        let mut text = String::new();
+        let mut count = 0;
+        while let Some(event) = stream.next().await {
+            count += 1;
+            if let ResponseStreamEvent::OutputTextDelta { delta, .. } = event.expect("event") {
+                text.push_str(&delta);
+            }
+        }
+
+        assert_eq!(count, 4);
+        assert_eq!(text, "Hello, how can I help?");
+        let response = stream.into_response().expect("final response");
+        assert_eq!(response.status, ResponseStatus::Completed);
+        assert_eq!(response.usage.unwrap().total_tokens, 30);
+    }
+
+    #[tokio::test]
+    async fn test_create_stream_surfaces_error_event() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/responses"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(
+                        "event: error\ndata: {\"type\":\"error\",\"code\":\"rate_limit_exceeded\",\"message\":\"slow down\"}\n\n",
+                    )
+                    .insert_header("content-type", "text/event-stream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let request = CreateResponseRequest::builder()
+            .model("gpt-4o")
+            .input("Hello")
+            .build();
+
+        let stream = create_stream(&client, &request).await.expect("stream");
+        let events: Vec<_> = stream.collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(
+            matches!(&events[0], Err(FoundryError::Api { code, .. }) if code == "rate_limit_exceeded")
+        );
+    }
+
+    // --- Tools, reasoning and background mode ---
+
+    #[test]
+    fn test_create_response_request_serializes_tools_and_options() {
+        let request = CreateResponseRequest::builder()
+            .model("o4-mini")
+            .input("Hi")
+            .instructions("Be brief")
+            .tool(ResponseTool::function(
+                "get_weather",
+                "Get weather",
+                serde_json::json!({"type": "object"}),
+            ))
+            .tools([
+                ResponseTool::file_search(["vs_1"]),
+                ResponseTool::web_search(),
+                ResponseTool::code_interpreter(),
+            ])
+            .tool_choice(ResponseToolChoice::Function("get_weather".into()))
+            .reasoning(ReasoningConfig {
+                effort: Some(ReasoningEffort::High),
+                summary: Some(ReasoningSummaryMode::Auto),
+            })
+            .text_format(TextFormat::JsonSchema {
+                name: "answer".into(),
+                schema: serde_json::json!({"type": "object"}),
+                description: None,
+                strict: Some(true),
+            })
+            .store(true)
+            .metadata("session", "abc")
+            .background(true)
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["instructions"], "Be brief");
+        assert_eq!(
+            json["tools"],
+            serde_json::json!([
+                {"type": "function", "name": "get_weather", "description": "Get weather", "parameters": {"type": "object"}},
+                {"type": "file_search", "vector_store_ids": ["vs_1"]},
+                {"type": "web_search_preview"},
+                {"type": "code_interpreter", "container": {"type": "auto"}}
+            ])
+        );
+        assert_eq!(
+            json["tool_choice"],
+            serde_json::json!({"type": "function", "name": "get_weather"})
+        );
+        assert_eq!(
+            json["reasoning"],
+            serde_json::json!({"effort": "high", "summary": "auto"})
+        );
+        assert_eq!(json["text"]["format"]["type"], "json_schema");
+        assert_eq!(json["text"]["format"]["strict"], true);
+        assert_eq!(json["store"], true);
+        assert_eq!(json["metadata"]["session"], "abc");
+        assert_eq!(json["background"], true);
+    }
+
+    #[test]
+    fn test_tool_choice_modes_serialize_as_strings() {
+        assert_eq!(
+            serde_json::to_value(ResponseToolChoice::None).unwrap(),
+            "none"
+        );
+        assert_eq!(
+            serde_json::to_value(ResponseToolChoice::Auto).unwrap(),
+            "auto"
+        );
+        assert_eq!(
+            serde_json::to_value(ResponseToolChoice::Required).unwrap(),
+            "required"
+        );
+    }
+
+    #[test]
+    fn test_function_output_converts_text_input_to_items() {
+        let request = CreateResponseRequest::builder()
+            .model("gpt-4o")
+            .previous_response_id("resp_1")
+            .function_output("call_1", "{\"ok\":true}")
+            .function_output("call_2", "42")
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["input"],
+            serde_json::json!([
+                {"type": "function_call_output", "call_id": "call_1", "output": "{\"ok\":true}"},
+                {"type": "function_call_output", "call_id": "call_2", "output": "42"}
+            ])
+        );
+
+        let with_text = CreateResponseRequest::builder()
+            .model("gpt-4o")
+            .input("Hello")
+            .function_output("call_1", "x")
+            .build();
+        let json = serde_json::to_value(&with_text).unwrap();
+        assert_eq!(
+            json["input"][0],
+            serde_json::json!({"type": "message", "role": "user", "content": "Hello"})
+        );
+        assert_eq!(json["input"][1]["type"], "function_call_output");
+    }
+
+    #[test]
+    fn test_background_requires_store() {
+        let result = CreateResponseRequest::builder()
+            .model("gpt-4o")
+            .input("Hi")
+            .background(true)
+            .store(false)
+            .try_build();
+        assert!(result.unwrap_err().to_string().contains("store"));
+    }
+
+    #[test]
+    fn test_response_typed_output_items() {
+        let response: Response = serde_json::from_value(serde_json::json!({
+            "id": "resp_1",
+            "object": "response",
+            "created_at": 1700000000,
+            "status": "completed",
+            "model": "o4-mini",
+            "previous_response_id": "resp_0",
+            "output": [
+                {"id": "rs_1", "type": "reasoning", "summary": [
+                    {"type": "summary_text", "text": "First"},
+                    {"type": "summary_text", "text": "Second"}
+                ]},
+                {"id": "fs_1", "type": "file_search_call", "status": "completed",
+                 "queries": ["rust"], "results": [{"file_id": "file_1", "filename": "a.md", "score": 0.9, "text": "Rust"}]},
+                {"id": "ci_1", "type": "code_interpreter_call", "status": "completed",
+                 "code": "print(1)", "outputs": [{"type": "logs", "logs": "1"}]},
+                {"id": "fc_1", "type": "function_call", "status": "completed",
+                 "call_id": "call_1", "name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(response.previous_response_id.as_deref(), Some("resp_0"));
+        assert_eq!(
+            response.reasoning_summary().as_deref(),
+            Some("First\n\nSecond")
+        );
+        assert_eq!(
+            response.output[1].output_type,
+            ResponseOutputType::FileSearchCall
+        );
+        assert_eq!(
+            response.output[1].results.as_ref().unwrap()[0].score,
+            Some(0.9)
+        );
+        assert_eq!(response.output[2].code.as_deref(), Some("print(1)"));
+
+        let calls: Vec<_> = response.function_calls().collect();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].call_id.as_deref(), Some("call_1"));
+        assert_eq!(calls[0].name.as_deref(), Some("get_weather"));
+        assert!(response.output_text().is_none());
+    }
+
+    #[test]
+    fn test_response_failed_with_error() {
+        let response: Response = serde_json::from_value(serde_json::json!({
+            "id": "resp_1",
+            "object": "response",
+            "created_at": 1700000000,
+            "status": "failed",
+            "model": "gpt-4o",
+            "output": [],
+            "error": {"code": "server_error", "message": "boom"}
+        }))
+        .unwrap();
+        assert_eq!(response.error.unwrap().code, "server_error");
+        assert!(response.status.is_terminal());
+        assert!(!ResponseStatus::Queued.is_terminal());
+        assert!(!ResponseStatus::InProgress.is_terminal());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_response_success() {
+        let server = MockServer::start().await;
+
+        let mut body = sample_response_json();
+        body["status"] = "cancelled".into();
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/responses/resp_abc123/cancel"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&body))
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let response = cancel(&client, "resp_abc123").await.expect("cancel");
+        assert_eq!(response.status, ResponseStatus::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_rejects_invalid_id() {
+        let server = MockServer::start().await;
+        let client = setup_mock_client(&server).await;
+        assert!(cancel(&client, "../resp").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_input_items_success() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/responses/resp_abc123/input_items"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "data": [
+                    {"id": "msg_1", "type": "message", "role": "user",
+                     "content": [{"type": "input_text", "text": "Hi"}]},
+                    {"id": "fco_1", "type": "function_call_output", "call_id": "call_1", "output": "42"}
+                ],
+                "first_id": "msg_1",
+                "last_id": "fco_1",
+                "has_more": false
+            })))
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let items = list_input_items(&client, "resp_abc123", None, None, None)
+            .await
+            .expect("list");
+
+        assert_eq!(items.data.len(), 2);
+        assert!(!items.has_more);
+        let content = items.data[0].content.as_ref().unwrap();
+        assert_eq!(content[0].text.as_deref(), Some("Hi"));
+        assert_eq!(
+            items.data[1].output_type,
+            ResponseOutputType::FunctionCallOutput
+        );
+        assert_eq!(items.data[1].output.as_deref(), Some("42"));
+    }
+
+    #[tokio::test]
+    async fn test_list_input_items_pagination() {
+        use wiremock::matchers::query_param;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/openai/v1/responses/resp_abc123/input_items"))
+            .and(query_param("after", "msg_1"))
+            .and(query_param("limit", "1"))
+            .and(query_param("order", "asc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "data": [
+                    {"id": "msg_2", "type": "message", "role": "user",
+                     "content": [{"type": "input_text", "text": "Again"}]}
+                ],
+                "first_id": "msg_2",
+                "last_id": "msg_2",
+                "has_more": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let items = list_input_items(
+            &client,
+            "resp_abc123",
+            Some("msg_1"),
+            Some(1),
+            Some(ListOrder::Asc),
+        )
+        .await
+        .expect("list");
+
+        assert_eq!(items.last_id.as_deref(), Some("msg_2"));
+        assert!(items.has_more);
+        assert!(
+            list_input_items(&client, "resp_abc123", Some("../x"), None, None)
+                .await
+                .is_err()
+        );
+    }
+
+    // --- Conversation ---
+
+    fn conversation_response_json(id: &str, text: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "object": "response",
+            "created_at": 1700000000,
+            "status": "completed",
+            "model": "gpt-4o",
+            "output": [{
+                "id": format!("msg_{}", id),
+                "type": "message",
+                "role": "assistant",
+                "content": [{"type": "output_text", "text": text}]
+            }]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_conversation_chains_previous_response_id() {
+        use wiremock::matchers::body_partial_json;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/responses"))
+            .and(body_partial_json(serde_json::json!({
+                "previous_response_id": "resp_1",
+                "input": [{"type": "message", "role": "user", "content": "And Go?"}]
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(conversation_response_json("resp_2", "Go is too.")),
+            )
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/responses"))
+            .and(body_json(serde_json::json!({
+                "model": "gpt-4o",
+                "instructions": "Be brief",
+                "input": [{"type": "message", "role": "user", "content": "Is Rust fast?"}]
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(conversation_response_json("resp_1", "Yes.")),
+            )
+            .mount(&server)
+            .await;
+
+        let client = setup_mock_client(&server).await;
+        let mut conversation = Conversation::new("gpt-4o").with_instructions("Be brief");
+
+        let first = conversation.send(&client, "Is Rust fast?").await.unwrap();
+        assert_eq!(first.output_text(), Some("Yes."));
+        let second = conversation.send(&client, "And Go?").await.unwrap();
+        assert_eq!(second.output_text(), Some("Go is too."));
+
+        assert_eq!(conversation.turns().len(), 2);
+        assert_eq!(conversation.last_response_id(), Some("resp_2"));
+        assert_eq!(conversation.history().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_conversation_replays_history_when_previous_response_is_gone() {
+        use wiremock::matchers::body_partial_json;
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/responses"))
+            .and(body_partial_json(serde_json::json!({
+                "previous_response_id": "resp_old"
+            })))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": {
+                    "code": "previous_response_not_found",
+                    "message": "Previous response with id 'resp_old' not found."
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/responses"))
+            .and(body_json(serde_json::json!({
+                "model": "gpt-4o",
+                "input": [
+                    {"type": "message", "role": "user", "content": "Hi"},
+                    {"type": "message", "role": "assistant", "content": "Hello!"},
+                    {"type": "message", "role": "user", "content": "Still there?"}
+                ]
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(conversation_response_json("resp_new", "Yes!")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // A session restored from storage whose response has expired.
+        let mut conversation: Conversation = serde_json::from_value(serde_json::json!({
+            "model": "gpt-4o",
+            "turns": [{
+                "input": [{"type": "message", "role": "user", "content": "Hi"}],
+                "response_id": "resp_old",
+                "output": [{"type": "message", "role": "assistant", "content": "Hello!"}]
+            }]
+        }))
+        .unwrap();
+
+        let client = setup_mock_client(&server).await;
+        let response = conversation.send(&client, "Still there?").await.unwrap();
+
+        assert_eq!(response.output_text(), Some("Yes!"));
+        assert_eq!(conversation.last_response_id(), Some("resp_new"));
+        assert_eq!(conversation.turns().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_conversation_does_not_replay_on_other_errors() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/responses"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": {"code": "invalid_prompt", "message": "Bad prompt"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut conversation = Conversation::new("gpt-4o");
+        conversation.turns.push(ConversationTurn {
+            input: vec![ResponseMessage::user("Hi").into()],
+            response_id: "resp_1".into(),
+            output: vec![],
+        });
+
+        let client = setup_mock_client(&server).await;
+        let err = conversation.send(&client, "Again").await.unwrap_err();
+        assert!(matches!(err, FoundryError::Api { ref code, .. } if code == "invalid_prompt"));
+        assert_eq!(conversation.turns().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_conversation_does_not_replay_on_unrelated_not_found() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/v1/responses"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": {"code": "DeploymentNotFound", "message": "The deployment does not exist."}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut conversation = Conversation::new("gpt-4o");
+        conversation.turns.push(ConversationTurn {
+            input: vec![ResponseMessage::user("Hi").into()],
+            response_id: "resp_1".into(),
+            output: vec![],
+        });
+
+        let client = setup_mock_client(&server).await;
+        let err = conversation.send(&client, "Again").await.unwrap_err();
+        assert!(matches!(err, FoundryError::Api { ref code, .. } if code == "DeploymentNotFound"));
+        assert_eq!(conversation.turns().len(), 1);
+    }
+
+    #[test]
+    fn test_is_missing_previous_response() {
+        let api = |code: &str, message: &str| FoundryError::Api {
+            code: code.into(),
+            message: message.into(),
+        };
+        assert!(is_missing_previous_response(&api(
+            "previous_response_not_found",
+            "gone"
+        )));
+        assert!(is_missing_previous_response(&api(
+            "invalid_request_error",
+            "Previous response with id 'resp_1' not found."
+        )));
+        assert!(!is_missing_previous_response(&api(
+            "not_found",
+            "No such model"
+        )));
+        assert!(!is_missing_previous_response(&FoundryError::http(
+            404,
+            "Resource not found"
+        )));
+    }
+
+    #[test]
+    fn test_conversation_branch_at_keeps_earlier_turns() {
+        let mut conversation = Conversation::new("gpt-4o");
+        for i in 0..3 {
+            conversation.turns.push(ConversationTurn {
+                input: vec![ResponseMessage::user(format!("q{}", i)).into()],
+                response_id: format!("resp_{}", i),
+                output: vec![],
+            });
+        }
+
+        let branch = conversation.branch_at(1).unwrap();
+        assert_eq!(branch.turns().len(), 1);
+        assert_eq!(branch.last_response_id(), Some("resp_0"));
+        assert_eq!(conversation.turns().len(), 3);
+
+        let empty = conversation.branch_at(0).unwrap();
+        assert!(empty.last_response_id().is_none());
+
+        let err = conversation.branch_at(4).unwrap_err();
+        assert!(matches!(err, FoundryError::Validation { .. }));
+    }
+
+    #[test]
+    fn test_conversation_replayable_items_keep_function_calls() {
+        let response: Response = serde_json::from_value(serde_json::json!({
+            "id": "resp_1",
+            "object": "response",
+            "created_at": 1700000000,
+            "status": "completed",
+            "model": "gpt-4o",
+            "output": [
+                {"id": "rs_1", "type": "reasoning", "summary": []},
+                {"id": "fc_1", "type": "function_call", "call_id": "call_1",
+                 "name": "lookup", "arguments": "{}"}
+            ]
+        }))
+        .unwrap();
+
+        let items = replayable_items(&response);
+        assert_eq!(items.len(), 1);
+        let json = serde_json::to_value(&items[0]).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "function_call", "call_id": "call_1", "name": "lookup", "arguments": "{}"})
+        );
+    }
+
+    #[test]
+    fn test_conversation_serialization_round_trip() {
+        let mut conversation = Conversation::new("gpt-4o")
+            .with_instructions("Be brief")
+            .with_tool(ResponseTool::web_search());
+        conversation.turns.push(ConversationTurn {
+            input: vec![ResponseMessage::user("Hi").into()],
+            response_id: "resp_1".into(),
+            output: vec![ResponseMessage::assistant("Hello").into()],
+        });
+
+        let json = serde_json::to_string(&conversation).unwrap();
+        let restored: Conversation = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.model(), "gpt-4o");
+        assert_eq!(restored.last_response_id(), Some("resp_1"));
+        assert_eq!(restored.tools, vec![ResponseTool::web_search()]);
+        assert_eq!(restored.history().len(), 2);
+    }
 }
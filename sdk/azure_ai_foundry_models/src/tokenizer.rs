@@ -0,0 +1,512 @@
+//! Offline token counting for chat messages and embedding inputs.
+//!
+//! Available with the `tokenizer` feature, which embeds the `cl100k_base`
+//! and `o200k_base` BPE encodings. Counts follow the chat format used by
+//! [`chat::training`](crate::chat::training): each message costs
+//! [`TOKENS_PER_MESSAGE`] plus its role and content, and each conversation
+//! costs [`TOKENS_PER_REPLY`] to prime the reply. Service-side counts can
+//! differ by a few tokens; leave some headroom.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use azure_ai_foundry_models::chat::{ChatCompletionRequest, Message};
+//! use azure_ai_foundry_models::tokenizer::{self, Encoding, TruncationStrategy};
+//!
+//! # fn example() -> azure_ai_foundry_core::error::FoundryResult<()> {
+//! let history = vec![
+//!     Message::system("You are a helpful assistant."),
+//!     Message::user("Summarize this long document..."),
+//!     Message::assistant("Here is a summary..."),
+//!     Message::user("Now shorten it."),
+//! ];
+//!
+//! let encoding = Encoding::for_model("gpt-4o");
+//! println!("{} tokens", encoding.count_messages(&history));
+//!
+//! let trimmed = tokenizer::truncate_messages(encoding, &history, 4_000, TruncationStrategy::DropOldest)?;
+//! let request = ChatCompletionRequest::builder()
+//!     .model("gpt-4o")
+//!     .messages(trimmed)
+//!     .max_tokens(1_000)
+//!     .build();
+//! assert!(tokenizer::fits_context(&request, 128_000));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+
+use azure_ai_foundry_core::error::{FoundryError, FoundryResult};
+use serde::Serialize;
+use tiktoken_rs::CoreBPE;
+
+use crate::chat::{ChatCompletionRequest, Message, Role};
+use crate::embeddings::{EmbeddingInput, EmbeddingRequest};
+
+pub use crate::chat::training::{TOKENS_PER_MESSAGE, TOKENS_PER_REPLY};
+pub use crate::embeddings::{MAX_EMBEDDING_INPUT_TOKENS, MAX_EMBEDDING_REQUEST_TOKENS};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Model name prefixes that use `o200k_base`.
+const O200K_PREFIXES: &[&str] = &[
+    "gpt-4o",
+    "chatgpt-4o",
+    "gpt-4.1",
+    "gpt-4.5",
+    "gpt-5",
+    "gpt-oss",
+    "o1",
+    "o3",
+    "o4",
+];
+
+// ---------------------------------------------------------------------------
+// Encoding
+// ---------------------------------------------------------------------------
+
+/// A BPE token encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// `cl100k_base`, used by GPT-4, GPT-3.5 Turbo and `text-embedding-*` models.
+    Cl100kBase,
+    /// `o200k_base`, used by GPT-4o, GPT-4.1, GPT-5 and the o-series.
+    O200kBase,
+}
+
+impl Encoding {
+    /// Pick the encoding for a model name.
+    ///
+    /// Fine-tuned names (`ft:gpt-4o-mini:...`) resolve to their base model.
+    /// Names not recognized as an older model, such as custom deployment
+    /// names, use [`O200kBase`](Self::O200kBase).
+    pub fn for_model(model: &str) -> Self {
+        let model = model.to_ascii_lowercase();
+        let model = model.strip_prefix("ft:").unwrap_or(&model);
+
+        if O200K_PREFIXES.iter().any(|p| model.starts_with(p)) {
+            Self::O200kBase
+        } else if model.starts_with("gpt-4")
+            || model.starts_with("gpt-35")
+            || model.starts_with("gpt-3.5")
+            || model.starts_with("text-embedding")
+        {
+            Self::Cl100kBase
+        } else {
+            Self::O200kBase
+        }
+    }
+
+    /// The encoding name, e.g. `o200k_base`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Cl100kBase => "cl100k_base",
+            Self::O200kBase => "o200k_base",
+        }
+    }
+
+    fn bpe(&self) -> &'static CoreBPE {
+        match self {
+            Self::Cl100kBase => tiktoken_rs::cl100k_base_singleton(),
+            Self::O200kBase => tiktoken_rs::o200k_base_singleton(),
+        }
+    }
+
+    /// Encode text into tokens. Special tokens are treated as plain text.
+    pub fn encode(&self, text: &str) -> Vec<u32> {
+        self.bpe().encode_ordinary(text)
+    }
+
+    /// Decode tokens back into text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the tokens are not valid for this
+    /// encoding or split a UTF-8 character.
+    pub fn decode(&self, tokens: &[u32]) -> FoundryResult<String> {
+        self.bpe()
+            .decode(tokens.to_vec())
+            .map_err(|e| FoundryError::validation(format!("failed to decode tokens: {}", e)))
+    }
+
+    /// Count the tokens in text.
+    pub fn count(&self, text: &str) -> usize {
+        self.encode(text).len()
+    }
+
+    /// Count the tokens of one message, including per-message overhead.
+    pub fn count_message(&self, message: &Message) -> usize {
+        let calls: usize = message
+            .tool_calls
+            .iter()
+            .flatten()
+            .map(|c| self.count(&c.function.name) + self.count(&c.function.arguments))
+            .sum();
+        TOKENS_PER_MESSAGE
+            + self.count(role_name(&message.role))
+            + message.content.as_deref().map_or(0, |c| self.count(c))
+            + message
+                .tool_call_id
+                .as_deref()
+                .map_or(0, |id| self.count(id))
+            + calls
+    }
+
+    /// Count the tokens of a conversation, including reply priming.
+    pub fn count_messages(&self, messages: &[Message]) -> usize {
+        messages
+            .iter()
+            .map(|m| self.count_message(m))
+            .sum::<usize>()
+            + TOKENS_PER_REPLY
+    }
+
+    /// Count the tokens of tool definitions as sent to the model.
+    ///
+    /// Works with any serializable tool type, such as `realtime::RealtimeTool`
+    /// (with the `realtime` feature) or raw JSON.
+    pub fn count_tools<T: Serialize>(&self, tools: &[T]) -> usize {
+        if tools.is_empty() {
+            return 0;
+        }
+        serde_json::to_string(tools).map_or(0, |json| self.count(&json))
+    }
+}
+
+fn role_name(role: &Role) -> &'static str {
+    match role {
+        Role::System => "system",
+        Role::User => "user",
+        Role::Assistant => "assistant",
+        Role::Tool => "tool",
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Requests
+// ---------------------------------------------------------------------------
+
+/// Count the prompt tokens of a chat completion request.
+pub fn count_request_tokens(request: &ChatCompletionRequest) -> usize {
+    Encoding::for_model(&request.model).count_messages(&request.messages)
+}
+
+/// Whether the prompt plus `max_tokens` fits in `context_window` tokens.
+pub fn fits_context(request: &ChatCompletionRequest, context_window: usize) -> bool {
+    let reserved = request.max_tokens.map_or(0, |t| t as usize);
+    count_request_tokens(request) + reserved <= context_window
+}
+
+/// Count the tokens of each input of an embedding request.
+pub fn embedding_input_tokens(request: &EmbeddingRequest) -> Vec<usize> {
+    let encoding = Encoding::for_model(&request.model);
+    match &request.input {
+        EmbeddingInput::Single(text) => vec![encoding.count(text)],
+        EmbeddingInput::Multiple(texts) => texts.iter().map(|t| encoding.count(t)).collect(),
+    }
+}
+
+/// Check an embedding request against the token limits and return its total.
+///
+/// # Errors
+///
+/// Returns a validation error naming the first input over
+/// [`MAX_EMBEDDING_INPUT_TOKENS`], or if all inputs together exceed
+/// [`MAX_EMBEDDING_REQUEST_TOKENS`].
+pub fn check_embedding_request(request: &EmbeddingRequest) -> FoundryResult<usize> {
+    let counts = embedding_input_tokens(request);
+    if let Some((index, count)) = counts
+        .iter()
+        .enumerate()
+        .find(|(_, &c)| c > MAX_EMBEDDING_INPUT_TOKENS)
+    {
+        return Err(FoundryError::validation_field(
+            "input",
+            format!(
+                "input {} has {} tokens, more than the {} token limit",
+                index, count, MAX_EMBEDDING_INPUT_TOKENS
+            ),
+        ));
+    }
+
+    let total = counts.iter().sum();
+    if total > MAX_EMBEDDING_REQUEST_TOKENS {
+        return Err(FoundryError::validation_field(
+            "input",
+            format!(
+                "request has {} tokens, more than the {} token limit",
+                total, MAX_EMBEDDING_REQUEST_TOKENS
+            ),
+        ));
+    }
+    Ok(total)
+}
+
+// ---------------------------------------------------------------------------
+// Truncation
+// ---------------------------------------------------------------------------
+
+/// How [`truncate_messages`] shortens a conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TruncationStrategy {
+    /// Drop whole messages, oldest first.
+    #[default]
+    DropOldest,
+    /// Cut the start of the oldest message's content, dropping it only when
+    /// trimming is not enough.
+    TrimOldest,
+}
+
+/// Shorten a conversation to fit `budget` tokens.
+///
+/// Leading system messages are always kept. Other messages are removed or
+/// trimmed oldest first; an assistant message with tool calls is removed
+/// together with its tool responses, so no tool message is left without
+/// its call.
+///
+/// # Errors
+///
+/// Returns a validation error if the conversation cannot fit, e.g. because
+/// the system messages alone exceed the budget.
+pub fn truncate_messages(
+    encoding: Encoding,
+    messages: &[Message],
+    budget: usize,
+    strategy: TruncationStrategy,
+) -> FoundryResult<Vec<Message>> {
+    let system_len = messages
+        .iter()
+        .take_while(|m| m.role == Role::System)
+        .count();
+    let (system, rest) = messages.split_at(system_len);
+
+    let mut total = encoding.count_messages(messages);
+    let mut rest: VecDeque<(Message, usize)> = rest
+        .iter()
+        .map(|m| (m.clone(), encoding.count_message(m)))
+        .collect();
+
+    while total > budget {
+        let Some((oldest, oldest_count)) = rest.front_mut() else {
+            return Err(FoundryError::validation(format!(
+                "messages cannot fit in {} tokens",
+                budget
+            )));
+        };
+
+        if strategy == TruncationStrategy::TrimOldest && oldest.tool_calls.is_none() {
+            if let Some(trimmed) = trim_start(encoding, oldest, total - budget) {
+                oldest.content = Some(trimmed);
+                let new_count = encoding.count_message(oldest);
+                if new_count < *oldest_count {
+                    total = total - *oldest_count + new_count;
+                    *oldest_count = new_count;
+                    continue;
+                }
+            }
+        }
+
+        // Drop the oldest message, then any tool responses it leaves orphaned.
+        if let Some((_, count)) = rest.pop_front() {
+            total -= count;
+        }
+        while rest.front().is_some_and(|(m, _)| m.role == Role::Tool) {
+            if let Some((_, count)) = rest.pop_front() {
+                total -= count;
+            }
+        }
+    }
+
+    Ok(system
+        .iter()
+        .cloned()
+        .chain(rest.into_iter().map(|(m, _)| m))
+        .collect())
+}
+
+/// Remove at least `excess` tokens from the start of a message's content.
+///
+/// Returns `None` when nothing useful would remain.
+fn trim_start(encoding: Encoding, message: &Message, excess: usize) -> Option<String> {
+    let tokens = encoding.encode(message.content.as_deref()?);
+    // A cut can land inside a multi-byte character; skip up to three more tokens.
+    (excess..tokens.len().min(excess + 4))
+        .find_map(|start| encoding.decode(&tokens[start..]).ok())
+        .filter(|text| !text.trim().is_empty())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat::{FunctionCall, ToolCall, ToolCallType};
+
+    fn tool_call(id: &str) -> ToolCall {
+        ToolCall {
+            id: id.into(),
+            call_type: ToolCallType::Function,
+            function: FunctionCall {
+                name: "lookup".into(),
+                arguments: "{}".into(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_for_model() {
+        assert_eq!(Encoding::for_model("gpt-4o-mini"), Encoding::O200kBase);
+        assert_eq!(
+            Encoding::for_model("ft:gpt-4o-mini:org::abc"),
+            Encoding::O200kBase
+        );
+        assert_eq!(Encoding::for_model("o3-mini"), Encoding::O200kBase);
+        assert_eq!(Encoding::for_model("GPT-4"), Encoding::Cl100kBase);
+        assert_eq!(Encoding::for_model("gpt-35-turbo"), Encoding::Cl100kBase);
+        assert_eq!(
+            Encoding::for_model("text-embedding-3-small"),
+            Encoding::Cl100kBase
+        );
+        assert_eq!(Encoding::for_model("my-deployment"), Encoding::O200kBase);
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        for encoding in [Encoding::Cl100kBase, Encoding::O200kBase] {
+            let tokens = encoding.encode("hello world");
+            assert_eq!(tokens.len(), 2, "{}", encoding.name());
+            assert_eq!(encoding.decode(&tokens).unwrap(), "hello world");
+        }
+    }
+
+    #[test]
+    fn test_count_messages_includes_overhead() {
+        let encoding = Encoding::O200kBase;
+        let messages = [Message::system("hello world"), Message::user("hello")];
+        // (3 + "system" + 2) + (3 + "user" + 1) + 3
+        let expected = 3 + encoding.count("system") + 2 + 3 + encoding.count("user") + 1 + 3;
+        assert_eq!(encoding.count_messages(&messages), expected);
+    }
+
+    #[test]
+    fn test_count_tools() {
+        let encoding = Encoding::Cl100kBase;
+        let tools = [serde_json::json!({"type": "function", "function": {"name": "lookup"}})];
+        assert!(encoding.count_tools(&tools) > 0);
+        assert_eq!(encoding.count_tools::<serde_json::Value>(&[]), 0);
+    }
+
+    #[test]
+    fn test_fits_context() {
+        let request = ChatCompletionRequest::builder()
+            .model("gpt-4o")
+            .message(Message::user("hello"))
+            .max_tokens(100)
+            .build();
+        let prompt = count_request_tokens(&request);
+        assert!(fits_context(&request, prompt + 100));
+        assert!(!fits_context(&request, prompt + 99));
+    }
+
+    #[test]
+    fn test_check_embedding_request() {
+        let request = EmbeddingRequest::builder()
+            .model("text-embedding-3-small")
+            .inputs(vec!["hello world".to_string(), "hi".to_string()])
+            .build();
+        assert_eq!(embedding_input_tokens(&request), vec![2, 1]);
+        assert_eq!(check_embedding_request(&request).unwrap(), 3);
+
+        let request = EmbeddingRequest::builder()
+            .model("text-embedding-3-small")
+            .inputs(vec!["ok".to_string(), "word ".repeat(9_000)])
+            .build();
+        let err = check_embedding_request(&request).unwrap_err();
+        assert!(err.to_string().contains("input 1 has"));
+    }
+
+    #[test]
+    fn test_truncate_drop_oldest_keeps_system_and_tool_pairs() {
+        let encoding = Encoding::O200kBase;
+        let mut call = Message::assistant("");
+        call.content = None;
+        call.tool_calls = Some(vec![tool_call("call_1")]);
+        let result = Message::tool("call_1", "found it");
+
+        let messages = vec![
+            Message::system("be brief"),
+            call,
+            result,
+            Message::user("first question"),
+            Message::assistant("first answer"),
+            Message::user("second question"),
+        ];
+        let last_two = encoding.count_messages(&[
+            messages[0].clone(),
+            messages[4].clone(),
+            messages[5].clone(),
+        ]);
+
+        let kept = truncate_messages(
+            encoding,
+            &messages,
+            last_two,
+            TruncationStrategy::DropOldest,
+        )
+        .unwrap();
+        let contents: Vec<_> = kept.iter().map(|m| m.content.as_deref()).collect();
+        assert_eq!(
+            contents,
+            [
+                Some("be brief"),
+                Some("first answer"),
+                Some("second question")
+            ]
+        );
+        assert!(encoding.count_messages(&kept) <= last_two);
+
+        // Dropping the tool call also drops its response.
+        let budget = encoding.count_messages(&messages) - 1;
+        let kept =
+            truncate_messages(encoding, &messages, budget, TruncationStrategy::DropOldest).unwrap();
+        assert_eq!(kept.len(), 4);
+        assert_eq!(kept[1].content.as_deref(), Some("first question"));
+    }
+
+    #[test]
+    fn test_truncate_trim_oldest() {
+        let encoding = Encoding::O200kBase;
+        let messages = vec![
+            Message::user("one two three four five six seven eight"),
+            Message::assistant("done"),
+        ];
+        let budget = encoding.count_messages(&messages) - 3;
+
+        let kept =
+            truncate_messages(encoding, &messages, budget, TruncationStrategy::TrimOldest).unwrap();
+        assert_eq!(kept.len(), 2);
+        assert_eq!(
+            kept[0].content.as_deref(),
+            Some(" four five six seven eight")
+        );
+        assert!(encoding.count_messages(&kept) <= budget);
+    }
+
+    #[test]
+    fn test_truncate_fails_when_system_exceeds_budget() {
+        let messages = vec![Message::system("a long system prompt"), Message::user("hi")];
+        let err = truncate_messages(
+            Encoding::O200kBase,
+            &messages,
+            5,
+            TruncationStrategy::DropOldest,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("cannot fit in 5 tokens"));
+    }
+}